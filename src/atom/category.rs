@@ -0,0 +1,80 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under category can be retrieved by using the methods under `Category`.
+
+use atom::Category;
+
+impl Category {
+    /// Get the term that exists under `Category`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::CategoryBuilder;
+    ///
+    /// let term = "technology";
+    /// let category_obj = CategoryBuilder::new()
+    ///     .term(term)
+    ///     .finalize();
+    /// assert_eq!(term.to_owned(), category_obj.term());
+    /// ```
+    pub fn term(&self) -> String {
+        self.term.clone()
+    }
+
+
+    /// Get the optional scheme that exists under `Category`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::CategoryBuilder;
+    ///
+    /// let scheme = "http://example.com/categories".to_owned();
+    /// let category_obj = CategoryBuilder::new()
+    ///     .scheme(Some(scheme.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(scheme), category_obj.scheme());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::CategoryBuilder;
+    ///
+    /// let category_obj = CategoryBuilder::new()
+    ///     .scheme(None)
+    ///     .finalize();
+    /// assert!(category_obj.scheme().is_none());
+    /// ```
+    pub fn scheme(&self) -> Option<String> {
+        self.scheme.clone()
+    }
+
+
+    /// Get the optional label that exists under `Category`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::CategoryBuilder;
+    ///
+    /// let label = "Technology".to_owned();
+    /// let category_obj = CategoryBuilder::new()
+    ///     .label(Some(label.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(label), category_obj.label());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::CategoryBuilder;
+    ///
+    /// let category_obj = CategoryBuilder::new()
+    ///     .label(None)
+    ///     .finalize();
+    /// assert!(category_obj.label().is_none());
+    /// ```
+    pub fn label(&self) -> Option<String> {
+        self.label.clone()
+    }
+}
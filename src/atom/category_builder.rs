@@ -0,0 +1,90 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for category by using the methods under `CategoryBuilder`.
+
+use atom::{Category, CategoryBuilder};
+
+impl CategoryBuilder {
+    /// Construct a new `CategoryBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::CategoryBuilder;
+    ///
+    /// let category_builder = CategoryBuilder::new();
+    /// ```
+    pub fn new() -> CategoryBuilder {
+        CategoryBuilder::default()
+    }
+
+
+    /// Set the term that exists under `Category`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::CategoryBuilder;
+    ///
+    /// let mut category_builder = CategoryBuilder::new();
+    /// category_builder.term("technology");
+    /// ```
+    pub fn term(&mut self, term: &str) -> &mut CategoryBuilder {
+        self.term = term.to_owned();
+        self
+    }
+
+
+    /// Set the optional scheme that exists under `Category`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::CategoryBuilder;
+    ///
+    /// let mut category_builder = CategoryBuilder::new();
+    /// category_builder.scheme(Some("http://example.com/categories".to_owned()));
+    /// ```
+    pub fn scheme(&mut self, scheme: Option<String>) -> &mut CategoryBuilder {
+        self.scheme = scheme;
+        self
+    }
+
+
+    /// Set the optional label that exists under `Category`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::CategoryBuilder;
+    ///
+    /// let mut category_builder = CategoryBuilder::new();
+    /// category_builder.label(Some("Technology".to_owned()));
+    /// ```
+    pub fn label(&mut self, label: Option<String>) -> &mut CategoryBuilder {
+        self.label = label;
+        self
+    }
+
+
+    /// Construct the `Category` from the `CategoryBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::CategoryBuilder;
+    ///
+    /// let category = CategoryBuilder::new()
+    ///         .term("technology")
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> Category {
+        Category {
+            term: self.term.clone(),
+            scheme: self.scheme.clone(),
+            label: self.label.clone(),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under content can be retrieved by using the methods under `Content`.
+
+use atom::Content;
+
+impl Content {
+    /// Get the content that exists under `Content`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::ContentBuilder;
+    ///
+    /// let content = "Some text.";
+    /// let content_obj = ContentBuilder::new()
+    ///     .content(content)
+    ///     .finalize();
+    /// assert_eq!(content.to_owned(), content_obj.content());
+    /// ```
+    pub fn content(&self) -> String {
+        self.content.clone()
+    }
+
+
+    /// Get the optional type that exists under `Content`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::ContentBuilder;
+    ///
+    /// let content_type = "html".to_owned();
+    /// let content_obj = ContentBuilder::new()
+    ///     .content_type(Some(content_type.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(content_type), content_obj.content_type());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::ContentBuilder;
+    ///
+    /// let content_obj = ContentBuilder::new()
+    ///     .content_type(None)
+    ///     .finalize();
+    /// assert!(content_obj.content_type().is_none());
+    /// ```
+    pub fn content_type(&self) -> Option<String> {
+        self.content_type.clone()
+    }
+
+
+    /// Get the optional src that exists under `Content`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::ContentBuilder;
+    ///
+    /// let src = "http://example.com/2003/12/13/atom03.html".to_owned();
+    /// let content_obj = ContentBuilder::new()
+    ///     .src(Some(src.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(src), content_obj.src());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::ContentBuilder;
+    ///
+    /// let content_obj = ContentBuilder::new()
+    ///     .src(None)
+    ///     .finalize();
+    /// assert!(content_obj.src().is_none());
+    /// ```
+    pub fn src(&self) -> Option<String> {
+        self.src.clone()
+    }
+}
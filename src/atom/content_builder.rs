@@ -0,0 +1,90 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for content by using the methods under `ContentBuilder`.
+
+use atom::{Content, ContentBuilder};
+
+impl ContentBuilder {
+    /// Construct a new `ContentBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::ContentBuilder;
+    ///
+    /// let content_builder = ContentBuilder::new();
+    /// ```
+    pub fn new() -> ContentBuilder {
+        ContentBuilder::default()
+    }
+
+
+    /// Set the content that exists under `Content`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::ContentBuilder;
+    ///
+    /// let mut content_builder = ContentBuilder::new();
+    /// content_builder.content("Some text.");
+    /// ```
+    pub fn content(&mut self, content: &str) -> &mut ContentBuilder {
+        self.content = content.to_owned();
+        self
+    }
+
+
+    /// Set the optional type that exists under `Content`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::ContentBuilder;
+    ///
+    /// let mut content_builder = ContentBuilder::new();
+    /// content_builder.content_type(Some("html".to_owned()));
+    /// ```
+    pub fn content_type(&mut self, content_type: Option<String>) -> &mut ContentBuilder {
+        self.content_type = content_type;
+        self
+    }
+
+
+    /// Set the optional src that exists under `Content`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::ContentBuilder;
+    ///
+    /// let mut content_builder = ContentBuilder::new();
+    /// content_builder.src(Some("http://example.com/2003/12/13/atom03.html".to_owned()));
+    /// ```
+    pub fn src(&mut self, src: Option<String>) -> &mut ContentBuilder {
+        self.src = src;
+        self
+    }
+
+
+    /// Construct the `Content` from the `ContentBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::ContentBuilder;
+    ///
+    /// let content = ContentBuilder::new()
+    ///         .content("Some text.")
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> Content {
+        Content {
+            content: self.content.clone(),
+            content_type: self.content_type.clone(),
+            src: self.src.clone(),
+        }
+    }
+}
@@ -0,0 +1,439 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under entry can be retrieved by using the methods under `Entry`.
+
+use atom::{Category, Content, Entry, Link, Person};
+use chrono::*;
+
+impl Entry {
+    /// Get the id that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let id = "urn:uuid:1225c695-cfb8-4ebb-aaaa-80da344efa6a";
+    /// let entry_obj = EntryBuilder::new()
+    ///     .id(id)
+    ///     .finalize();
+    /// assert_eq!(id.to_owned(), entry_obj.id());
+    /// ```
+    pub fn id(&self) -> String {
+        self.id.clone()
+    }
+
+
+    /// Get the title that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let title = "Atom-Powered Robots Run Amok";
+    /// let entry_obj = EntryBuilder::new()
+    ///     .title(title)
+    ///     .finalize();
+    /// assert_eq!(title.to_owned(), entry_obj.title());
+    /// ```
+    pub fn title(&self) -> String {
+        self.title.clone()
+    }
+
+
+    /// Get the optional title type that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let title_type = "html".to_owned();
+    /// let entry_obj = EntryBuilder::new()
+    ///     .title_type(Some(title_type.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(title_type), entry_obj.title_type());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let entry_obj = EntryBuilder::new()
+    ///     .title_type(None)
+    ///     .finalize();
+    /// assert!(entry_obj.title_type().is_none());
+    /// ```
+    pub fn title_type(&self) -> Option<String> {
+        self.title_type.clone()
+    }
+
+
+    /// Get the optional updated date that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let updated = "2003-12-13T18:30:02+00:00";
+    /// let entry_obj = EntryBuilder::new()
+    ///     .updated(Some(updated.to_owned()))
+    ///     .finalize();
+    /// let local = entry_obj.updated();
+    /// assert!(local.is_some());
+    /// assert_eq!(updated.to_owned(), local.unwrap().to_rfc3339());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let entry_obj = EntryBuilder::new()
+    ///     .updated(None)
+    ///     .finalize();
+    /// assert!(entry_obj.updated().is_none());
+    /// ```
+    pub fn updated(&self) -> Option<DateTime<FixedOffset>> {
+        self.updated
+    }
+
+
+    /// Get the text of the optional updated date that exists under `Entry` when
+    /// it could not be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let entry_obj = EntryBuilder::new()
+    ///     .updated(Some("2003-12-13 18:30:02".to_owned()))
+    ///     .finalize();
+    /// assert!(entry_obj.updated().is_some());
+    /// assert!(entry_obj.updated_raw().is_none());
+    ///
+    /// let entry_obj = EntryBuilder::new()
+    ///     .updated(Some("last Saturday".to_owned()))
+    ///     .finalize();
+    /// assert!(entry_obj.updated().is_none());
+    /// assert_eq!(Some("last Saturday".to_owned()), entry_obj.updated_raw());
+    /// ```
+    pub fn updated_raw(&self) -> Option<String> {
+        self.updated_raw.clone()
+    }
+
+
+    /// Get the optional authors that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::{EntryBuilder, PersonBuilder};
+    ///
+    /// let person_1 = PersonBuilder::new().finalize();
+    /// let person_2 = PersonBuilder::new().finalize();
+    /// let authors_vec = vec![person_1, person_2];
+    /// let entry_obj = EntryBuilder::new()
+    ///     .authors(Some(authors_vec.clone()))
+    ///     .finalize();
+    /// let authors_option = entry_obj.authors();
+    /// assert!(authors_option.is_some());
+    /// assert_eq!(authors_vec.len(), authors_option.unwrap().len());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let entry_obj = EntryBuilder::new()
+    ///     .authors(None)
+    ///     .finalize();
+    /// assert!(entry_obj.authors().is_none());
+    /// ```
+    pub fn authors(&self) -> Option<Vec<Person>> {
+        self.authors.clone()
+    }
+
+
+    /// Get the optional contributors that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::{EntryBuilder, PersonBuilder};
+    ///
+    /// let person_1 = PersonBuilder::new().finalize();
+    /// let person_2 = PersonBuilder::new().finalize();
+    /// let contributors_vec = vec![person_1, person_2];
+    /// let entry_obj = EntryBuilder::new()
+    ///     .contributors(Some(contributors_vec.clone()))
+    ///     .finalize();
+    /// let contributors_option = entry_obj.contributors();
+    /// assert!(contributors_option.is_some());
+    /// assert_eq!(contributors_vec.len(), contributors_option.unwrap().len());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let entry_obj = EntryBuilder::new()
+    ///     .contributors(None)
+    ///     .finalize();
+    /// assert!(entry_obj.contributors().is_none());
+    /// ```
+    pub fn contributors(&self) -> Option<Vec<Person>> {
+        self.contributors.clone()
+    }
+
+
+    /// Get the optional links that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::{EntryBuilder, LinkBuilder};
+    ///
+    /// let link_1 = LinkBuilder::new().finalize();
+    /// let link_2 = LinkBuilder::new().finalize();
+    /// let links_vec = vec![link_1, link_2];
+    /// let entry_obj = EntryBuilder::new()
+    ///     .links(Some(links_vec.clone()))
+    ///     .finalize();
+    /// let links_option = entry_obj.links();
+    /// assert!(links_option.is_some());
+    /// assert_eq!(links_vec.len(), links_option.unwrap().len());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let entry_obj = EntryBuilder::new()
+    ///     .links(None)
+    ///     .finalize();
+    /// assert!(entry_obj.links().is_none());
+    /// ```
+    pub fn links(&self) -> Option<Vec<Link>> {
+        self.links.clone()
+    }
+
+
+    /// Get the optional categories that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::{EntryBuilder, CategoryBuilder};
+    ///
+    /// let category_1 = CategoryBuilder::new().finalize();
+    /// let category_2 = CategoryBuilder::new().finalize();
+    /// let categories_vec = vec![category_1, category_2];
+    /// let entry_obj = EntryBuilder::new()
+    ///     .categories(Some(categories_vec.clone()))
+    ///     .finalize();
+    /// let categories_option = entry_obj.categories();
+    /// assert!(categories_option.is_some());
+    /// assert_eq!(categories_vec.len(), categories_option.unwrap().len());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let entry_obj = EntryBuilder::new()
+    ///     .categories(None)
+    ///     .finalize();
+    /// assert!(entry_obj.categories().is_none());
+    /// ```
+    pub fn categories(&self) -> Option<Vec<Category>> {
+        self.categories.clone()
+    }
+
+
+    /// Get the optional summary that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let summary = "Some text.".to_owned();
+    /// let entry_obj = EntryBuilder::new()
+    ///     .summary(Some(summary.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(summary), entry_obj.summary());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let entry_obj = EntryBuilder::new()
+    ///     .summary(None)
+    ///     .finalize();
+    /// assert!(entry_obj.summary().is_none());
+    /// ```
+    pub fn summary(&self) -> Option<String> {
+        self.summary.clone()
+    }
+
+
+    /// Get the optional summary type that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let summary_type = "text".to_owned();
+    /// let entry_obj = EntryBuilder::new()
+    ///     .summary_type(Some(summary_type.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(summary_type), entry_obj.summary_type());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let entry_obj = EntryBuilder::new()
+    ///     .summary_type(None)
+    ///     .finalize();
+    /// assert!(entry_obj.summary_type().is_none());
+    /// ```
+    pub fn summary_type(&self) -> Option<String> {
+        self.summary_type.clone()
+    }
+
+
+    /// Get the optional content that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::{EntryBuilder, ContentBuilder};
+    ///
+    /// let content = ContentBuilder::new().finalize();
+    /// let entry_obj = EntryBuilder::new()
+    ///     .content(Some(content))
+    ///     .finalize();
+    /// assert!(entry_obj.content().is_some());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let entry_obj = EntryBuilder::new()
+    ///     .content(None)
+    ///     .finalize();
+    /// assert!(entry_obj.content().is_none());
+    /// ```
+    pub fn content(&self) -> Option<Content> {
+        self.content.clone()
+    }
+
+
+    /// Get the optional published date that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let published = "2003-12-13T08:29:29-04:00";
+    /// let entry_obj = EntryBuilder::new()
+    ///     .published(Some(published.to_owned()))
+    ///     .finalize();
+    /// let local = entry_obj.published();
+    /// assert!(local.is_some());
+    /// assert_eq!(published.to_owned(), local.unwrap().to_rfc3339());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let entry_obj = EntryBuilder::new()
+    ///     .published(None)
+    ///     .finalize();
+    /// assert!(entry_obj.published().is_none());
+    /// ```
+    pub fn published(&self) -> Option<DateTime<FixedOffset>> {
+        self.published
+    }
+
+
+    /// Get the text of the optional published date that exists under `Entry`
+    /// when it could not be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let entry_obj = EntryBuilder::new()
+    ///     .published(Some("Sat, 13 Dec 2003 08:29:29 -0400".to_owned()))
+    ///     .finalize();
+    /// assert!(entry_obj.published().is_some());
+    /// assert!(entry_obj.published_raw().is_none());
+    ///
+    /// let entry_obj = EntryBuilder::new()
+    ///     .published(Some("last Saturday".to_owned()))
+    ///     .finalize();
+    /// assert!(entry_obj.published().is_none());
+    /// assert_eq!(Some("last Saturday".to_owned()), entry_obj.published_raw());
+    /// ```
+    pub fn published_raw(&self) -> Option<String> {
+        self.published_raw.clone()
+    }
+
+
+    /// Get the optional rights that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let rights = "Copyright (c) 2003, Mark Pilgrim".to_owned();
+    /// let entry_obj = EntryBuilder::new()
+    ///     .rights(Some(rights.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(rights), entry_obj.rights());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let entry_obj = EntryBuilder::new()
+    ///     .rights(None)
+    ///     .finalize();
+    /// assert!(entry_obj.rights().is_none());
+    /// ```
+    pub fn rights(&self) -> Option<String> {
+        self.rights.clone()
+    }
+
+
+    /// Get the optional rights type that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let rights_type = "text".to_owned();
+    /// let entry_obj = EntryBuilder::new()
+    ///     .rights_type(Some(rights_type.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(rights_type), entry_obj.rights_type());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let entry_obj = EntryBuilder::new()
+    ///     .rights_type(None)
+    ///     .finalize();
+    /// assert!(entry_obj.rights_type().is_none());
+    /// ```
+    pub fn rights_type(&self) -> Option<String> {
+        self.rights_type.clone()
+    }
+}
@@ -0,0 +1,299 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for entry by using the methods under `EntryBuilder`.
+
+use atom::{Category, Content, Link, Person};
+use util;
+
+use atom::{Entry, EntryBuilder};
+
+impl EntryBuilder {
+    /// Construct a new `EntryBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let entry_builder = EntryBuilder::new();
+    /// ```
+    pub fn new() -> EntryBuilder {
+        EntryBuilder::default()
+    }
+
+
+    /// Set the id that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.id("urn:uuid:1225c695-cfb8-4ebb-aaaa-80da344efa6a");
+    /// ```
+    pub fn id(&mut self, id: &str) -> &mut EntryBuilder {
+        self.id = id.to_owned();
+        self
+    }
+
+
+    /// Set the title that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.title("Atom-Powered Robots Run Amok");
+    /// ```
+    pub fn title(&mut self, title: &str) -> &mut EntryBuilder {
+        self.title = title.to_owned();
+        self
+    }
+
+
+    /// Set the optional title type that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.title_type(Some("html".to_owned()));
+    /// ```
+    pub fn title_type(&mut self, title_type: Option<String>) -> &mut EntryBuilder {
+        self.title_type = title_type;
+        self
+    }
+
+
+    /// Set the optional updated date that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.updated(Some("2003-12-13T18:30:02+00:00".to_owned()));
+    /// ```
+    pub fn updated(&mut self, updated: Option<String>) -> &mut EntryBuilder {
+        self.updated = util::option_string_to_option_date(updated.clone());
+        self.updated_raw = updated.filter(|_| self.updated.is_none());
+        self
+    }
+
+
+    /// Set the optional authors that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::{EntryBuilder, PersonBuilder};
+    ///
+    /// let person = PersonBuilder::new().finalize();
+    /// let authors = vec![person];
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.authors(Some(authors));
+    /// ```
+    pub fn authors(&mut self, authors: Option<Vec<Person>>) -> &mut EntryBuilder {
+        self.authors = authors;
+        self
+    }
+
+
+    /// Set the optional contributors that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::{EntryBuilder, PersonBuilder};
+    ///
+    /// let person = PersonBuilder::new().finalize();
+    /// let contributors = vec![person];
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.contributors(Some(contributors));
+    /// ```
+    pub fn contributors(&mut self, contributors: Option<Vec<Person>>) -> &mut EntryBuilder {
+        self.contributors = contributors;
+        self
+    }
+
+
+    /// Set the optional links that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::{EntryBuilder, LinkBuilder};
+    ///
+    /// let link = LinkBuilder::new().finalize();
+    /// let links = vec![link];
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.links(Some(links));
+    /// ```
+    pub fn links(&mut self, links: Option<Vec<Link>>) -> &mut EntryBuilder {
+        self.links = links;
+        self
+    }
+
+
+    /// Set the optional categories that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::{EntryBuilder, CategoryBuilder};
+    ///
+    /// let category = CategoryBuilder::new().finalize();
+    /// let categories = vec![category];
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.categories(Some(categories));
+    /// ```
+    pub fn categories(&mut self, categories: Option<Vec<Category>>) -> &mut EntryBuilder {
+        self.categories = categories;
+        self
+    }
+
+
+    /// Set the optional summary that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.summary(Some("Some text.".to_owned()));
+    /// ```
+    pub fn summary(&mut self, summary: Option<String>) -> &mut EntryBuilder {
+        self.summary = summary;
+        self
+    }
+
+
+    /// Set the optional summary type that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.summary_type(Some("text".to_owned()));
+    /// ```
+    pub fn summary_type(&mut self, summary_type: Option<String>) -> &mut EntryBuilder {
+        self.summary_type = summary_type;
+        self
+    }
+
+
+    /// Set the optional content that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::{EntryBuilder, ContentBuilder};
+    ///
+    /// let content = ContentBuilder::new().finalize();
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.content(Some(content));
+    /// ```
+    pub fn content(&mut self, content: Option<Content>) -> &mut EntryBuilder {
+        self.content = content;
+        self
+    }
+
+
+    /// Set the optional published date that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.published(Some("2003-12-13T08:29:29-04:00".to_owned()));
+    /// ```
+    pub fn published(&mut self, published: Option<String>) -> &mut EntryBuilder {
+        self.published = util::option_string_to_option_date(published.clone());
+        self.published_raw = published.filter(|_| self.published.is_none());
+        self
+    }
+
+
+    /// Set the optional rights that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.rights(Some("Copyright (c) 2003, Mark Pilgrim".to_owned()));
+    /// ```
+    pub fn rights(&mut self, rights: Option<String>) -> &mut EntryBuilder {
+        self.rights = rights;
+        self
+    }
+
+
+    /// Set the optional rights type that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.rights_type(Some("text".to_owned()));
+    /// ```
+    pub fn rights_type(&mut self, rights_type: Option<String>) -> &mut EntryBuilder {
+        self.rights_type = rights_type;
+        self
+    }
+
+
+    /// Construct the `Entry` from the `EntryBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::EntryBuilder;
+    ///
+    /// let entry = EntryBuilder::new()
+    ///         .id("urn:uuid:1225c695-cfb8-4ebb-aaaa-80da344efa6a")
+    ///         .title("Atom-Powered Robots Run Amok")
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> Entry {
+        Entry {
+            id: self.id.clone(),
+            title: self.title.clone(),
+            title_type: self.title_type.clone(),
+            updated: self.updated,
+            updated_raw: self.updated_raw.clone(),
+            authors: self.authors.clone(),
+            contributors: self.contributors.clone(),
+            links: self.links.clone(),
+            categories: self.categories.clone(),
+            summary: self.summary.clone(),
+            summary_type: self.summary_type.clone(),
+            content: self.content.clone(),
+            published: self.published,
+            published_raw: self.published_raw.clone(),
+            rights: self.rights.clone(),
+            rights_type: self.rights_type.clone(),
+        }
+    }
+}
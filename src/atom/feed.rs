@@ -0,0 +1,470 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under feed can be retrieved by using the methods under `Feed`.
+
+use atom::{Category, Entry, Feed, Link, Person};
+use chrono::*;
+
+impl Feed {
+    /// Get the id that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let id = "urn:uuid:60a76c80-d399-11d9-b93C-0003939e0af6";
+    /// let feed_obj = FeedBuilder::new()
+    ///     .id(id)
+    ///     .finalize();
+    /// assert_eq!(id.to_owned(), feed_obj.id());
+    /// ```
+    pub fn id(&self) -> String {
+        self.id.clone()
+    }
+
+
+    /// Get the title that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let title = "Example Feed";
+    /// let feed_obj = FeedBuilder::new()
+    ///     .title(title)
+    ///     .finalize();
+    /// assert_eq!(title.to_owned(), feed_obj.title());
+    /// ```
+    pub fn title(&self) -> String {
+        self.title.clone()
+    }
+
+
+    /// Get the optional title type that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let title_type = "text".to_owned();
+    /// let feed_obj = FeedBuilder::new()
+    ///     .title_type(Some(title_type.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(title_type), feed_obj.title_type());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let feed_obj = FeedBuilder::new()
+    ///     .title_type(None)
+    ///     .finalize();
+    /// assert!(feed_obj.title_type().is_none());
+    /// ```
+    pub fn title_type(&self) -> Option<String> {
+        self.title_type.clone()
+    }
+
+
+    /// Get the optional updated date that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let updated = "2003-12-13T18:30:02+00:00";
+    /// let feed_obj = FeedBuilder::new()
+    ///     .updated(Some(updated.to_owned()))
+    ///     .finalize();
+    /// let local = feed_obj.updated();
+    /// assert!(local.is_some());
+    /// assert_eq!(updated.to_owned(), local.unwrap().to_rfc3339());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let feed_obj = FeedBuilder::new()
+    ///     .updated(None)
+    ///     .finalize();
+    /// assert!(feed_obj.updated().is_none());
+    /// ```
+    pub fn updated(&self) -> Option<DateTime<FixedOffset>> {
+        self.updated
+    }
+
+
+    /// Get the text of the optional updated date that exists under `Feed` when
+    /// it could not be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let feed_obj = FeedBuilder::new()
+    ///     .updated(Some("2003-12-13 18:30:02".to_owned()))
+    ///     .finalize();
+    /// assert!(feed_obj.updated().is_some());
+    /// assert!(feed_obj.updated_raw().is_none());
+    ///
+    /// let feed_obj = FeedBuilder::new()
+    ///     .updated(Some("last Saturday".to_owned()))
+    ///     .finalize();
+    /// assert!(feed_obj.updated().is_none());
+    /// assert_eq!(Some("last Saturday".to_owned()), feed_obj.updated_raw());
+    /// ```
+    pub fn updated_raw(&self) -> Option<String> {
+        self.updated_raw.clone()
+    }
+
+
+    /// Get the optional authors that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::{FeedBuilder, PersonBuilder};
+    ///
+    /// let person_1 = PersonBuilder::new().finalize();
+    /// let person_2 = PersonBuilder::new().finalize();
+    /// let authors_vec = vec![person_1, person_2];
+    /// let feed_obj = FeedBuilder::new()
+    ///     .authors(Some(authors_vec.clone()))
+    ///     .finalize();
+    /// let authors_option = feed_obj.authors();
+    /// assert!(authors_option.is_some());
+    /// assert_eq!(authors_vec.len(), authors_option.unwrap().len());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let feed_obj = FeedBuilder::new()
+    ///     .authors(None)
+    ///     .finalize();
+    /// assert!(feed_obj.authors().is_none());
+    /// ```
+    pub fn authors(&self) -> Option<Vec<Person>> {
+        self.authors.clone()
+    }
+
+
+    /// Get the optional contributors that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::{FeedBuilder, PersonBuilder};
+    ///
+    /// let person_1 = PersonBuilder::new().finalize();
+    /// let person_2 = PersonBuilder::new().finalize();
+    /// let contributors_vec = vec![person_1, person_2];
+    /// let feed_obj = FeedBuilder::new()
+    ///     .contributors(Some(contributors_vec.clone()))
+    ///     .finalize();
+    /// let contributors_option = feed_obj.contributors();
+    /// assert!(contributors_option.is_some());
+    /// assert_eq!(contributors_vec.len(), contributors_option.unwrap().len());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let feed_obj = FeedBuilder::new()
+    ///     .contributors(None)
+    ///     .finalize();
+    /// assert!(feed_obj.contributors().is_none());
+    /// ```
+    pub fn contributors(&self) -> Option<Vec<Person>> {
+        self.contributors.clone()
+    }
+
+
+    /// Get the optional links that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::{FeedBuilder, LinkBuilder};
+    ///
+    /// let link_1 = LinkBuilder::new().finalize();
+    /// let link_2 = LinkBuilder::new().finalize();
+    /// let links_vec = vec![link_1, link_2];
+    /// let feed_obj = FeedBuilder::new()
+    ///     .links(Some(links_vec.clone()))
+    ///     .finalize();
+    /// let links_option = feed_obj.links();
+    /// assert!(links_option.is_some());
+    /// assert_eq!(links_vec.len(), links_option.unwrap().len());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let feed_obj = FeedBuilder::new()
+    ///     .links(None)
+    ///     .finalize();
+    /// assert!(feed_obj.links().is_none());
+    /// ```
+    pub fn links(&self) -> Option<Vec<Link>> {
+        self.links.clone()
+    }
+
+
+    /// Get the optional categories that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::{FeedBuilder, CategoryBuilder};
+    ///
+    /// let category_1 = CategoryBuilder::new().finalize();
+    /// let category_2 = CategoryBuilder::new().finalize();
+    /// let categories_vec = vec![category_1, category_2];
+    /// let feed_obj = FeedBuilder::new()
+    ///     .categories(Some(categories_vec.clone()))
+    ///     .finalize();
+    /// let categories_option = feed_obj.categories();
+    /// assert!(categories_option.is_some());
+    /// assert_eq!(categories_vec.len(), categories_option.unwrap().len());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let feed_obj = FeedBuilder::new()
+    ///     .categories(None)
+    ///     .finalize();
+    /// assert!(feed_obj.categories().is_none());
+    /// ```
+    pub fn categories(&self) -> Option<Vec<Category>> {
+        self.categories.clone()
+    }
+
+
+    /// Get the optional generator that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let generator = "Example Toolkit".to_owned();
+    /// let feed_obj = FeedBuilder::new()
+    ///     .generator(Some(generator.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(generator), feed_obj.generator());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let feed_obj = FeedBuilder::new()
+    ///     .generator(None)
+    ///     .finalize();
+    /// assert!(feed_obj.generator().is_none());
+    /// ```
+    pub fn generator(&self) -> Option<String> {
+        self.generator.clone()
+    }
+
+
+    /// Get the optional icon that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let icon = "http://example.com/favicon.ico".to_owned();
+    /// let feed_obj = FeedBuilder::new()
+    ///     .icon(Some(icon.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(icon), feed_obj.icon());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let feed_obj = FeedBuilder::new()
+    ///     .icon(None)
+    ///     .finalize();
+    /// assert!(feed_obj.icon().is_none());
+    /// ```
+    pub fn icon(&self) -> Option<String> {
+        self.icon.clone()
+    }
+
+
+    /// Get the optional logo that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let logo = "http://example.com/logo.png".to_owned();
+    /// let feed_obj = FeedBuilder::new()
+    ///     .logo(Some(logo.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(logo), feed_obj.logo());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let feed_obj = FeedBuilder::new()
+    ///     .logo(None)
+    ///     .finalize();
+    /// assert!(feed_obj.logo().is_none());
+    /// ```
+    pub fn logo(&self) -> Option<String> {
+        self.logo.clone()
+    }
+
+
+    /// Get the optional rights that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let rights = "Copyright (c) 2003, Mark Pilgrim".to_owned();
+    /// let feed_obj = FeedBuilder::new()
+    ///     .rights(Some(rights.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(rights), feed_obj.rights());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let feed_obj = FeedBuilder::new()
+    ///     .rights(None)
+    ///     .finalize();
+    /// assert!(feed_obj.rights().is_none());
+    /// ```
+    pub fn rights(&self) -> Option<String> {
+        self.rights.clone()
+    }
+
+
+    /// Get the optional rights type that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let rights_type = "text".to_owned();
+    /// let feed_obj = FeedBuilder::new()
+    ///     .rights_type(Some(rights_type.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(rights_type), feed_obj.rights_type());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let feed_obj = FeedBuilder::new()
+    ///     .rights_type(None)
+    ///     .finalize();
+    /// assert!(feed_obj.rights_type().is_none());
+    /// ```
+    pub fn rights_type(&self) -> Option<String> {
+        self.rights_type.clone()
+    }
+
+
+    /// Get the optional subtitle that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let subtitle = "A subtitle.".to_owned();
+    /// let feed_obj = FeedBuilder::new()
+    ///     .subtitle(Some(subtitle.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(subtitle), feed_obj.subtitle());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let feed_obj = FeedBuilder::new()
+    ///     .subtitle(None)
+    ///     .finalize();
+    /// assert!(feed_obj.subtitle().is_none());
+    /// ```
+    pub fn subtitle(&self) -> Option<String> {
+        self.subtitle.clone()
+    }
+
+
+    /// Get the optional subtitle type that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let subtitle_type = "html".to_owned();
+    /// let feed_obj = FeedBuilder::new()
+    ///     .subtitle_type(Some(subtitle_type.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(subtitle_type), feed_obj.subtitle_type());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let feed_obj = FeedBuilder::new()
+    ///     .subtitle_type(None)
+    ///     .finalize();
+    /// assert!(feed_obj.subtitle_type().is_none());
+    /// ```
+    pub fn subtitle_type(&self) -> Option<String> {
+        self.subtitle_type.clone()
+    }
+
+
+    /// Get the optional entries that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::{FeedBuilder, EntryBuilder};
+    ///
+    /// let entry_1 = EntryBuilder::new().finalize();
+    /// let entry_2 = EntryBuilder::new().finalize();
+    /// let entries_vec = vec![entry_1, entry_2];
+    /// let feed_obj = FeedBuilder::new()
+    ///     .entries(Some(entries_vec.clone()))
+    ///     .finalize();
+    /// let entries_option = feed_obj.entries();
+    /// assert!(entries_option.is_some());
+    /// assert_eq!(entries_vec.len(), entries_option.unwrap().len());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let feed_obj = FeedBuilder::new()
+    ///     .entries(None)
+    ///     .finalize();
+    /// assert!(feed_obj.entries().is_none());
+    /// ```
+    pub fn entries(&self) -> Option<Vec<Entry>> {
+        self.entries.clone()
+    }
+}
@@ -0,0 +1,332 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for feed by using the methods under `FeedBuilder`.
+
+use atom::{Category, Entry, Link, Person};
+use util;
+
+use atom::{Feed, FeedBuilder};
+
+impl FeedBuilder {
+    /// Construct a new `FeedBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let feed_builder = FeedBuilder::new();
+    /// ```
+    pub fn new() -> FeedBuilder {
+        FeedBuilder::default()
+    }
+
+
+    /// Set the id that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.id("urn:uuid:60a76c80-d399-11d9-b93C-0003939e0af6");
+    /// ```
+    pub fn id(&mut self, id: &str) -> &mut FeedBuilder {
+        self.id = id.to_owned();
+        self
+    }
+
+
+    /// Set the title that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.title("Example Feed");
+    /// ```
+    pub fn title(&mut self, title: &str) -> &mut FeedBuilder {
+        self.title = title.to_owned();
+        self
+    }
+
+
+    /// Set the optional title type that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.title_type(Some("text".to_owned()));
+    /// ```
+    pub fn title_type(&mut self, title_type: Option<String>) -> &mut FeedBuilder {
+        self.title_type = title_type;
+        self
+    }
+
+
+    /// Set the optional updated date that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.updated(Some("2003-12-13T18:30:02+00:00".to_owned()));
+    /// ```
+    pub fn updated(&mut self, updated: Option<String>) -> &mut FeedBuilder {
+        self.updated = util::option_string_to_option_date(updated.clone());
+        self.updated_raw = updated.filter(|_| self.updated.is_none());
+        self
+    }
+
+
+    /// Set the optional authors that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::{FeedBuilder, PersonBuilder};
+    ///
+    /// let person = PersonBuilder::new().finalize();
+    /// let authors = vec![person];
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.authors(Some(authors));
+    /// ```
+    pub fn authors(&mut self, authors: Option<Vec<Person>>) -> &mut FeedBuilder {
+        self.authors = authors;
+        self
+    }
+
+
+    /// Set the optional contributors that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::{FeedBuilder, PersonBuilder};
+    ///
+    /// let person = PersonBuilder::new().finalize();
+    /// let contributors = vec![person];
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.contributors(Some(contributors));
+    /// ```
+    pub fn contributors(&mut self, contributors: Option<Vec<Person>>) -> &mut FeedBuilder {
+        self.contributors = contributors;
+        self
+    }
+
+
+    /// Set the optional links that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::{FeedBuilder, LinkBuilder};
+    ///
+    /// let link = LinkBuilder::new().finalize();
+    /// let links = vec![link];
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.links(Some(links));
+    /// ```
+    pub fn links(&mut self, links: Option<Vec<Link>>) -> &mut FeedBuilder {
+        self.links = links;
+        self
+    }
+
+
+    /// Set the optional categories that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::{FeedBuilder, CategoryBuilder};
+    ///
+    /// let category = CategoryBuilder::new().finalize();
+    /// let categories = vec![category];
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.categories(Some(categories));
+    /// ```
+    pub fn categories(&mut self, categories: Option<Vec<Category>>) -> &mut FeedBuilder {
+        self.categories = categories;
+        self
+    }
+
+
+    /// Set the optional generator that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.generator(Some("Example Toolkit".to_owned()));
+    /// ```
+    pub fn generator(&mut self, generator: Option<String>) -> &mut FeedBuilder {
+        self.generator = generator;
+        self
+    }
+
+
+    /// Set the optional icon that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.icon(Some("http://example.com/favicon.ico".to_owned()));
+    /// ```
+    pub fn icon(&mut self, icon: Option<String>) -> &mut FeedBuilder {
+        self.icon = icon;
+        self
+    }
+
+
+    /// Set the optional logo that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.logo(Some("http://example.com/logo.png".to_owned()));
+    /// ```
+    pub fn logo(&mut self, logo: Option<String>) -> &mut FeedBuilder {
+        self.logo = logo;
+        self
+    }
+
+
+    /// Set the optional rights that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.rights(Some("Copyright (c) 2003, Mark Pilgrim".to_owned()));
+    /// ```
+    pub fn rights(&mut self, rights: Option<String>) -> &mut FeedBuilder {
+        self.rights = rights;
+        self
+    }
+
+
+    /// Set the optional rights type that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.rights_type(Some("text".to_owned()));
+    /// ```
+    pub fn rights_type(&mut self, rights_type: Option<String>) -> &mut FeedBuilder {
+        self.rights_type = rights_type;
+        self
+    }
+
+
+    /// Set the optional subtitle that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.subtitle(Some("A subtitle.".to_owned()));
+    /// ```
+    pub fn subtitle(&mut self, subtitle: Option<String>) -> &mut FeedBuilder {
+        self.subtitle = subtitle;
+        self
+    }
+
+
+    /// Set the optional subtitle type that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.subtitle_type(Some("html".to_owned()));
+    /// ```
+    pub fn subtitle_type(&mut self, subtitle_type: Option<String>) -> &mut FeedBuilder {
+        self.subtitle_type = subtitle_type;
+        self
+    }
+
+
+    /// Set the optional entries that exists under `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::{FeedBuilder, EntryBuilder};
+    ///
+    /// let entry = EntryBuilder::new().finalize();
+    /// let entries = vec![entry];
+    ///
+    /// let mut feed_builder = FeedBuilder::new();
+    /// feed_builder.entries(Some(entries));
+    /// ```
+    pub fn entries(&mut self, entries: Option<Vec<Entry>>) -> &mut FeedBuilder {
+        self.entries = entries;
+        self
+    }
+
+
+    /// Construct the `Feed` from the `FeedBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    ///
+    /// let feed = FeedBuilder::new()
+    ///         .id("urn:uuid:60a76c80-d399-11d9-b93C-0003939e0af6")
+    ///         .title("Example Feed")
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> Feed {
+        Feed {
+            id: self.id.clone(),
+            title: self.title.clone(),
+            title_type: self.title_type.clone(),
+            updated: self.updated,
+            updated_raw: self.updated_raw.clone(),
+            authors: self.authors.clone(),
+            contributors: self.contributors.clone(),
+            links: self.links.clone(),
+            categories: self.categories.clone(),
+            generator: self.generator.clone(),
+            icon: self.icon.clone(),
+            logo: self.logo.clone(),
+            rights: self.rights.clone(),
+            rights_type: self.rights_type.clone(),
+            subtitle: self.subtitle.clone(),
+            subtitle_type: self.subtitle_type.clone(),
+            entries: self.entries.clone(),
+        }
+    }
+}
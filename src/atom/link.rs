@@ -0,0 +1,161 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under link can be retrieved by using the methods under `Link`.
+
+use atom::Link;
+
+impl Link {
+    /// Get the href that exists under `Link`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::LinkBuilder;
+    ///
+    /// let href = "http://example.com/2003/12/13/atom03";
+    /// let link_obj = LinkBuilder::new()
+    ///     .href(href)
+    ///     .finalize();
+    /// assert_eq!(href.to_owned(), link_obj.href());
+    /// ```
+    pub fn href(&self) -> String {
+        self.href.clone()
+    }
+
+
+    /// Get the optional rel that exists under `Link`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::LinkBuilder;
+    ///
+    /// let rel = "alternate".to_owned();
+    /// let link_obj = LinkBuilder::new()
+    ///     .rel(Some(rel.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(rel), link_obj.rel());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::LinkBuilder;
+    ///
+    /// let link_obj = LinkBuilder::new()
+    ///     .rel(None)
+    ///     .finalize();
+    /// assert!(link_obj.rel().is_none());
+    /// ```
+    pub fn rel(&self) -> Option<String> {
+        self.rel.clone()
+    }
+
+
+    /// Get the optional type that exists under `Link`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::LinkBuilder;
+    ///
+    /// let link_type = "text/html".to_owned();
+    /// let link_obj = LinkBuilder::new()
+    ///     .link_type(Some(link_type.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(link_type), link_obj.link_type());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::LinkBuilder;
+    ///
+    /// let link_obj = LinkBuilder::new()
+    ///     .link_type(None)
+    ///     .finalize();
+    /// assert!(link_obj.link_type().is_none());
+    /// ```
+    pub fn link_type(&self) -> Option<String> {
+        self.link_type.clone()
+    }
+
+
+    /// Get the optional hreflang that exists under `Link`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::LinkBuilder;
+    ///
+    /// let hreflang = "en".to_owned();
+    /// let link_obj = LinkBuilder::new()
+    ///     .hreflang(Some(hreflang.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(hreflang), link_obj.hreflang());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::LinkBuilder;
+    ///
+    /// let link_obj = LinkBuilder::new()
+    ///     .hreflang(None)
+    ///     .finalize();
+    /// assert!(link_obj.hreflang().is_none());
+    /// ```
+    pub fn hreflang(&self) -> Option<String> {
+        self.hreflang.clone()
+    }
+
+
+    /// Get the optional title that exists under `Link`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::LinkBuilder;
+    ///
+    /// let title = "Atom-Powered Robots Run Amok".to_owned();
+    /// let link_obj = LinkBuilder::new()
+    ///     .title(Some(title.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(title), link_obj.title());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::LinkBuilder;
+    ///
+    /// let link_obj = LinkBuilder::new()
+    ///     .title(None)
+    ///     .finalize();
+    /// assert!(link_obj.title().is_none());
+    /// ```
+    pub fn title(&self) -> Option<String> {
+        self.title.clone()
+    }
+
+
+    /// Get the optional length that exists under `Link`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::LinkBuilder;
+    ///
+    /// let length = 1337;
+    /// let link_obj = LinkBuilder::new()
+    ///     .length(Some(length))
+    ///     .finalize();
+    /// assert_eq!(Some(length), link_obj.length());
+    /// ```
+    ///
+    /// ```
+    /// use feed::atom::LinkBuilder;
+    ///
+    /// let link_obj = LinkBuilder::new()
+    ///     .length(None)
+    ///     .finalize();
+    /// assert!(link_obj.length().is_none());
+    /// ```
+    pub fn length(&self) -> Option<i64> {
+        self.length
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for link by using the methods under `LinkBuilder`.
+
+use atom::{Link, LinkBuilder};
+
+impl LinkBuilder {
+    /// Construct a new `LinkBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::LinkBuilder;
+    ///
+    /// let link_builder = LinkBuilder::new();
+    /// ```
+    pub fn new() -> LinkBuilder {
+        LinkBuilder::default()
+    }
+
+
+    /// Set the href that exists under `Link`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::LinkBuilder;
+    ///
+    /// let mut link_builder = LinkBuilder::new();
+    /// link_builder.href("http://example.com/2003/12/13/atom03");
+    /// ```
+    pub fn href(&mut self, href: &str) -> &mut LinkBuilder {
+        self.href = href.to_owned();
+        self
+    }
+
+
+    /// Set the optional rel that exists under `Link`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::LinkBuilder;
+    ///
+    /// let mut link_builder = LinkBuilder::new();
+    /// link_builder.rel(Some("alternate".to_owned()));
+    /// ```
+    pub fn rel(&mut self, rel: Option<String>) -> &mut LinkBuilder {
+        self.rel = rel;
+        self
+    }
+
+
+    /// Set the optional type that exists under `Link`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::LinkBuilder;
+    ///
+    /// let mut link_builder = LinkBuilder::new();
+    /// link_builder.link_type(Some("text/html".to_owned()));
+    /// ```
+    pub fn link_type(&mut self, link_type: Option<String>) -> &mut LinkBuilder {
+        self.link_type = link_type;
+        self
+    }
+
+
+    /// Set the optional hreflang that exists under `Link`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::LinkBuilder;
+    ///
+    /// let mut link_builder = LinkBuilder::new();
+    /// link_builder.hreflang(Some("en".to_owned()));
+    /// ```
+    pub fn hreflang(&mut self, hreflang: Option<String>) -> &mut LinkBuilder {
+        self.hreflang = hreflang;
+        self
+    }
+
+
+    /// Set the optional title that exists under `Link`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::LinkBuilder;
+    ///
+    /// let mut link_builder = LinkBuilder::new();
+    /// link_builder.title(Some("Atom-Powered Robots Run Amok".to_owned()));
+    /// ```
+    pub fn title(&mut self, title: Option<String>) -> &mut LinkBuilder {
+        self.title = title;
+        self
+    }
+
+
+    /// Set the optional length that exists under `Link`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::LinkBuilder;
+    ///
+    /// let mut link_builder = LinkBuilder::new();
+    /// link_builder.length(Some(1337));
+    /// ```
+    pub fn length(&mut self, length: Option<i64>) -> &mut LinkBuilder {
+        self.length = length;
+        self
+    }
+
+
+    /// Construct the `Link` from the `LinkBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::LinkBuilder;
+    ///
+    /// let link = LinkBuilder::new()
+    ///         .href("http://example.com/2003/12/13/atom03")
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> Link {
+        Link {
+            href: self.href.clone(),
+            rel: self.rel.clone(),
+            link_type: self.link_type.clone(),
+            hreflang: self.hreflang.clone(),
+            title: self.title.clone(),
+            length: self.length,
+        }
+    }
+}
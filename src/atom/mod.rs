@@ -0,0 +1,198 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! All the structs for atom.
+
+pub mod category;
+pub mod category_builder;
+pub mod content;
+pub mod content_builder;
+pub mod entry;
+pub mod entry_builder;
+pub mod feed;
+pub mod feed_builder;
+pub mod link;
+pub mod link_builder;
+pub mod person;
+pub mod person_builder;
+
+use chrono::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// This `Category` struct contains all the items that exist for the category field under `Feed` and `Entry`.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Category {
+    term: String,
+    scheme: Option<String>,
+    label: Option<String>,
+}
+
+
+/// This `CategoryBuilder` struct creates the `Category`.
+#[derive(Default)]
+pub struct CategoryBuilder {
+    term: String,
+    scheme: Option<String>,
+    label: Option<String>,
+}
+
+
+/// This `Content` struct contains all the items that exist for the content field under `Entry`.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Content {
+    content: String,
+    content_type: Option<String>,
+    src: Option<String>,
+}
+
+
+/// This `ContentBuilder` struct creates the `Content`.
+#[derive(Default)]
+pub struct ContentBuilder {
+    content: String,
+    content_type: Option<String>,
+    src: Option<String>,
+}
+
+
+/// This `Entry` struct contains all the items that exist for the entry field under `Feed`.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Entry {
+    id: String,
+    title: String,
+    title_type: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default, with = "::util::serde_date"))]
+    updated: Option<DateTime<FixedOffset>>,
+    updated_raw: Option<String>,
+    authors: Option<Vec<Person>>,
+    contributors: Option<Vec<Person>>,
+    links: Option<Vec<Link>>,
+    categories: Option<Vec<Category>>,
+    summary: Option<String>,
+    summary_type: Option<String>,
+    content: Option<Content>,
+    #[cfg_attr(feature = "serde", serde(default, with = "::util::serde_date"))]
+    published: Option<DateTime<FixedOffset>>,
+    published_raw: Option<String>,
+    rights: Option<String>,
+    rights_type: Option<String>,
+}
+
+
+/// This `EntryBuilder` struct creates the `Entry`.
+#[derive(Default)]
+pub struct EntryBuilder {
+    id: String,
+    title: String,
+    title_type: Option<String>,
+    updated: Option<DateTime<FixedOffset>>,
+    updated_raw: Option<String>,
+    authors: Option<Vec<Person>>,
+    contributors: Option<Vec<Person>>,
+    links: Option<Vec<Link>>,
+    categories: Option<Vec<Category>>,
+    summary: Option<String>,
+    summary_type: Option<String>,
+    content: Option<Content>,
+    published: Option<DateTime<FixedOffset>>,
+    published_raw: Option<String>,
+    rights: Option<String>,
+    rights_type: Option<String>,
+}
+
+
+/// This `Feed` struct contains all the items that exist for the atom feed.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Feed {
+    id: String,
+    title: String,
+    title_type: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default, with = "::util::serde_date"))]
+    updated: Option<DateTime<FixedOffset>>,
+    updated_raw: Option<String>,
+    authors: Option<Vec<Person>>,
+    contributors: Option<Vec<Person>>,
+    links: Option<Vec<Link>>,
+    categories: Option<Vec<Category>>,
+    generator: Option<String>,
+    icon: Option<String>,
+    logo: Option<String>,
+    rights: Option<String>,
+    rights_type: Option<String>,
+    subtitle: Option<String>,
+    subtitle_type: Option<String>,
+    entries: Option<Vec<Entry>>,
+}
+
+
+/// This `FeedBuilder` struct creates the `Feed`.
+#[derive(Default)]
+pub struct FeedBuilder {
+    id: String,
+    title: String,
+    title_type: Option<String>,
+    updated: Option<DateTime<FixedOffset>>,
+    updated_raw: Option<String>,
+    authors: Option<Vec<Person>>,
+    contributors: Option<Vec<Person>>,
+    links: Option<Vec<Link>>,
+    categories: Option<Vec<Category>>,
+    generator: Option<String>,
+    icon: Option<String>,
+    logo: Option<String>,
+    rights: Option<String>,
+    rights_type: Option<String>,
+    subtitle: Option<String>,
+    subtitle_type: Option<String>,
+    entries: Option<Vec<Entry>>,
+}
+
+
+/// This `Link` struct contains all the items that exist for the link field under `Feed` and `Entry`.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Link {
+    href: String,
+    rel: Option<String>,
+    link_type: Option<String>,
+    hreflang: Option<String>,
+    title: Option<String>,
+    length: Option<i64>,
+}
+
+
+/// This `LinkBuilder` struct creates the `Link`.
+#[derive(Default)]
+pub struct LinkBuilder {
+    href: String,
+    rel: Option<String>,
+    link_type: Option<String>,
+    hreflang: Option<String>,
+    title: Option<String>,
+    length: Option<i64>,
+}
+
+
+/// This `Person` struct contains all the items that exist for the person field under `Feed` and `Entry`.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Person {
+    name: String,
+    uri: Option<String>,
+    email: Option<String>,
+}
+
+
+/// This `PersonBuilder` struct creates the `Person`.
+#[derive(Default)]
+pub struct PersonBuilder {
+    name: String,
+    uri: Option<String>,
+    email: Option<String>,
+}
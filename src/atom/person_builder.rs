@@ -0,0 +1,90 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for person by using the methods under `PersonBuilder`.
+
+use atom::{Person, PersonBuilder};
+
+impl PersonBuilder {
+    /// Construct a new `PersonBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::PersonBuilder;
+    ///
+    /// let person_builder = PersonBuilder::new();
+    /// ```
+    pub fn new() -> PersonBuilder {
+        PersonBuilder::default()
+    }
+
+
+    /// Set the name that exists under `Person`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::PersonBuilder;
+    ///
+    /// let mut person_builder = PersonBuilder::new();
+    /// person_builder.name("John Doe");
+    /// ```
+    pub fn name(&mut self, name: &str) -> &mut PersonBuilder {
+        self.name = name.to_owned();
+        self
+    }
+
+
+    /// Set the optional uri that exists under `Person`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::PersonBuilder;
+    ///
+    /// let mut person_builder = PersonBuilder::new();
+    /// person_builder.uri(Some("http://example.com/johndoe".to_owned()));
+    /// ```
+    pub fn uri(&mut self, uri: Option<String>) -> &mut PersonBuilder {
+        self.uri = uri;
+        self
+    }
+
+
+    /// Set the optional email that exists under `Person`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::PersonBuilder;
+    ///
+    /// let mut person_builder = PersonBuilder::new();
+    /// person_builder.email(Some("johndoe@example.com".to_owned()));
+    /// ```
+    pub fn email(&mut self, email: Option<String>) -> &mut PersonBuilder {
+        self.email = email;
+        self
+    }
+
+
+    /// Construct the `Person` from the `PersonBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::PersonBuilder;
+    ///
+    /// let person = PersonBuilder::new()
+    ///         .name("John Doe")
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> Person {
+        Person {
+            name: self.name.clone(),
+            uri: self.uri.clone(),
+            email: self.email.clone(),
+        }
+    }
+}
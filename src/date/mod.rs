@@ -0,0 +1,234 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! Lenient parsing of the dates found in feeds.
+//!
+//! Feeds are meant to use RFC 2822 dates, or RFC 3339 dates in RSS 1.0 and
+//! atom, but many use something close to them instead: a missing day of the
+//! week, a two digit year, a named time zone such as `EST`, month names in
+//! another language or text after the date. These are parsed as well.
+
+use chrono::*;
+
+// The formats of ISO 8601 dates with an offset, tried in order.
+const OFFSET_FORMATS: [&str; 4] = ["%Y-%m-%dT%H:%M:%S%z",
+                                   "%Y-%m-%dT%H:%M:%S%.f%z",
+                                   "%Y-%m-%d %H:%M:%S%z",
+                                   "%Y-%m-%d %H:%M:%S %z"];
+
+// The formats of ISO 8601 dates without an offset, read as UTC.
+const NAIVE_FORMATS: [&str; 4] = ["%Y-%m-%dT%H:%M:%S",
+                                  "%Y-%m-%dT%H:%M:%S%.f",
+                                  "%Y-%m-%d %H:%M:%S",
+                                  "%Y-%m-%dT%H:%M"];
+
+// The prefixes of month names in English, German, French, Spanish, Italian,
+// Dutch and Portuguese, checked in order.
+const MONTHS: [(&str, u32); 40] = [("jan", 1), ("jän", 1), ("ene", 1), ("gen", 1),
+                                   ("feb", 2), ("fév", 2), ("fev", 2),
+                                   ("mar", 3), ("mär", 3), ("mrz", 3), ("maa", 3), ("mrt", 3),
+                                   ("apr", 4), ("avr", 4), ("abr", 4),
+                                   ("may", 5), ("mai", 5), ("mag", 5), ("mei", 5),
+                                   ("jun", 6), ("juin", 6), ("giu", 6),
+                                   ("jul", 7), ("juil", 7), ("lug", 7),
+                                   ("aug", 8), ("aoû", 8), ("aou", 8), ("ago", 8),
+                                   ("sep", 9), ("set", 9),
+                                   ("oct", 10), ("okt", 10), ("ott", 10), ("out", 10),
+                                   ("nov", 11),
+                                   ("dec", 12), ("dez", 12), ("déc", 12), ("dic", 12)];
+
+// The offsets in hours of named time zones.
+const ZONES: [(&str, i32); 26] = [("ut", 0), ("utc", 0), ("gmt", 0), ("z", 0), ("wet", 0),
+                                  ("edt", -4), ("est", -5), ("cdt", -5), ("cst", -6),
+                                  ("mdt", -6), ("mst", -7), ("pdt", -7), ("pst", -8),
+                                  ("akdt", -8), ("akst", -9), ("hst", -10),
+                                  ("bst", 1), ("west", 1), ("cet", 1), ("cest", 2),
+                                  ("mez", 1), ("mesz", 2),
+                                  ("eet", 2), ("eest", 3), ("msk", 3), ("jst", 9)];
+
+/// Parse a date of a feed, trying RFC 2822, RFC 3339, other ISO 8601 forms and
+/// then a lenient reading of RFC 2822.
+///
+/// A date without a time is at midnight and a date without a time zone is
+/// in UTC. `None` is returned when no date can be found in the text.
+///
+/// # Examples
+///
+/// ```
+/// use feed::date;
+///
+/// let expected = "2016-03-13T20:02:02-07:00";
+/// for text in &["Sun, 13 Mar 2016 20:02:02 -0700",
+///               "2016-03-13T20:02:02-07:00",
+///               "13 Mar 2016 20:02:02 -0700",
+///               "Sun, 13 Mar 16 20:02:02 PDT",
+///               "Sun, 13 March 2016 20:02:02 -07:00 (Pacific Daylight Time)",
+///               "So, 13 Mär 2016 20:02:02 -0700",
+///               "Sun Mar 13 20:02:02 PDT 2016"] {
+///     assert_eq!(expected.to_owned(), date::parse(text).unwrap().to_rfc3339());
+/// }
+///
+/// assert_eq!("2016-03-13T00:00:00+00:00".to_owned(),
+///            date::parse("2016-03-13").unwrap().to_rfc3339());
+/// assert_eq!("2016-03-13T20:02:00+00:00".to_owned(),
+///            date::parse("13 mars 2016 20:02").unwrap().to_rfc3339());
+/// assert!(date::parse("yesterday").is_none());
+/// ```
+///
+/// A day of the week whose abbreviation is also the one of a month, as the
+/// `mar` of Tuesday in French, Spanish or Italian, is not taken for the
+/// month, and an offset may follow the name of UTC.
+///
+/// ```
+/// use feed::date;
+///
+/// assert_eq!("2016-04-15T10:00:00+02:00".to_owned(),
+///            date::parse("mar., 15 avr. 2016 10:00:00 +0200").unwrap().to_rfc3339());
+/// assert_eq!("2016-04-15T10:00:00+00:00".to_owned(),
+///            date::parse("mar 15 abr 2016 10:00").unwrap().to_rfc3339());
+/// assert_eq!("2016-03-13T20:02:00+01:00".to_owned(),
+///            date::parse("13 Mar 2016 20:02 GMT+1").unwrap().to_rfc3339());
+/// ```
+pub fn parse(date: &str) -> Option<DateTime<FixedOffset>> {
+    let date = date.trim();
+    if let Ok(date) = DateTime::parse_from_rfc2822(date) {
+        return Some(date);
+    }
+
+    if let Ok(date) = DateTime::parse_from_rfc3339(date) {
+        return Some(date);
+    }
+
+    for format in &OFFSET_FORMATS {
+        if let Ok(date) = DateTime::parse_from_str(date, format) {
+            return Some(date);
+        }
+    }
+
+    for format in &NAIVE_FORMATS {
+        if let Ok(date) = NaiveDateTime::parse_from_str(date, format) {
+            return utc(date);
+        }
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+        return utc(date.and_hms(0, 0, 0));
+    }
+
+    parse_lenient(date)
+}
+
+
+// Read a date close to RFC 2822 token by token, skipping the day of the week
+// and anything after the time zone.
+fn parse_lenient(date: &str) -> Option<DateTime<FixedOffset>> {
+    let mut day = None;
+    let mut month = None;
+    let mut year = None;
+    let mut time = None;
+    let mut offset = None;
+
+    let tokens = date.split(|c: char| c.is_whitespace() || c == ',')
+                     .filter(|token| !token.is_empty());
+    for token in tokens {
+        if time.is_none() && token.contains(':') {
+            time = parse_time(token);
+        } else if token.chars().all(|c| c.is_ascii_digit()) {
+            if day.is_none() && token.len() <= 2 && year.is_none() {
+                day = token.parse::<u32>().ok();
+            } else if year.is_none() {
+                year = token.parse::<i32>().ok().map(|year| match token.len() {
+                    1 | 2 if year < 50 => 2000 + year,
+                    1..=3 => 1900 + year,
+                    _ => year,
+                });
+            }
+        } else if year.is_none() && time.is_none() && month_number(token).is_some() {
+            // A later month replaces an earlier one, which is then the day of
+            // the week, as the `mar` of Tuesday in French, Spanish or Italian.
+            month = month_number(token);
+        } else if let (Some((hour, minute, second)), Some(pm)) = (time, meridiem(token)) {
+            time = Some((hour % 12 + if pm { 12 } else { 0 }, minute, second));
+        } else if offset.is_none() && time.is_some() {
+            offset = parse_offset(token);
+        }
+    }
+
+    let (hour, minute, second) = time.unwrap_or((0, 0, 0));
+    let date = NaiveDate::from_ymd_opt(year?, month?, day?)?.and_hms_opt(hour, minute, second)?;
+    let offset = FixedOffset::east_opt(offset.unwrap_or(0))?;
+    offset.from_local_datetime(&date).single()
+}
+
+
+// Read a time of hours, minutes and optional seconds, leaving out fractions.
+fn parse_time(token: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = token.split('.').next()?.split(':');
+    let hour = parts.next()?.parse().ok()?;
+    let minute = parts.next()?.parse().ok()?;
+    let second = match parts.next() {
+        Some(second) => second.parse().ok()?,
+        None => 0,
+    };
+    Some((hour, minute, second))
+}
+
+
+// Read an offset such as `-0700`, `-07:00`, `-7`, `PDT` or `GMT-7` to seconds
+// east of UTC.
+fn parse_offset(token: &str) -> Option<i32> {
+    let token = token.to_lowercase();
+    if let Some(&(_, hours)) = ZONES.iter().find(|&&(name, _)| name == token) {
+        return Some(hours * 3600);
+    }
+
+    let token = ["gmt", "utc", "ut"].iter()
+                                    .find_map(|name| token.strip_prefix(name))
+                                    .unwrap_or(&token);
+    let sign = match token.chars().next()? {
+        '+' => 1,
+        '-' => -1,
+        _ => return None,
+    };
+    let digits = token[1..].replace(':', "");
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let (hours, minutes) = match digits.len() {
+        1 | 2 => (digits.as_str(), "0"),
+        4 => (&digits[..2], &digits[2..]),
+        _ => return None,
+    };
+    let hours = hours.parse::<i32>().ok()?;
+    let minutes = minutes.parse::<i32>().ok()?;
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+
+// Get the number of a month from its name or abbreviation.
+fn month_number(token: &str) -> Option<u32> {
+    let token = token.trim_end_matches('.').to_lowercase();
+    if token.chars().count() < 3 {
+        return None;
+    }
+    MONTHS.iter()
+          .find(|&&(prefix, _)| token.starts_with(prefix))
+          .map(|&(_, month)| month)
+}
+
+
+// Read `am` or `pm`, returning whether the time is after noon.
+fn meridiem(token: &str) -> Option<bool> {
+    match token.replace('.', "").to_lowercase().as_str() {
+        "am" => Some(false),
+        "pm" => Some(true),
+        _ => None,
+    }
+}
+
+
+// Get the date and time without an offset in UTC.
+fn utc(date: NaiveDateTime) -> Option<DateTime<FixedOffset>> {
+    FixedOffset::east(0).from_local_datetime(&date).single()
+}
@@ -2,67 +2,293 @@
 // Use of this source code is governed by the LGPLv3 license that can be
 // found in the LICENSE file.
 
-// utf8 to str error.
-pub fn utf8_to_str_error() -> &'static str {
-    "Error converting utf8 to str"
-}
+//! The errors returned when a feed cannot be read or written.
 
+use quick_xml::error::Error as XmlError;
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::str::Utf8Error;
 
-// str to bool error.
-pub fn str_to_bool_error() -> &'static str {
-    "Error converting str to bool"
+/// This `FeedError` enum contains all the ways reading or writing a feed can fail.
+///
+/// Errors raised while parsing carry the byte position in the document and the
+/// path of the element being read, e.g. `/rss/channel/ttl`.
+#[derive(Debug)]
+pub enum FeedError {
+    /// The document is not well formed xml.
+    Xml {
+        /// Description of the syntax error.
+        message: String,
+        /// Byte position in the document.
+        position: usize,
+        /// Path of the element being read.
+        path: String,
+    },
+    /// An element or attribute that must contain an integer does not.
+    InvalidInteger {
+        /// The text that could not be converted.
+        value: String,
+        /// Byte position in the document.
+        position: usize,
+        /// Path of the element being read.
+        path: String,
+    },
+    /// The document, or the text of an element or attribute, is not valid utf8.
+    Utf8 {
+        /// Byte position in the document.
+        position: usize,
+        /// Path of the element being read.
+        path: String,
+    },
+    /// A required element is missing.
+    MissingElement {
+        /// Name of the missing element.
+        element: String,
+        /// Byte position in the document.
+        position: usize,
+        /// Path of the element being read.
+        path: String,
+    },
+    /// A required attribute is missing.
+    MissingAttribute {
+        /// Name of the missing attribute.
+        attribute: String,
+        /// Byte position in the document.
+        position: usize,
+        /// Path of the element being read.
+        path: String,
+    },
+    /// The root element is not the one of the expected feed format.
+    UnknownFormat {
+        /// Qualified name of the root element.
+        element: String,
+        /// Byte position in the document.
+        position: usize,
+        /// Path of the element being read.
+        path: String,
+    },
+    /// The document is not valid json, or a json value has the wrong type.
+    Json {
+        /// Description of the error.
+        message: String,
+        /// Json pointer of the value being read, e.g. `/items/0/title`.
+        path: String,
+    },
+    /// The server answered with a non-2xx status.
+    Http {
+        /// The http status code of the response.
+        status: u32,
+    },
+    /// The request could not be sent or the response could not be received.
+    Transport {
+        /// Description of the failure.
+        message: String,
+    },
+    /// The document could not be read from its file or reader.
+    Io {
+        /// Description of the failure.
+        message: String,
+    },
+    /// The response is neither declared nor recognized as a feed.
+    NotAFeed {
+        /// The content type of the response, if any.
+        content_type: Option<String>,
+    },
+    /// The label does not name a character encoding.
+    UnknownEncoding {
+        /// The label, e.g. `ISO-8859-1`.
+        label: String,
+    },
 }
 
 
-// str to datetime error.
-pub fn str_to_datetime_error() -> &'static str {
-    "Error converting str to datetime"
-}
+impl FeedError {
+    /// Get the byte position in the document where the error occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::FeedReader;
+    ///
+    /// let xml = "<rss><channel><item><title>Title</title><enclosure length=\"42\"/></item></channel></rss>";
+    /// let error = FeedReader::parse(xml).err().unwrap();
+    /// assert_eq!(40, error.position());
+    /// ```
+    pub fn position(&self) -> usize {
+        match *self {
+            FeedError::Xml { position, .. } |
+            FeedError::InvalidInteger { position, .. } |
+            FeedError::Utf8 { position, .. } |
+            FeedError::MissingElement { position, .. } |
+            FeedError::MissingAttribute { position, .. } |
+            FeedError::UnknownFormat { position, .. } => position,
+            FeedError::Json { .. } |
+            FeedError::Http { .. } |
+            FeedError::Transport { .. } |
+            FeedError::Io { .. } |
+            FeedError::NotAFeed { .. } |
+            FeedError::UnknownEncoding { .. } => 0,
+        }
+    }
 
 
-// str to i64 error.
-pub fn str_to_i64_error() -> &'static str {
-    "Error converting str to i64"
-}
+    /// Get the path of the element being read when the error occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::FeedReader;
+    ///
+    /// let xml = "<rss><channel><item><title>Title</title><enclosure length=\"42\"/></item></channel></rss>";
+    /// let error = FeedReader::parse(xml).err().unwrap();
+    /// assert_eq!("/rss/channel/item/enclosure", error.path());
+    /// ```
+    pub fn path(&self) -> &str {
+        match *self {
+            FeedError::Xml { ref path, .. } |
+            FeedError::InvalidInteger { ref path, .. } |
+            FeedError::Utf8 { ref path, .. } |
+            FeedError::MissingElement { ref path, .. } |
+            FeedError::MissingAttribute { ref path, .. } |
+            FeedError::UnknownFormat { ref path, .. } |
+            FeedError::Json { ref path, .. } => path,
+            FeedError::Http { .. } |
+            FeedError::Transport { .. } |
+            FeedError::Io { .. } |
+            FeedError::NotAFeed { .. } |
+            FeedError::UnknownEncoding { .. } => "",
+        }
+    }
 
 
-// element to string error.
-pub fn element_to_string_error() -> &'static str {
-    "Error converting element to string"
+    // Set the position and element path of an error raised without context.
+    pub(crate) fn at(mut self, at: usize, elements: &[String]) -> FeedError {
+        match self {
+            FeedError::Xml { ref mut position, ref mut path, .. } |
+            FeedError::InvalidInteger { ref mut position, ref mut path, .. } |
+            FeedError::Utf8 { ref mut position, ref mut path } |
+            FeedError::MissingElement { ref mut position, ref mut path, .. } |
+            FeedError::MissingAttribute { ref mut position, ref mut path, .. } |
+            FeedError::UnknownFormat { ref mut position, ref mut path, .. } => {
+                *position = at;
+                *path = format!("/{}", elements.join("/"));
+            }
+            FeedError::Json { .. } |
+            FeedError::Http { .. } |
+            FeedError::Transport { .. } |
+            FeedError::Io { .. } |
+            FeedError::NotAFeed { .. } |
+            FeedError::UnknownEncoding { .. } => (),
+        }
+        self
+    }
 }
 
 
-// response error
-pub fn response_error() -> &'static str {
-    "Error retrieving response"
+impl fmt::Display for FeedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FeedError::Xml { ref message, .. } => write!(f, "Malformed xml: {}", message)?,
+            FeedError::InvalidInteger { ref value, .. } => {
+                write!(f, "Error converting {:?} to an integer", value)?
+            }
+            FeedError::Utf8 { .. } => write!(f, "Error converting utf8 to str")?,
+            FeedError::MissingElement { ref element, .. } => {
+                write!(f, "Missing required element {}", element)?
+            }
+            FeedError::MissingAttribute { ref attribute, .. } => {
+                write!(f, "Missing required attribute {}", attribute)?
+            }
+            FeedError::UnknownFormat { ref element, .. } => {
+                write!(f, "Unknown feed format with root element {}", element)?
+            }
+            FeedError::Json { ref message, ref path } => {
+                return write!(f, "Invalid json feed: {} in {}", message, path)
+            }
+            FeedError::Http { status } => return write!(f, "Http status {}", status),
+            FeedError::Transport { ref message } => {
+                return write!(f, "Error retrieving response: {}", message)
+            }
+            FeedError::Io { ref message } => return write!(f, "Error reading feed: {}", message),
+            FeedError::NotAFeed { content_type: Some(ref content_type) } => {
+                return write!(f, "Response with content type {} is not a feed", content_type)
+            }
+            FeedError::NotAFeed { content_type: None } => {
+                return write!(f, "Response is not a feed")
+            }
+            FeedError::UnknownEncoding { ref label } => {
+                return write!(f, "Unknown character encoding {}", label)
+            }
+        }
+        if !self.path().is_empty() {
+            write!(f, " in {}", self.path())?;
+        }
+        write!(f, " at position {}", self.position())
+    }
 }
 
 
-// response error
-pub fn missing_xml_error() -> &'static str {
-    "Url must end with .xml"
+impl Error for FeedError {
+    fn description(&self) -> &str {
+        match *self {
+            FeedError::Xml { .. } => "malformed xml",
+            FeedError::InvalidInteger { .. } => "invalid integer",
+            FeedError::Utf8 { .. } => "invalid utf8",
+            FeedError::MissingElement { .. } => "missing required element",
+            FeedError::MissingAttribute { .. } => "missing required attribute",
+            FeedError::UnknownFormat { .. } => "unknown feed format",
+            FeedError::Json { .. } => "invalid json feed",
+            FeedError::Http { .. } => "http error",
+            FeedError::Transport { .. } => "error retrieving response",
+            FeedError::Io { .. } => "error reading feed",
+            FeedError::NotAFeed { .. } => "not a feed",
+            FeedError::UnknownEncoding { .. } => "unknown character encoding",
+        }
+    }
 }
 
 
-// item required field error
-pub fn item_required_field_error() -> &'static str {
-    "Either Title or Description must have a value"
+impl From<XmlError> for FeedError {
+    fn from(error: XmlError) -> FeedError {
+        match error {
+            XmlError::Io(error) => FeedError::from(error),
+            XmlError::Utf8(_) => {
+                FeedError::Utf8 {
+                    position: 0,
+                    path: String::new(),
+                }
+            }
+            error => {
+                FeedError::Xml {
+                    message: error.to_string(),
+                    position: 0,
+                    path: String::new(),
+                }
+            }
+        }
+    }
 }
 
 
-// xml start tag error
-pub fn tag_start_error(tag: &str) -> String {
-    format!("Error creating start tag for {}", tag)
+impl From<io::Error> for FeedError {
+    fn from(error: io::Error) -> FeedError {
+        FeedError::Io { message: error.to_string() }
+    }
 }
 
 
-// xml start tag error
-pub fn tag_text_error(tag: &str) -> String {
-    format!("Error creating text for {}", tag)
+impl From<Utf8Error> for FeedError {
+    fn from(error: Utf8Error) -> FeedError {
+        FeedError::Utf8 {
+            position: error.valid_up_to(),
+            path: String::new(),
+        }
+    }
 }
 
 
-// xml end tag error
-pub fn tag_end_error(tag: &str) -> String {
-    format!("Error creating end tag for {}", tag)
+// item required field error
+pub fn item_required_field_error() -> &'static str {
+    "Either Title or Description must have a value"
 }
@@ -0,0 +1,322 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under Dublin Core extension can be retrieved by using the methods
+//! under `DublinCoreExtension`.
+
+use extension::{Extension, ExtensionElement, DUBLIN_CORE_NAMESPACE};
+use extension::dublin_core::{self, DublinCoreExtension};
+
+impl DublinCoreExtension {
+    /// Get the titles of the resource that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .titles(vec!["Making Music with Linux".to_owned()])
+    ///     .finalize();
+    /// assert_eq!(vec!["Making Music with Linux".to_owned()], dublin_core.titles());
+    /// ```
+    pub fn titles(&self) -> Vec<String> {
+        self.titles.clone()
+    }
+
+
+    /// Get the creators of the resource that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .creators(vec!["Jane Doe".to_owned()])
+    ///     .finalize();
+    /// assert_eq!(vec!["Jane Doe".to_owned()], dublin_core.creators());
+    /// ```
+    pub fn creators(&self) -> Vec<String> {
+        self.creators.clone()
+    }
+
+
+    /// Get the subjects of the resource that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .subjects(vec!["Linux".to_owned()])
+    ///     .finalize();
+    /// assert_eq!(vec!["Linux".to_owned()], dublin_core.subjects());
+    /// ```
+    pub fn subjects(&self) -> Vec<String> {
+        self.subjects.clone()
+    }
+
+
+    /// Get the descriptions of the resource that exist under
+    /// `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .descriptions(vec!["A show about Linux".to_owned()])
+    ///     .finalize();
+    /// assert_eq!(vec!["A show about Linux".to_owned()], dublin_core.descriptions());
+    /// ```
+    pub fn descriptions(&self) -> Vec<String> {
+        self.descriptions.clone()
+    }
+
+
+    /// Get the publishers of the resource that exist under
+    /// `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .publishers(vec!["Jupiter Broadcasting".to_owned()])
+    ///     .finalize();
+    /// assert_eq!(vec!["Jupiter Broadcasting".to_owned()], dublin_core.publishers());
+    /// ```
+    pub fn publishers(&self) -> Vec<String> {
+        self.publishers.clone()
+    }
+
+
+    /// Get the contributors to the resource that exist under
+    /// `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .contributors(vec!["John Doe".to_owned()])
+    ///     .finalize();
+    /// assert_eq!(vec!["John Doe".to_owned()], dublin_core.contributors());
+    /// ```
+    pub fn contributors(&self) -> Vec<String> {
+        self.contributors.clone()
+    }
+
+
+    /// Get the dates of the resource that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .dates(vec!["2016-03-13T20:02:02-07:00".to_owned()])
+    ///     .finalize();
+    /// assert_eq!(vec!["2016-03-13T20:02:02-07:00".to_owned()], dublin_core.dates());
+    /// ```
+    pub fn dates(&self) -> Vec<String> {
+        self.dates.clone()
+    }
+
+
+    /// Get the types of the resource that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .types(vec!["Sound".to_owned()])
+    ///     .finalize();
+    /// assert_eq!(vec!["Sound".to_owned()], dublin_core.types());
+    /// ```
+    pub fn types(&self) -> Vec<String> {
+        self.types.clone()
+    }
+
+
+    /// Get the formats of the resource that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .formats(vec!["audio/ogg".to_owned()])
+    ///     .finalize();
+    /// assert_eq!(vec!["audio/ogg".to_owned()], dublin_core.formats());
+    /// ```
+    pub fn formats(&self) -> Vec<String> {
+        self.formats.clone()
+    }
+
+
+    /// Get the identifiers of the resource that exist under
+    /// `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .identifiers(vec!["urn:isbn:0-486-27557-4".to_owned()])
+    ///     .finalize();
+    /// assert_eq!(vec!["urn:isbn:0-486-27557-4".to_owned()], dublin_core.identifiers());
+    /// ```
+    pub fn identifiers(&self) -> Vec<String> {
+        self.identifiers.clone()
+    }
+
+
+    /// Get the sources the resource is derived from that exist under
+    /// `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .sources(vec!["http://example.com/original".to_owned()])
+    ///     .finalize();
+    /// assert_eq!(vec!["http://example.com/original".to_owned()], dublin_core.sources());
+    /// ```
+    pub fn sources(&self) -> Vec<String> {
+        self.sources.clone()
+    }
+
+
+    /// Get the languages of the resource that exist under
+    /// `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .languages(vec!["en".to_owned()])
+    ///     .finalize();
+    /// assert_eq!(vec!["en".to_owned()], dublin_core.languages());
+    /// ```
+    pub fn languages(&self) -> Vec<String> {
+        self.languages.clone()
+    }
+
+
+    /// Get the related resources that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .relations(vec!["http://example.com/related".to_owned()])
+    ///     .finalize();
+    /// assert_eq!(vec!["http://example.com/related".to_owned()], dublin_core.relations());
+    /// ```
+    pub fn relations(&self) -> Vec<String> {
+        self.relations.clone()
+    }
+
+
+    /// Get the coverages of the resource that exist under
+    /// `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .coverages(vec!["2016".to_owned()])
+    ///     .finalize();
+    /// assert_eq!(vec!["2016".to_owned()], dublin_core.coverages());
+    /// ```
+    pub fn coverages(&self) -> Vec<String> {
+        self.coverages.clone()
+    }
+
+
+    /// Get the rights held in the resource that exist under
+    /// `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .rights(vec!["Public domain".to_owned()])
+    ///     .finalize();
+    /// assert_eq!(vec!["Public domain".to_owned()], dublin_core.rights());
+    /// ```
+    pub fn rights(&self) -> Vec<String> {
+        self.rights.clone()
+    }
+}
+
+
+impl Extension for DublinCoreExtension {
+    const NAMESPACE: &'static str = DUBLIN_CORE_NAMESPACE;
+    const PREFIX: &'static str = "dc";
+
+
+    fn from_elements(elements: &[ExtensionElement]) -> Option<DublinCoreExtension> {
+        Some(DublinCoreExtension {
+            titles: dublin_core::read_texts(elements, "title"),
+            creators: dublin_core::read_texts(elements, "creator"),
+            subjects: dublin_core::read_texts(elements, "subject"),
+            descriptions: dublin_core::read_texts(elements, "description"),
+            publishers: dublin_core::read_texts(elements, "publisher"),
+            contributors: dublin_core::read_texts(elements, "contributor"),
+            dates: dublin_core::read_texts(elements, "date"),
+            types: dublin_core::read_texts(elements, "type"),
+            formats: dublin_core::read_texts(elements, "format"),
+            identifiers: dublin_core::read_texts(elements, "identifier"),
+            sources: dublin_core::read_texts(elements, "source"),
+            languages: dublin_core::read_texts(elements, "language"),
+            relations: dublin_core::read_texts(elements, "relation"),
+            coverages: dublin_core::read_texts(elements, "coverage"),
+            rights: dublin_core::read_texts(elements, "rights"),
+        })
+    }
+
+
+    // Convert to the Dublin Core elements in the order of the element set.
+    fn to_elements(&self) -> Vec<ExtensionElement> {
+        let mut elements = Vec::new();
+        dublin_core::push_texts(&mut elements, "title", &self.titles);
+        dublin_core::push_texts(&mut elements, "creator", &self.creators);
+        dublin_core::push_texts(&mut elements, "subject", &self.subjects);
+        dublin_core::push_texts(&mut elements, "description", &self.descriptions);
+        dublin_core::push_texts(&mut elements, "publisher", &self.publishers);
+        dublin_core::push_texts(&mut elements, "contributor", &self.contributors);
+        dublin_core::push_texts(&mut elements, "date", &self.dates);
+        dublin_core::push_texts(&mut elements, "type", &self.types);
+        dublin_core::push_texts(&mut elements, "format", &self.formats);
+        dublin_core::push_texts(&mut elements, "identifier", &self.identifiers);
+        dublin_core::push_texts(&mut elements, "source", &self.sources);
+        dublin_core::push_texts(&mut elements, "language", &self.languages);
+        dublin_core::push_texts(&mut elements, "relation", &self.relations);
+        dublin_core::push_texts(&mut elements, "coverage", &self.coverages);
+        dublin_core::push_texts(&mut elements, "rights", &self.rights);
+        elements
+    }
+}
@@ -0,0 +1,318 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for Dublin Core extension by using the methods under
+//! `DublinCoreExtensionBuilder`.
+
+use extension::dublin_core::{DublinCoreExtension, DublinCoreExtensionBuilder};
+
+impl DublinCoreExtensionBuilder {
+    /// Construct a new `DublinCoreExtensionBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// ```
+    pub fn new() -> DublinCoreExtensionBuilder {
+        DublinCoreExtensionBuilder::default()
+    }
+
+
+    /// Set the titles of the resource that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.titles(vec!["Making Music with Linux".to_owned()]);
+    /// ```
+    pub fn titles(&mut self, titles: Vec<String>) -> &mut DublinCoreExtensionBuilder {
+        self.titles = titles;
+        self
+    }
+
+
+    /// Set the creators of the resource that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.creators(vec!["Jane Doe".to_owned()]);
+    /// ```
+    pub fn creators(&mut self, creators: Vec<String>) -> &mut DublinCoreExtensionBuilder {
+        self.creators = creators;
+        self
+    }
+
+
+    /// Set the subjects of the resource that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.subjects(vec!["Linux".to_owned()]);
+    /// ```
+    pub fn subjects(&mut self, subjects: Vec<String>) -> &mut DublinCoreExtensionBuilder {
+        self.subjects = subjects;
+        self
+    }
+
+
+    /// Set the descriptions of the resource that exist under
+    /// `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.descriptions(vec!["A show about Linux".to_owned()]);
+    /// ```
+    pub fn descriptions(&mut self, descriptions: Vec<String>) -> &mut DublinCoreExtensionBuilder {
+        self.descriptions = descriptions;
+        self
+    }
+
+
+    /// Set the publishers of the resource that exist under
+    /// `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.publishers(vec!["Jupiter Broadcasting".to_owned()]);
+    /// ```
+    pub fn publishers(&mut self, publishers: Vec<String>) -> &mut DublinCoreExtensionBuilder {
+        self.publishers = publishers;
+        self
+    }
+
+
+    /// Set the contributors to the resource that exist under
+    /// `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.contributors(vec!["John Doe".to_owned()]);
+    /// ```
+    pub fn contributors(&mut self, contributors: Vec<String>) -> &mut DublinCoreExtensionBuilder {
+        self.contributors = contributors;
+        self
+    }
+
+
+    /// Set the dates of the resource that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.dates(vec!["2016-03-13T20:02:02-07:00".to_owned()]);
+    /// ```
+    pub fn dates(&mut self, dates: Vec<String>) -> &mut DublinCoreExtensionBuilder {
+        self.dates = dates;
+        self
+    }
+
+
+    /// Set the types of the resource that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.types(vec!["Sound".to_owned()]);
+    /// ```
+    pub fn types(&mut self, types: Vec<String>) -> &mut DublinCoreExtensionBuilder {
+        self.types = types;
+        self
+    }
+
+
+    /// Set the formats of the resource that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.formats(vec!["audio/ogg".to_owned()]);
+    /// ```
+    pub fn formats(&mut self, formats: Vec<String>) -> &mut DublinCoreExtensionBuilder {
+        self.formats = formats;
+        self
+    }
+
+
+    /// Set the identifiers of the resource that exist under
+    /// `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.identifiers(vec!["urn:isbn:0-486-27557-4".to_owned()]);
+    /// ```
+    pub fn identifiers(&mut self, identifiers: Vec<String>) -> &mut DublinCoreExtensionBuilder {
+        self.identifiers = identifiers;
+        self
+    }
+
+
+    /// Set the sources the resource is derived from that exist under
+    /// `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.sources(vec!["http://example.com/original".to_owned()]);
+    /// ```
+    pub fn sources(&mut self, sources: Vec<String>) -> &mut DublinCoreExtensionBuilder {
+        self.sources = sources;
+        self
+    }
+
+
+    /// Set the languages of the resource that exist under
+    /// `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.languages(vec!["en".to_owned()]);
+    /// ```
+    pub fn languages(&mut self, languages: Vec<String>) -> &mut DublinCoreExtensionBuilder {
+        self.languages = languages;
+        self
+    }
+
+
+    /// Set the related resources that exist under `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.relations(vec!["http://example.com/related".to_owned()]);
+    /// ```
+    pub fn relations(&mut self, relations: Vec<String>) -> &mut DublinCoreExtensionBuilder {
+        self.relations = relations;
+        self
+    }
+
+
+    /// Set the coverages of the resource that exist under
+    /// `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.coverages(vec!["2016".to_owned()]);
+    /// ```
+    pub fn coverages(&mut self, coverages: Vec<String>) -> &mut DublinCoreExtensionBuilder {
+        self.coverages = coverages;
+        self
+    }
+
+
+    /// Set the rights held in the resource that exist under
+    /// `DublinCoreExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let mut dublin_core_builder = DublinCoreExtensionBuilder::new();
+    /// dublin_core_builder.rights(vec!["Public domain".to_owned()]);
+    /// ```
+    pub fn rights(&mut self, rights: Vec<String>) -> &mut DublinCoreExtensionBuilder {
+        self.rights = rights;
+        self
+    }
+
+
+    /// Construct the `DublinCoreExtension` from the
+    /// `DublinCoreExtensionBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///         .titles(Vec::new())
+    ///         .creators(vec!["Jane Doe".to_owned()])
+    ///         .subjects(Vec::new())
+    ///         .descriptions(Vec::new())
+    ///         .publishers(Vec::new())
+    ///         .contributors(Vec::new())
+    ///         .dates(vec!["2016-03-13T20:02:02-07:00".to_owned()])
+    ///         .types(Vec::new())
+    ///         .formats(Vec::new())
+    ///         .identifiers(Vec::new())
+    ///         .sources(Vec::new())
+    ///         .languages(Vec::new())
+    ///         .relations(Vec::new())
+    ///         .coverages(Vec::new())
+    ///         .rights(Vec::new())
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> DublinCoreExtension {
+        DublinCoreExtension {
+            titles: self.titles.clone(),
+            creators: self.creators.clone(),
+            subjects: self.subjects.clone(),
+            descriptions: self.descriptions.clone(),
+            publishers: self.publishers.clone(),
+            contributors: self.contributors.clone(),
+            dates: self.dates.clone(),
+            types: self.types.clone(),
+            formats: self.formats.clone(),
+            identifiers: self.identifiers.clone(),
+            sources: self.sources.clone(),
+            languages: self.languages.clone(),
+            relations: self.relations.clone(),
+            coverages: self.coverages.clone(),
+            rights: self.rights.clone(),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! All the structs for the Dublin Core elements of a channel and an item.
+
+pub mod dublin_core_extension;
+pub mod dublin_core_extension_builder;
+
+use extension::{ExtensionElement, DUBLIN_CORE_NAMESPACE};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use util;
+
+/// This `DublinCoreExtension` struct contains the Dublin Core elements of a
+/// `Channel` or an `Item`, each of which may be repeated.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct DublinCoreExtension {
+    titles: Vec<String>,
+    creators: Vec<String>,
+    subjects: Vec<String>,
+    descriptions: Vec<String>,
+    publishers: Vec<String>,
+    contributors: Vec<String>,
+    dates: Vec<String>,
+    types: Vec<String>,
+    formats: Vec<String>,
+    identifiers: Vec<String>,
+    sources: Vec<String>,
+    languages: Vec<String>,
+    relations: Vec<String>,
+    coverages: Vec<String>,
+    rights: Vec<String>,
+}
+
+
+/// This `DublinCoreExtensionBuilder` struct creates the `DublinCoreExtension`.
+#[derive(Default)]
+pub struct DublinCoreExtensionBuilder {
+    titles: Vec<String>,
+    creators: Vec<String>,
+    subjects: Vec<String>,
+    descriptions: Vec<String>,
+    publishers: Vec<String>,
+    contributors: Vec<String>,
+    dates: Vec<String>,
+    types: Vec<String>,
+    formats: Vec<String>,
+    identifiers: Vec<String>,
+    sources: Vec<String>,
+    languages: Vec<String>,
+    relations: Vec<String>,
+    coverages: Vec<String>,
+    rights: Vec<String>,
+}
+
+
+// Read the text of every element with the local name.
+fn read_texts(elements: &[ExtensionElement], local_name: &str) -> Vec<String> {
+    elements.iter()
+            .filter(|element| element.local_name() == local_name)
+            .map(|element| element.text().unwrap_or_default())
+            .collect()
+}
+
+
+// Add an element with the local name for each of the texts.
+fn push_texts(elements: &mut Vec<ExtensionElement>, local_name: &str, texts: &[String]) {
+    let name = format!("dc:{}", local_name);
+    elements.extend(texts.iter().map(|text| util::extension_text_element(&name, DUBLIN_CORE_NAMESPACE, text)));
+}
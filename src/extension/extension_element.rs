@@ -0,0 +1,198 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under extension element can be retrieved by using the methods
+//! under `ExtensionElement`.
+
+use extension::ExtensionElement;
+
+impl ExtensionElement {
+    /// Get the name, with its prefix, that exists under `ExtensionElement`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::ExtensionElementBuilder;
+    ///
+    /// let element = ExtensionElementBuilder::new()
+    ///     .name("media:title")
+    ///     .finalize();
+    /// assert_eq!("media:title".to_owned(), element.name());
+    /// ```
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+
+    /// Get the name without its prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::ExtensionElementBuilder;
+    ///
+    /// let element = ExtensionElementBuilder::new()
+    ///     .name("media:title")
+    ///     .finalize();
+    /// assert_eq!("title", element.local_name());
+    ///
+    /// let element = ExtensionElementBuilder::new()
+    ///     .name("title")
+    ///     .finalize();
+    /// assert_eq!("title", element.local_name());
+    /// ```
+    pub fn local_name(&self) -> &str {
+        self.name.split_once(':').map_or(&self.name, |(_, local_name)| local_name)
+    }
+
+
+    /// Get the prefix of the name, `None` for an element of the default
+    /// namespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::ExtensionElementBuilder;
+    ///
+    /// let element = ExtensionElementBuilder::new()
+    ///     .name("media:title")
+    ///     .finalize();
+    /// assert_eq!(Some("media"), element.prefix());
+    ///
+    /// let element = ExtensionElementBuilder::new()
+    ///     .name("title")
+    ///     .finalize();
+    /// assert!(element.prefix().is_none());
+    /// ```
+    pub fn prefix(&self) -> Option<&str> {
+        self.name.split_once(':').map(|(prefix, _)| prefix)
+    }
+
+
+    /// Get the uri of the namespace that exists under `ExtensionElement`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::ExtensionElementBuilder;
+    ///
+    /// let namespace = "http://search.yahoo.com/mrss/";
+    /// let element = ExtensionElementBuilder::new()
+    ///     .namespace(namespace)
+    ///     .finalize();
+    /// assert_eq!(namespace.to_owned(), element.namespace());
+    /// ```
+    pub fn namespace(&self) -> String {
+        self.namespace.clone()
+    }
+
+
+    /// Get the attributes that exist under `ExtensionElement`, in the order
+    /// they were read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::ExtensionElementBuilder;
+    ///
+    /// let attributes = vec![("type".to_owned(), "plain".to_owned())];
+    /// let element = ExtensionElementBuilder::new()
+    ///     .attributes(attributes.clone())
+    ///     .finalize();
+    /// assert_eq!(attributes, element.attributes());
+    /// ```
+    pub fn attributes(&self) -> Vec<(String, String)> {
+        self.attributes.clone()
+    }
+
+
+    /// Get the value of the attribute with the name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::ExtensionElementBuilder;
+    ///
+    /// let element = ExtensionElementBuilder::new()
+    ///     .attributes(vec![("type".to_owned(), "plain".to_owned())])
+    ///     .finalize();
+    /// assert_eq!(Some("plain".to_owned()), element.attribute("type"));
+    /// assert!(element.attribute("lang").is_none());
+    /// ```
+    pub fn attribute(&self, name: &str) -> Option<String> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.clone())
+    }
+
+
+    /// Get the optional text that exists under `ExtensionElement`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::ExtensionElementBuilder;
+    ///
+    /// let element = ExtensionElementBuilder::new()
+    ///     .text(Some("A title".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("A title".to_owned()), element.text());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::ExtensionElementBuilder;
+    ///
+    /// let element = ExtensionElementBuilder::new()
+    ///     .text(None)
+    ///     .finalize();
+    /// assert!(element.text().is_none());
+    /// ```
+    pub fn text(&self) -> Option<String> {
+        self.text.clone()
+    }
+
+
+    /// Get the child elements that exist under `ExtensionElement`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::ExtensionElementBuilder;
+    ///
+    /// let child = ExtensionElementBuilder::new()
+    ///     .name("media:title")
+    ///     .finalize();
+    /// let element = ExtensionElementBuilder::new()
+    ///     .name("media:group")
+    ///     .children(vec![child.clone()])
+    ///     .finalize();
+    /// assert_eq!(vec![child], element.children());
+    /// ```
+    pub fn children(&self) -> Vec<ExtensionElement> {
+        self.children.clone()
+    }
+
+
+    /// Get the first child element with the name, without its prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::ExtensionElementBuilder;
+    ///
+    /// let child = ExtensionElementBuilder::new()
+    ///     .name("media:title")
+    ///     .finalize();
+    /// let element = ExtensionElementBuilder::new()
+    ///     .name("media:group")
+    ///     .children(vec![child.clone()])
+    ///     .finalize();
+    /// assert_eq!(Some(child), element.child("title"));
+    /// assert!(element.child("description").is_none());
+    /// ```
+    pub fn child(&self, local_name: &str) -> Option<ExtensionElement> {
+        self.children.iter().find(|child| child.local_name() == local_name).cloned()
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for extension element by using the methods under
+//! `ExtensionElementBuilder`.
+
+use extension::{ExtensionElement, ExtensionElementBuilder};
+
+impl ExtensionElementBuilder {
+    /// Construct a new `ExtensionElementBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::ExtensionElementBuilder;
+    ///
+    /// let element_builder = ExtensionElementBuilder::new();
+    /// ```
+    pub fn new() -> ExtensionElementBuilder {
+        ExtensionElementBuilder::default()
+    }
+
+
+    /// Set the name, with its prefix, that exists under `ExtensionElement`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::ExtensionElementBuilder;
+    ///
+    /// let mut element_builder = ExtensionElementBuilder::new();
+    /// element_builder.name("media:title");
+    /// ```
+    pub fn name(&mut self, name: &str) -> &mut ExtensionElementBuilder {
+        self.name = name.to_owned();
+        self
+    }
+
+
+    /// Set the uri of the namespace that exists under `ExtensionElement`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::ExtensionElementBuilder;
+    ///
+    /// let mut element_builder = ExtensionElementBuilder::new();
+    /// element_builder.namespace("http://search.yahoo.com/mrss/");
+    /// ```
+    pub fn namespace(&mut self, namespace: &str) -> &mut ExtensionElementBuilder {
+        self.namespace = namespace.to_owned();
+        self
+    }
+
+
+    /// Set the attributes that exist under `ExtensionElement`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::ExtensionElementBuilder;
+    ///
+    /// let mut element_builder = ExtensionElementBuilder::new();
+    /// element_builder.attributes(vec![("type".to_owned(), "plain".to_owned())]);
+    /// ```
+    pub fn attributes(&mut self, attributes: Vec<(String, String)>) -> &mut ExtensionElementBuilder {
+        self.attributes = attributes;
+        self
+    }
+
+
+    /// Set the optional text that exists under `ExtensionElement`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::ExtensionElementBuilder;
+    ///
+    /// let mut element_builder = ExtensionElementBuilder::new();
+    /// element_builder.text(Some("A title".to_owned()));
+    /// ```
+    pub fn text(&mut self, text: Option<String>) -> &mut ExtensionElementBuilder {
+        self.text = text;
+        self
+    }
+
+
+    /// Set the child elements that exist under `ExtensionElement`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::ExtensionElementBuilder;
+    ///
+    /// let child = ExtensionElementBuilder::new()
+    ///     .name("media:title")
+    ///     .namespace("http://search.yahoo.com/mrss/")
+    ///     .finalize();
+    ///
+    /// let mut element_builder = ExtensionElementBuilder::new();
+    /// element_builder.children(vec![child]);
+    /// ```
+    pub fn children(&mut self, children: Vec<ExtensionElement>) -> &mut ExtensionElementBuilder {
+        self.children = children;
+        self
+    }
+
+
+    /// Construct the `ExtensionElement` from the `ExtensionElementBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::ExtensionElementBuilder;
+    ///
+    /// let element = ExtensionElementBuilder::new()
+    ///         .name("media:title")
+    ///         .namespace("http://search.yahoo.com/mrss/")
+    ///         .attributes(vec![("type".to_owned(), "plain".to_owned())])
+    ///         .text(Some("A title".to_owned()))
+    ///         .children(Vec::new())
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> ExtensionElement {
+        ExtensionElement {
+            name: self.name.clone(),
+            namespace: self.namespace.clone(),
+            attributes: self.attributes.clone(),
+            text: self.text.clone(),
+            children: self.children.clone(),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under iTunes category can be retrieved by using the methods
+//! under `ITunesCategory`.
+
+use extension::itunes::ITunesCategory;
+
+impl ITunesCategory {
+    /// Get the text that exists under `ITunesCategory`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesCategoryBuilder;
+    ///
+    /// let category = ITunesCategoryBuilder::new()
+    ///     .text("Technology")
+    ///     .finalize();
+    /// assert_eq!("Technology".to_owned(), category.text());
+    /// ```
+    pub fn text(&self) -> String {
+        self.text.clone()
+    }
+
+
+    /// Get the subcategories that exist under `ITunesCategory`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesCategoryBuilder;
+    ///
+    /// let subcategory = ITunesCategoryBuilder::new()
+    ///     .text("Podcasting")
+    ///     .finalize();
+    /// let category = ITunesCategoryBuilder::new()
+    ///     .text("Technology")
+    ///     .subcategories(vec![subcategory.clone()])
+    ///     .finalize();
+    /// assert_eq!(vec![subcategory], category.subcategories());
+    /// ```
+    pub fn subcategories(&self) -> Vec<ITunesCategory> {
+        self.subcategories.clone()
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for iTunes category by using the methods under
+//! `ITunesCategoryBuilder`.
+
+use extension::itunes::{ITunesCategory, ITunesCategoryBuilder};
+
+impl ITunesCategoryBuilder {
+    /// Construct a new `ITunesCategoryBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesCategoryBuilder;
+    ///
+    /// let category_builder = ITunesCategoryBuilder::new();
+    /// ```
+    pub fn new() -> ITunesCategoryBuilder {
+        ITunesCategoryBuilder::default()
+    }
+
+
+    /// Set the text that exists under `ITunesCategory`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesCategoryBuilder;
+    ///
+    /// let mut category_builder = ITunesCategoryBuilder::new();
+    /// category_builder.text("Technology");
+    /// ```
+    pub fn text(&mut self, text: &str) -> &mut ITunesCategoryBuilder {
+        self.text = text.to_owned();
+        self
+    }
+
+
+    /// Set the subcategories that exist under `ITunesCategory`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesCategoryBuilder;
+    ///
+    /// let subcategory = ITunesCategoryBuilder::new()
+    ///     .text("Podcasting")
+    ///     .finalize();
+    ///
+    /// let mut category_builder = ITunesCategoryBuilder::new();
+    /// category_builder.subcategories(vec![subcategory]);
+    /// ```
+    pub fn subcategories(&mut self, subcategories: Vec<ITunesCategory>) -> &mut ITunesCategoryBuilder {
+        self.subcategories = subcategories;
+        self
+    }
+
+
+    /// Construct the `ITunesCategory` from the `ITunesCategoryBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesCategoryBuilder;
+    ///
+    /// let category = ITunesCategoryBuilder::new()
+    ///         .text("Technology")
+    ///         .subcategories(Vec::new())
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> ITunesCategory {
+        ITunesCategory {
+            text: self.text.clone(),
+            subcategories: self.subcategories.clone(),
+        }
+    }
+}
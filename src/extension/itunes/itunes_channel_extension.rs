@@ -0,0 +1,205 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under iTunes channel extension can be retrieved by using the
+//! methods under `ITunesChannelExtension`.
+
+use extension::{Extension, ExtensionElement, ITUNES_NAMESPACE};
+use extension::itunes::{self, ITunesCategory, ITunesChannelExtension, ITunesOwner};
+use util;
+
+impl ITunesChannelExtension {
+    /// Get the optional author that exists under `ITunesChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    ///
+    /// let itunes = ITunesChannelExtensionBuilder::new()
+    ///     .author(Some("Jane Doe".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("Jane Doe".to_owned()), itunes.author());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    ///
+    /// let itunes = ITunesChannelExtensionBuilder::new()
+    ///     .author(None)
+    ///     .finalize();
+    /// assert!(itunes.author().is_none());
+    /// ```
+    pub fn author(&self) -> Option<String> {
+        self.author.clone()
+    }
+
+
+    /// Get whether the podcast is blocked from the iTunes directory under
+    /// `ITunesChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    ///
+    /// let itunes = ITunesChannelExtensionBuilder::new()
+    ///     .block(true)
+    ///     .finalize();
+    /// assert!(itunes.block());
+    /// ```
+    pub fn block(&self) -> bool {
+        self.block
+    }
+
+
+    /// Get the categories that exist under `ITunesChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::{ITunesCategoryBuilder, ITunesChannelExtensionBuilder};
+    ///
+    /// let category = ITunesCategoryBuilder::new()
+    ///     .text("Technology")
+    ///     .finalize();
+    /// let itunes = ITunesChannelExtensionBuilder::new()
+    ///     .categories(vec![category.clone()])
+    ///     .finalize();
+    /// assert_eq!(vec![category], itunes.categories());
+    /// ```
+    pub fn categories(&self) -> Vec<ITunesCategory> {
+        self.categories.clone()
+    }
+
+
+    /// Get the optional url of the image that exists under
+    /// `ITunesChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    ///
+    /// let url = "http://example.com/artwork.jpg";
+    /// let itunes = ITunesChannelExtensionBuilder::new()
+    ///     .image(Some(url.to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some(url.to_owned()), itunes.image());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    ///
+    /// let itunes = ITunesChannelExtensionBuilder::new()
+    ///     .image(None)
+    ///     .finalize();
+    /// assert!(itunes.image().is_none());
+    /// ```
+    pub fn image(&self) -> Option<String> {
+        self.image.clone()
+    }
+
+
+    /// Get whether the podcast is explicit under `ITunesChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    ///
+    /// let itunes = ITunesChannelExtensionBuilder::new()
+    ///     .explicit(Some(false))
+    ///     .finalize();
+    /// assert_eq!(Some(false), itunes.explicit());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    ///
+    /// let itunes = ITunesChannelExtensionBuilder::new()
+    ///     .explicit(None)
+    ///     .finalize();
+    /// assert!(itunes.explicit().is_none());
+    /// ```
+    pub fn explicit(&self) -> Option<bool> {
+        self.explicit
+    }
+
+
+    /// Get the optional owner that exists under `ITunesChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::{ITunesChannelExtensionBuilder, ITunesOwnerBuilder};
+    ///
+    /// let owner = ITunesOwnerBuilder::new()
+    ///     .email(Some("jane@example.com".to_owned()))
+    ///     .finalize();
+    /// let itunes = ITunesChannelExtensionBuilder::new()
+    ///     .owner(Some(owner.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(owner), itunes.owner());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    ///
+    /// let itunes = ITunesChannelExtensionBuilder::new()
+    ///     .owner(None)
+    ///     .finalize();
+    /// assert!(itunes.owner().is_none());
+    /// ```
+    pub fn owner(&self) -> Option<ITunesOwner> {
+        self.owner.clone()
+    }
+}
+
+
+impl Extension for ITunesChannelExtension {
+    const NAMESPACE: &'static str = ITUNES_NAMESPACE;
+    const PREFIX: &'static str = "itunes";
+
+
+    // Read the iTunes elements of a channel, leaving out the ones of an
+    // episode.
+    fn from_elements(elements: &[ExtensionElement]) -> Option<ITunesChannelExtension> {
+        Some(ITunesChannelExtension {
+            author: util::extension_text(elements, "author"),
+            block: itunes::parse_block(elements),
+            categories: elements.iter()
+                                .filter(|element| element.local_name() == "category")
+                                .map(itunes::read_category)
+                                .collect(),
+            image: util::extension_attribute(elements, "image", "href"),
+            explicit: itunes::parse_explicit(elements),
+            owner: elements.iter()
+                           .find(|element| element.local_name() == "owner")
+                           .map(itunes::read_owner),
+        })
+    }
+
+
+    fn to_elements(&self) -> Vec<ExtensionElement> {
+        let mut elements = Vec::new();
+        if let Some(ref author) = self.author {
+            elements.push(itunes::text_element("author", author));
+        }
+        if self.block {
+            elements.push(itunes::text_element("block", "Yes"));
+        }
+        elements.extend(self.categories.iter().map(itunes::category_element));
+        if let Some(ref image) = self.image {
+            elements.push(itunes::image_element(image));
+        }
+        if let Some(explicit) = self.explicit {
+            elements.push(itunes::text_element("explicit", &explicit.to_string()));
+        }
+        if let Some(ref owner) = self.owner {
+            elements.push(itunes::owner_element(owner));
+        }
+        elements
+    }
+}
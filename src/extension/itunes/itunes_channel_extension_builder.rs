@@ -0,0 +1,160 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for iTunes channel extension by using the methods
+//! under `ITunesChannelExtensionBuilder`.
+
+use extension::itunes::{ITunesCategory, ITunesChannelExtension, ITunesChannelExtensionBuilder,
+                        ITunesOwner};
+
+impl ITunesChannelExtensionBuilder {
+    /// Construct a new `ITunesChannelExtensionBuilder` and return default
+    /// values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    ///
+    /// let itunes_builder = ITunesChannelExtensionBuilder::new();
+    /// ```
+    pub fn new() -> ITunesChannelExtensionBuilder {
+        ITunesChannelExtensionBuilder::default()
+    }
+
+
+    /// Set the optional author that exists under `ITunesChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    ///
+    /// let mut itunes_builder = ITunesChannelExtensionBuilder::new();
+    /// itunes_builder.author(Some("Jane Doe".to_owned()));
+    /// ```
+    pub fn author(&mut self, author: Option<String>) -> &mut ITunesChannelExtensionBuilder {
+        self.author = author;
+        self
+    }
+
+
+    /// Set whether the podcast is blocked from the iTunes directory under
+    /// `ITunesChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    ///
+    /// let mut itunes_builder = ITunesChannelExtensionBuilder::new();
+    /// itunes_builder.block(true);
+    /// ```
+    pub fn block(&mut self, block: bool) -> &mut ITunesChannelExtensionBuilder {
+        self.block = block;
+        self
+    }
+
+
+    /// Set the categories that exist under `ITunesChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::{ITunesCategoryBuilder, ITunesChannelExtensionBuilder};
+    ///
+    /// let category = ITunesCategoryBuilder::new()
+    ///     .text("Technology")
+    ///     .finalize();
+    ///
+    /// let mut itunes_builder = ITunesChannelExtensionBuilder::new();
+    /// itunes_builder.categories(vec![category]);
+    /// ```
+    pub fn categories(&mut self, categories: Vec<ITunesCategory>) -> &mut ITunesChannelExtensionBuilder {
+        self.categories = categories;
+        self
+    }
+
+
+    /// Set the optional url of the image that exists under
+    /// `ITunesChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    ///
+    /// let mut itunes_builder = ITunesChannelExtensionBuilder::new();
+    /// itunes_builder.image(Some("http://example.com/artwork.jpg".to_owned()));
+    /// ```
+    pub fn image(&mut self, image: Option<String>) -> &mut ITunesChannelExtensionBuilder {
+        self.image = image;
+        self
+    }
+
+
+    /// Set whether the podcast is explicit under `ITunesChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    ///
+    /// let mut itunes_builder = ITunesChannelExtensionBuilder::new();
+    /// itunes_builder.explicit(Some(false));
+    /// ```
+    pub fn explicit(&mut self, explicit: Option<bool>) -> &mut ITunesChannelExtensionBuilder {
+        self.explicit = explicit;
+        self
+    }
+
+
+    /// Set the optional owner that exists under `ITunesChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::{ITunesChannelExtensionBuilder, ITunesOwnerBuilder};
+    ///
+    /// let owner = ITunesOwnerBuilder::new()
+    ///     .name(Some("Jane Doe".to_owned()))
+    ///     .finalize();
+    ///
+    /// let mut itunes_builder = ITunesChannelExtensionBuilder::new();
+    /// itunes_builder.owner(Some(owner));
+    /// ```
+    pub fn owner(&mut self, owner: Option<ITunesOwner>) -> &mut ITunesChannelExtensionBuilder {
+        self.owner = owner;
+        self
+    }
+
+
+    /// Construct the `ITunesChannelExtension` from the
+    /// `ITunesChannelExtensionBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    ///
+    /// let itunes = ITunesChannelExtensionBuilder::new()
+    ///         .author(Some("Jane Doe".to_owned()))
+    ///         .block(false)
+    ///         .categories(Vec::new())
+    ///         .image(Some("http://example.com/artwork.jpg".to_owned()))
+    ///         .explicit(Some(false))
+    ///         .owner(None)
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> ITunesChannelExtension {
+        ITunesChannelExtension {
+            author: self.author.clone(),
+            block: self.block,
+            categories: self.categories.clone(),
+            image: self.image.clone(),
+            explicit: self.explicit,
+            owner: self.owner.clone(),
+        }
+    }
+}
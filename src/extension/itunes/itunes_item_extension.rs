@@ -0,0 +1,301 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under iTunes item extension can be retrieved by using the
+//! methods under `ITunesItemExtension`.
+
+use extension::{Extension, ExtensionElement, ITUNES_NAMESPACE};
+use extension::itunes::{self, ITunesItemExtension};
+use util;
+
+impl ITunesItemExtension {
+    /// Get the optional author that exists under `ITunesItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let itunes = ITunesItemExtensionBuilder::new()
+    ///     .author(Some("Jane Doe".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("Jane Doe".to_owned()), itunes.author());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let itunes = ITunesItemExtensionBuilder::new()
+    ///     .author(None)
+    ///     .finalize();
+    /// assert!(itunes.author().is_none());
+    /// ```
+    pub fn author(&self) -> Option<String> {
+        self.author.clone()
+    }
+
+
+    /// Get whether the episode is blocked from the iTunes directory under
+    /// `ITunesItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let itunes = ITunesItemExtensionBuilder::new()
+    ///     .block(true)
+    ///     .finalize();
+    /// assert!(itunes.block());
+    /// ```
+    pub fn block(&self) -> bool {
+        self.block
+    }
+
+
+    /// Get the optional url of the image that exists under
+    /// `ITunesItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let url = "http://example.com/episode.jpg";
+    /// let itunes = ITunesItemExtensionBuilder::new()
+    ///     .image(Some(url.to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some(url.to_owned()), itunes.image());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let itunes = ITunesItemExtensionBuilder::new()
+    ///     .image(None)
+    ///     .finalize();
+    /// assert!(itunes.image().is_none());
+    /// ```
+    pub fn image(&self) -> Option<String> {
+        self.image.clone()
+    }
+
+
+    /// Get whether the episode is explicit under `ITunesItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let itunes = ITunesItemExtensionBuilder::new()
+    ///     .explicit(Some(true))
+    ///     .finalize();
+    /// assert_eq!(Some(true), itunes.explicit());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let itunes = ITunesItemExtensionBuilder::new()
+    ///     .explicit(None)
+    ///     .finalize();
+    /// assert!(itunes.explicit().is_none());
+    /// ```
+    pub fn explicit(&self) -> Option<bool> {
+        self.explicit
+    }
+
+
+    /// Get the optional duration in seconds that exists under
+    /// `ITunesItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let itunes = ITunesItemExtensionBuilder::new()
+    ///     .duration(Some(3723))
+    ///     .finalize();
+    /// assert_eq!(Some(3723), itunes.duration());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// for &(text, seconds) in &[("1:02:03", 3723), ("62:03", 3723), ("3723", 3723), ("04:05.5", 245)] {
+    ///     let itunes = ITunesItemExtensionBuilder::new()
+    ///         .duration_raw(Some(text.to_owned()))
+    ///         .finalize();
+    ///     assert_eq!(Some(seconds), itunes.duration());
+    /// }
+    /// ```
+    pub fn duration(&self) -> Option<i64> {
+        self.duration
+    }
+
+
+    /// Get the text of the duration that exists under `ITunesItemExtension`
+    /// as it was read, also when it is not a duration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let itunes = ITunesItemExtensionBuilder::new()
+    ///     .duration_raw(Some("about an hour".to_owned()))
+    ///     .finalize();
+    /// assert!(itunes.duration().is_none());
+    /// assert_eq!(Some("about an hour".to_owned()), itunes.duration_raw());
+    /// ```
+    pub fn duration_raw(&self) -> Option<String> {
+        self.duration_raw.clone()
+    }
+
+
+    /// Get the optional number of the episode that exists under
+    /// `ITunesItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let itunes = ITunesItemExtensionBuilder::new()
+    ///     .episode(Some(42))
+    ///     .finalize();
+    /// assert_eq!(Some(42), itunes.episode());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let itunes = ITunesItemExtensionBuilder::new()
+    ///     .episode(None)
+    ///     .finalize();
+    /// assert!(itunes.episode().is_none());
+    /// ```
+    pub fn episode(&self) -> Option<i64> {
+        self.episode
+    }
+
+
+    /// Get the optional number of the season that exists under
+    /// `ITunesItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let itunes = ITunesItemExtensionBuilder::new()
+    ///     .season(Some(2))
+    ///     .finalize();
+    /// assert_eq!(Some(2), itunes.season());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let itunes = ITunesItemExtensionBuilder::new()
+    ///     .season(None)
+    ///     .finalize();
+    /// assert!(itunes.season().is_none());
+    /// ```
+    pub fn season(&self) -> Option<i64> {
+        self.season
+    }
+
+
+    /// Get the optional type of the episode that exists under
+    /// `ITunesItemExtension`, `full`, `trailer` or `bonus`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let itunes = ITunesItemExtensionBuilder::new()
+    ///     .episode_type(Some("trailer".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("trailer".to_owned()), itunes.episode_type());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let itunes = ITunesItemExtensionBuilder::new()
+    ///     .episode_type(None)
+    ///     .finalize();
+    /// assert!(itunes.episode_type().is_none());
+    /// ```
+    pub fn episode_type(&self) -> Option<String> {
+        self.episode_type.clone()
+    }
+}
+
+
+impl Extension for ITunesItemExtension {
+    const NAMESPACE: &'static str = ITUNES_NAMESPACE;
+    const PREFIX: &'static str = "itunes";
+
+
+    // Read the iTunes elements of an item, numbers that cannot be read being
+    // left out.
+    fn from_elements(elements: &[ExtensionElement]) -> Option<ITunesItemExtension> {
+        let duration_raw = util::extension_text(elements, "duration");
+        let episode = util::extension_text(elements, "episode");
+        let season = util::extension_text(elements, "season");
+        Some(ITunesItemExtension {
+            author: util::extension_text(elements, "author"),
+            block: itunes::parse_block(elements),
+            image: util::extension_attribute(elements, "image", "href"),
+            explicit: itunes::parse_explicit(elements),
+            duration: duration_raw.as_deref().and_then(itunes::parse_duration),
+            duration_raw,
+            episode: util::option_str_to_option_i64(episode.as_deref()),
+            season: util::option_str_to_option_i64(season.as_deref()),
+            episode_type: util::extension_text(elements, "episodeType"),
+        })
+    }
+
+
+    // Convert to the iTunes elements of an item, the duration being written
+    // as it was read unless it was changed.
+    fn to_elements(&self) -> Vec<ExtensionElement> {
+        let mut elements = Vec::new();
+        if let Some(ref author) = self.author {
+            elements.push(itunes::text_element("author", author));
+        }
+        if self.block {
+            elements.push(itunes::text_element("block", "Yes"));
+        }
+        if let Some(ref image) = self.image {
+            elements.push(itunes::image_element(image));
+        }
+        if let Some(explicit) = self.explicit {
+            elements.push(itunes::text_element("explicit", &explicit.to_string()));
+        }
+        let duration = match (self.duration, &self.duration_raw) {
+            (Some(duration), Some(raw)) if itunes::parse_duration(raw) == Some(duration) => Some(raw.clone()),
+            (Some(duration), _) => Some(itunes::format_duration(duration)),
+            (None, raw) => raw.clone(),
+        };
+        if let Some(duration) = duration {
+            elements.push(itunes::text_element("duration", &duration));
+        }
+        if let Some(episode) = self.episode {
+            elements.push(itunes::text_element("episode", &episode.to_string()));
+        }
+        if let Some(season) = self.season {
+            elements.push(itunes::text_element("season", &season.to_string()));
+        }
+        if let Some(ref episode_type) = self.episode_type {
+            elements.push(itunes::text_element("episodeType", episode_type));
+        }
+        elements
+    }
+}
@@ -0,0 +1,210 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for iTunes item extension by using the methods under
+//! `ITunesItemExtensionBuilder`.
+
+use extension::itunes::{self, ITunesItemExtension, ITunesItemExtensionBuilder};
+
+impl ITunesItemExtensionBuilder {
+    /// Construct a new `ITunesItemExtensionBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let itunes_builder = ITunesItemExtensionBuilder::new();
+    /// ```
+    pub fn new() -> ITunesItemExtensionBuilder {
+        ITunesItemExtensionBuilder::default()
+    }
+
+
+    /// Set the optional author that exists under `ITunesItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let mut itunes_builder = ITunesItemExtensionBuilder::new();
+    /// itunes_builder.author(Some("Jane Doe".to_owned()));
+    /// ```
+    pub fn author(&mut self, author: Option<String>) -> &mut ITunesItemExtensionBuilder {
+        self.author = author;
+        self
+    }
+
+
+    /// Set whether the episode is blocked from the iTunes directory under
+    /// `ITunesItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let mut itunes_builder = ITunesItemExtensionBuilder::new();
+    /// itunes_builder.block(true);
+    /// ```
+    pub fn block(&mut self, block: bool) -> &mut ITunesItemExtensionBuilder {
+        self.block = block;
+        self
+    }
+
+
+    /// Set the optional url of the image that exists under
+    /// `ITunesItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let mut itunes_builder = ITunesItemExtensionBuilder::new();
+    /// itunes_builder.image(Some("http://example.com/episode.jpg".to_owned()));
+    /// ```
+    pub fn image(&mut self, image: Option<String>) -> &mut ITunesItemExtensionBuilder {
+        self.image = image;
+        self
+    }
+
+
+    /// Set whether the episode is explicit under `ITunesItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let mut itunes_builder = ITunesItemExtensionBuilder::new();
+    /// itunes_builder.explicit(Some(true));
+    /// ```
+    pub fn explicit(&mut self, explicit: Option<bool>) -> &mut ITunesItemExtensionBuilder {
+        self.explicit = explicit;
+        self
+    }
+
+
+    /// Set the optional duration in seconds that exists under
+    /// `ITunesItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let mut itunes_builder = ITunesItemExtensionBuilder::new();
+    /// itunes_builder.duration(Some(3723));
+    /// ```
+    pub fn duration(&mut self, duration: Option<i64>) -> &mut ITunesItemExtensionBuilder {
+        self.duration = duration;
+        self
+    }
+
+
+    /// Set the duration that exists under `ITunesItemExtension` from its text
+    /// of HH:MM:SS, MM:SS or seconds, which is kept even when it is not a
+    /// duration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let mut itunes_builder = ITunesItemExtensionBuilder::new();
+    /// itunes_builder.duration_raw(Some("1:02:03".to_owned()));
+    /// ```
+    pub fn duration_raw(&mut self, duration: Option<String>) -> &mut ITunesItemExtensionBuilder {
+        self.duration = duration.as_deref().and_then(itunes::parse_duration);
+        self.duration_raw = duration;
+        self
+    }
+
+
+    /// Set the optional number of the episode that exists under
+    /// `ITunesItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let mut itunes_builder = ITunesItemExtensionBuilder::new();
+    /// itunes_builder.episode(Some(42));
+    /// ```
+    pub fn episode(&mut self, episode: Option<i64>) -> &mut ITunesItemExtensionBuilder {
+        self.episode = episode;
+        self
+    }
+
+
+    /// Set the optional number of the season that exists under
+    /// `ITunesItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let mut itunes_builder = ITunesItemExtensionBuilder::new();
+    /// itunes_builder.season(Some(2));
+    /// ```
+    pub fn season(&mut self, season: Option<i64>) -> &mut ITunesItemExtensionBuilder {
+        self.season = season;
+        self
+    }
+
+
+    /// Set the optional type of the episode that exists under
+    /// `ITunesItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let mut itunes_builder = ITunesItemExtensionBuilder::new();
+    /// itunes_builder.episode_type(Some("full".to_owned()));
+    /// ```
+    pub fn episode_type(&mut self, episode_type: Option<String>) -> &mut ITunesItemExtensionBuilder {
+        self.episode_type = episode_type;
+        self
+    }
+
+
+    /// Construct the `ITunesItemExtension` from the
+    /// `ITunesItemExtensionBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    ///
+    /// let itunes = ITunesItemExtensionBuilder::new()
+    ///         .author(Some("Jane Doe".to_owned()))
+    ///         .block(false)
+    ///         .image(None)
+    ///         .explicit(Some(false))
+    ///         .duration_raw(Some("45:00".to_owned()))
+    ///         .episode(Some(42))
+    ///         .season(Some(2))
+    ///         .episode_type(Some("full".to_owned()))
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> ITunesItemExtension {
+        ITunesItemExtension {
+            author: self.author.clone(),
+            block: self.block,
+            image: self.image.clone(),
+            explicit: self.explicit,
+            duration: self.duration,
+            duration_raw: self.duration_raw.clone(),
+            episode: self.episode,
+            season: self.season,
+            episode_type: self.episode_type.clone(),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under iTunes owner can be retrieved by using the methods under
+//! `ITunesOwner`.
+
+use extension::itunes::ITunesOwner;
+
+impl ITunesOwner {
+    /// Get the optional name that exists under `ITunesOwner`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesOwnerBuilder;
+    ///
+    /// let owner = ITunesOwnerBuilder::new()
+    ///     .name(Some("Jane Doe".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("Jane Doe".to_owned()), owner.name());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesOwnerBuilder;
+    ///
+    /// let owner = ITunesOwnerBuilder::new()
+    ///     .name(None)
+    ///     .finalize();
+    /// assert!(owner.name().is_none());
+    /// ```
+    pub fn name(&self) -> Option<String> {
+        self.name.clone()
+    }
+
+
+    /// Get the optional email that exists under `ITunesOwner`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesOwnerBuilder;
+    ///
+    /// let owner = ITunesOwnerBuilder::new()
+    ///     .email(Some("jane@example.com".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("jane@example.com".to_owned()), owner.email());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesOwnerBuilder;
+    ///
+    /// let owner = ITunesOwnerBuilder::new()
+    ///     .email(None)
+    ///     .finalize();
+    /// assert!(owner.email().is_none());
+    /// ```
+    pub fn email(&self) -> Option<String> {
+        self.email.clone()
+    }
+}
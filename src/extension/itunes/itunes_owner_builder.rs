@@ -0,0 +1,75 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for iTunes owner by using the methods under
+//! `ITunesOwnerBuilder`.
+
+use extension::itunes::{ITunesOwner, ITunesOwnerBuilder};
+
+impl ITunesOwnerBuilder {
+    /// Construct a new `ITunesOwnerBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesOwnerBuilder;
+    ///
+    /// let owner_builder = ITunesOwnerBuilder::new();
+    /// ```
+    pub fn new() -> ITunesOwnerBuilder {
+        ITunesOwnerBuilder::default()
+    }
+
+
+    /// Set the optional name that exists under `ITunesOwner`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesOwnerBuilder;
+    ///
+    /// let mut owner_builder = ITunesOwnerBuilder::new();
+    /// owner_builder.name(Some("Jane Doe".to_owned()));
+    /// ```
+    pub fn name(&mut self, name: Option<String>) -> &mut ITunesOwnerBuilder {
+        self.name = name;
+        self
+    }
+
+
+    /// Set the optional email that exists under `ITunesOwner`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesOwnerBuilder;
+    ///
+    /// let mut owner_builder = ITunesOwnerBuilder::new();
+    /// owner_builder.email(Some("jane@example.com".to_owned()));
+    /// ```
+    pub fn email(&mut self, email: Option<String>) -> &mut ITunesOwnerBuilder {
+        self.email = email;
+        self
+    }
+
+
+    /// Construct the `ITunesOwner` from the `ITunesOwnerBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesOwnerBuilder;
+    ///
+    /// let owner = ITunesOwnerBuilder::new()
+    ///         .name(Some("Jane Doe".to_owned()))
+    ///         .email(Some("jane@example.com".to_owned()))
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> ITunesOwner {
+        ITunesOwner {
+            name: self.name.clone(),
+            email: self.email.clone(),
+        }
+    }
+}
@@ -0,0 +1,219 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! All the structs for the iTunes podcast elements of a channel and an item.
+
+pub mod itunes_category;
+pub mod itunes_category_builder;
+pub mod itunes_channel_extension;
+pub mod itunes_channel_extension_builder;
+pub mod itunes_item_extension;
+pub mod itunes_item_extension_builder;
+pub mod itunes_owner;
+pub mod itunes_owner_builder;
+
+use extension::{ExtensionElement, ExtensionElementBuilder, ITUNES_NAMESPACE};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use util;
+
+/// This `ITunesCategory` struct contains a category of the iTunes directory,
+/// with its subcategories.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct ITunesCategory {
+    text: String,
+    subcategories: Vec<ITunesCategory>,
+}
+
+
+/// This `ITunesCategoryBuilder` struct creates the `ITunesCategory`.
+#[derive(Default)]
+pub struct ITunesCategoryBuilder {
+    text: String,
+    subcategories: Vec<ITunesCategory>,
+}
+
+
+/// This `ITunesChannelExtension` struct contains the iTunes elements of a
+/// `Channel`.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct ITunesChannelExtension {
+    author: Option<String>,
+    block: bool,
+    categories: Vec<ITunesCategory>,
+    image: Option<String>,
+    explicit: Option<bool>,
+    owner: Option<ITunesOwner>,
+}
+
+
+/// This `ITunesChannelExtensionBuilder` struct creates the
+/// `ITunesChannelExtension`.
+#[derive(Default)]
+pub struct ITunesChannelExtensionBuilder {
+    author: Option<String>,
+    block: bool,
+    categories: Vec<ITunesCategory>,
+    image: Option<String>,
+    explicit: Option<bool>,
+    owner: Option<ITunesOwner>,
+}
+
+
+/// This `ITunesItemExtension` struct contains the iTunes elements of an
+/// `Item`.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct ITunesItemExtension {
+    author: Option<String>,
+    block: bool,
+    image: Option<String>,
+    explicit: Option<bool>,
+    duration: Option<i64>,
+    duration_raw: Option<String>,
+    episode: Option<i64>,
+    season: Option<i64>,
+    episode_type: Option<String>,
+}
+
+
+/// This `ITunesItemExtensionBuilder` struct creates the `ITunesItemExtension`.
+#[derive(Default)]
+pub struct ITunesItemExtensionBuilder {
+    author: Option<String>,
+    block: bool,
+    image: Option<String>,
+    explicit: Option<bool>,
+    duration: Option<i64>,
+    duration_raw: Option<String>,
+    episode: Option<i64>,
+    season: Option<i64>,
+    episode_type: Option<String>,
+}
+
+
+/// This `ITunesOwner` struct contains the owner of a podcast, who is contacted
+/// by the iTunes directory.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct ITunesOwner {
+    name: Option<String>,
+    email: Option<String>,
+}
+
+
+/// This `ITunesOwnerBuilder` struct creates the `ITunesOwner`.
+#[derive(Default)]
+pub struct ITunesOwnerBuilder {
+    name: Option<String>,
+    email: Option<String>,
+}
+
+
+// Construct the builder of an iTunes element with the local name.
+fn builder(local_name: &str) -> ExtensionElementBuilder {
+    util::extension_builder(&format!("itunes:{}", local_name), ITUNES_NAMESPACE)
+}
+
+
+// Construct an iTunes element containing only text.
+fn text_element(local_name: &str, text: &str) -> ExtensionElement {
+    util::extension_text_element(&format!("itunes:{}", local_name), ITUNES_NAMESPACE, text)
+}
+
+
+// Construct the `itunes:image` element of the url of an image.
+fn image_element(href: &str) -> ExtensionElement {
+    builder("image").attributes(vec![("href".to_owned(), href.to_owned())]).finalize()
+}
+
+
+// Read the text of `itunes:block`, which blocks the podcast or episode when
+// it is `Yes`.
+fn parse_block(elements: &[ExtensionElement]) -> bool {
+    util::extension_text(elements, "block").is_some_and(|block| block.trim().eq_ignore_ascii_case("yes"))
+}
+
+
+// Read the text of `itunes:explicit`, which is `true` or `false` and in older
+// feeds `yes`, `explicit`, `no` or `clean`.
+fn parse_explicit(elements: &[ExtensionElement]) -> Option<bool> {
+    let explicit = util::extension_text(elements, "explicit")?.trim().to_lowercase();
+    match explicit.as_str() {
+        "true" | "yes" | "explicit" => Some(true),
+        "false" | "no" | "clean" => Some(false),
+        _ => None,
+    }
+}
+
+
+// Read a duration of HH:MM:SS, MM:SS or seconds to seconds, leaving out
+// fractions of a second.
+fn parse_duration(duration: &str) -> Option<i64> {
+    let parts = duration.trim().split(':').collect::<Vec<_>>();
+    if parts.len() > 3 {
+        return None;
+    }
+
+    let mut seconds = 0;
+    for part in parts {
+        let part = part.trim().split('.').next().unwrap_or_default();
+        let value = part.parse::<u32>().ok()?;
+        seconds = seconds * 60 + i64::from(value);
+    }
+    Some(seconds)
+}
+
+
+// Write a duration in seconds as HH:MM:SS.
+fn format_duration(seconds: i64) -> String {
+    format!("{:02}:{:02}:{:02}", seconds / 3600, seconds / 60 % 60, seconds % 60)
+}
+
+
+// Read an `itunes:category` element and the categories nested in it.
+fn read_category(element: &ExtensionElement) -> ITunesCategory {
+    ITunesCategory {
+        text: element.attribute("text").unwrap_or_default(),
+        subcategories: element.children()
+                              .iter()
+                              .filter(|child| child.local_name() == "category")
+                              .map(read_category)
+                              .collect(),
+    }
+}
+
+
+// Construct the `itunes:category` element of the category.
+fn category_element(category: &ITunesCategory) -> ExtensionElement {
+    builder("category")
+        .attributes(vec![("text".to_owned(), category.text.clone())])
+        .children(category.subcategories.iter().map(category_element).collect())
+        .finalize()
+}
+
+
+// Read an `itunes:owner` element.
+fn read_owner(element: &ExtensionElement) -> ITunesOwner {
+    let children = element.children();
+    ITunesOwner {
+        name: util::extension_text(&children, "name"),
+        email: util::extension_text(&children, "email"),
+    }
+}
+
+
+// Construct the `itunes:owner` element of the owner.
+fn owner_element(owner: &ITunesOwner) -> ExtensionElement {
+    let mut children = Vec::new();
+    if let Some(ref name) = owner.name {
+        children.push(text_element("name", name));
+    }
+    if let Some(ref email) = owner.email {
+        children.push(text_element("email", email));
+    }
+    builder("owner").children(children).finalize()
+}
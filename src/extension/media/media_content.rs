@@ -0,0 +1,356 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under media content can be retrieved by using the methods under
+//! `MediaContent`.
+
+use extension::media::{MediaContent, MediaCredit, MediaText, MediaThumbnail};
+
+impl MediaContent {
+    /// Get the url of the media that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .url("http://example.com/video.mp4")
+    ///     .finalize();
+    /// assert_eq!("http://example.com/video.mp4".to_owned(), content.url());
+    /// ```
+    pub fn url(&self) -> String {
+        self.url.clone()
+    }
+
+
+    /// Get the optional mime type of the media that exists under
+    /// `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .content_type(Some("video/mp4".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("video/mp4".to_owned()), content.content_type());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .content_type(None)
+    ///     .finalize();
+    /// assert!(content.content_type().is_none());
+    /// ```
+    pub fn content_type(&self) -> Option<String> {
+        self.content_type.clone()
+    }
+
+
+    /// Get the optional kind of the media that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .medium(Some("video".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("video".to_owned()), content.medium());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .medium(None)
+    ///     .finalize();
+    /// assert!(content.medium().is_none());
+    /// ```
+    pub fn medium(&self) -> Option<String> {
+        self.medium.clone()
+    }
+
+
+    /// Get the optional size of the media in bytes that exists under
+    /// `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .file_size(Some(12216320))
+    ///     .finalize();
+    /// assert_eq!(Some(12216320), content.file_size());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .file_size(None)
+    ///     .finalize();
+    /// assert!(content.file_size().is_none());
+    /// ```
+    pub fn file_size(&self) -> Option<i64> {
+        self.file_size
+    }
+
+
+    /// Get the optional width of the media in pixels that exists under
+    /// `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .width(Some(1280))
+    ///     .finalize();
+    /// assert_eq!(Some(1280), content.width());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .width(None)
+    ///     .finalize();
+    /// assert!(content.width().is_none());
+    /// ```
+    pub fn width(&self) -> Option<i64> {
+        self.width
+    }
+
+
+    /// Get the optional height of the media in pixels that exists under
+    /// `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .height(Some(720))
+    ///     .finalize();
+    /// assert_eq!(Some(720), content.height());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .height(None)
+    ///     .finalize();
+    /// assert!(content.height().is_none());
+    /// ```
+    pub fn height(&self) -> Option<i64> {
+        self.height
+    }
+
+
+    /// Get the optional bitrate of the media in kilobits per second that exists
+    /// under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .bitrate(Some("2500".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("2500".to_owned()), content.bitrate());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .bitrate(None)
+    ///     .finalize();
+    /// assert!(content.bitrate().is_none());
+    /// ```
+    pub fn bitrate(&self) -> Option<String> {
+        self.bitrate.clone()
+    }
+
+
+    /// Get the optional duration of the media in seconds that exists under
+    /// `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .duration(Some(185))
+    ///     .finalize();
+    /// assert_eq!(Some(185), content.duration());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .duration(None)
+    ///     .finalize();
+    /// assert!(content.duration().is_none());
+    /// ```
+    pub fn duration(&self) -> Option<i64> {
+        self.duration
+    }
+
+
+    /// Get the optional language of the media that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .lang(Some("en".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("en".to_owned()), content.lang());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .lang(None)
+    ///     .finalize();
+    /// assert!(content.lang().is_none());
+    /// ```
+    pub fn lang(&self) -> Option<String> {
+        self.lang.clone()
+    }
+
+
+    /// Get whether the rendition is the default one of its group under
+    /// `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .default_rendition(true)
+    ///     .finalize();
+    /// assert!(content.default_rendition());
+    /// ```
+    pub fn default_rendition(&self) -> bool {
+        self.default_rendition
+    }
+
+
+    /// Get the optional title that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaTextBuilder};
+    ///
+    /// let text = MediaTextBuilder::new()
+    ///     .text("Fish & Chips")
+    ///     .finalize();
+    /// let content = MediaContentBuilder::new()
+    ///     .title(Some(text.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(text), content.title());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .title(None)
+    ///     .finalize();
+    /// assert!(content.title().is_none());
+    /// ```
+    pub fn title(&self) -> Option<MediaText> {
+        self.title.clone()
+    }
+
+
+    /// Get the optional description that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaTextBuilder};
+    ///
+    /// let text = MediaTextBuilder::new()
+    ///     .text("Fish & Chips")
+    ///     .finalize();
+    /// let content = MediaContentBuilder::new()
+    ///     .description(Some(text.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(text), content.description());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .description(None)
+    ///     .finalize();
+    /// assert!(content.description().is_none());
+    /// ```
+    pub fn description(&self) -> Option<MediaText> {
+        self.description.clone()
+    }
+
+
+    /// Get the thumbnails that exist under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaThumbnailBuilder};
+    ///
+    /// let thumbnail = MediaThumbnailBuilder::new()
+    ///     .url("http://example.com/thumbnail.jpg")
+    ///     .finalize();
+    /// let content = MediaContentBuilder::new()
+    ///     .thumbnails(vec![thumbnail.clone()])
+    ///     .finalize();
+    /// assert_eq!(vec![thumbnail], content.thumbnails());
+    /// ```
+    pub fn thumbnails(&self) -> Vec<MediaThumbnail> {
+        self.thumbnails.clone()
+    }
+
+
+    /// Get the credits that exist under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaCreditBuilder};
+    ///
+    /// let credit = MediaCreditBuilder::new()
+    ///     .name("Jane Doe")
+    ///     .finalize();
+    /// let content = MediaContentBuilder::new()
+    ///     .credits(vec![credit.clone()])
+    ///     .finalize();
+    /// assert_eq!(vec![credit], content.credits());
+    /// ```
+    pub fn credits(&self) -> Vec<MediaCredit> {
+        self.credits.clone()
+    }
+}
@@ -0,0 +1,314 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for media content by using the methods under
+//! `MediaContentBuilder`.
+
+use extension::media::{MediaContent, MediaContentBuilder, MediaCredit, MediaText, MediaThumbnail};
+
+impl MediaContentBuilder {
+    /// Construct a new `MediaContentBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let content_builder = MediaContentBuilder::new();
+    /// ```
+    pub fn new() -> MediaContentBuilder {
+        MediaContentBuilder::default()
+    }
+
+
+    /// Set the url of the media that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let mut content_builder = MediaContentBuilder::new();
+    /// content_builder.url("http://example.com/video.mp4");
+    /// ```
+    pub fn url(&mut self, url: &str) -> &mut MediaContentBuilder {
+        self.url = url.to_owned();
+        self
+    }
+
+
+    /// Set the optional mime type of the media that exists under
+    /// `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let mut content_builder = MediaContentBuilder::new();
+    /// content_builder.content_type(Some("video/mp4".to_owned()));
+    /// ```
+    pub fn content_type(&mut self, content_type: Option<String>) -> &mut MediaContentBuilder {
+        self.content_type = content_type;
+        self
+    }
+
+
+    /// Set the optional kind of the media that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let mut content_builder = MediaContentBuilder::new();
+    /// content_builder.medium(Some("video".to_owned()));
+    /// ```
+    pub fn medium(&mut self, medium: Option<String>) -> &mut MediaContentBuilder {
+        self.medium = medium;
+        self
+    }
+
+
+    /// Set the optional size of the media in bytes that exists under
+    /// `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let mut content_builder = MediaContentBuilder::new();
+    /// content_builder.file_size(Some(12216320));
+    /// ```
+    pub fn file_size(&mut self, file_size: Option<i64>) -> &mut MediaContentBuilder {
+        self.file_size = file_size;
+        self
+    }
+
+
+    /// Set the optional width of the media in pixels that exists under
+    /// `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let mut content_builder = MediaContentBuilder::new();
+    /// content_builder.width(Some(1280));
+    /// ```
+    pub fn width(&mut self, width: Option<i64>) -> &mut MediaContentBuilder {
+        self.width = width;
+        self
+    }
+
+
+    /// Set the optional height of the media in pixels that exists under
+    /// `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let mut content_builder = MediaContentBuilder::new();
+    /// content_builder.height(Some(720));
+    /// ```
+    pub fn height(&mut self, height: Option<i64>) -> &mut MediaContentBuilder {
+        self.height = height;
+        self
+    }
+
+
+    /// Set the optional bitrate of the media in kilobits per second that exists
+    /// under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let mut content_builder = MediaContentBuilder::new();
+    /// content_builder.bitrate(Some("2500".to_owned()));
+    /// ```
+    pub fn bitrate(&mut self, bitrate: Option<String>) -> &mut MediaContentBuilder {
+        self.bitrate = bitrate;
+        self
+    }
+
+
+    /// Set the optional duration of the media in seconds that exists under
+    /// `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let mut content_builder = MediaContentBuilder::new();
+    /// content_builder.duration(Some(185));
+    /// ```
+    pub fn duration(&mut self, duration: Option<i64>) -> &mut MediaContentBuilder {
+        self.duration = duration;
+        self
+    }
+
+
+    /// Set the optional language of the media that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let mut content_builder = MediaContentBuilder::new();
+    /// content_builder.lang(Some("en".to_owned()));
+    /// ```
+    pub fn lang(&mut self, lang: Option<String>) -> &mut MediaContentBuilder {
+        self.lang = lang;
+        self
+    }
+
+
+    /// Set whether the rendition is the default one of its group under
+    /// `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let mut content_builder = MediaContentBuilder::new();
+    /// content_builder.default_rendition(true);
+    /// ```
+    pub fn default_rendition(&mut self, default_rendition: bool) -> &mut MediaContentBuilder {
+        self.default_rendition = default_rendition;
+        self
+    }
+
+
+    /// Set the optional title that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaTextBuilder};
+    ///
+    /// let text = MediaTextBuilder::new()
+    ///     .text("Fish & Chips")
+    ///     .finalize();
+    ///
+    /// let mut content_builder = MediaContentBuilder::new();
+    /// content_builder.title(Some(text));
+    /// ```
+    pub fn title(&mut self, title: Option<MediaText>) -> &mut MediaContentBuilder {
+        self.title = title;
+        self
+    }
+
+
+    /// Set the optional description that exists under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaTextBuilder};
+    ///
+    /// let text = MediaTextBuilder::new()
+    ///     .text("Fish & Chips")
+    ///     .finalize();
+    ///
+    /// let mut content_builder = MediaContentBuilder::new();
+    /// content_builder.description(Some(text));
+    /// ```
+    pub fn description(&mut self, description: Option<MediaText>) -> &mut MediaContentBuilder {
+        self.description = description;
+        self
+    }
+
+
+    /// Set the thumbnails that exist under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaThumbnailBuilder};
+    ///
+    /// let thumbnail = MediaThumbnailBuilder::new()
+    ///     .url("http://example.com/thumbnail.jpg")
+    ///     .finalize();
+    ///
+    /// let mut content_builder = MediaContentBuilder::new();
+    /// content_builder.thumbnails(vec![thumbnail]);
+    /// ```
+    pub fn thumbnails(&mut self, thumbnails: Vec<MediaThumbnail>) -> &mut MediaContentBuilder {
+        self.thumbnails = thumbnails;
+        self
+    }
+
+
+    /// Set the credits that exist under `MediaContent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaCreditBuilder};
+    ///
+    /// let credit = MediaCreditBuilder::new()
+    ///     .name("Jane Doe")
+    ///     .finalize();
+    ///
+    /// let mut content_builder = MediaContentBuilder::new();
+    /// content_builder.credits(vec![credit]);
+    /// ```
+    pub fn credits(&mut self, credits: Vec<MediaCredit>) -> &mut MediaContentBuilder {
+        self.credits = credits;
+        self
+    }
+
+
+    /// Construct the `MediaContent` from the `MediaContentBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaContentBuilder;
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///         .url("http://example.com/video.mp4")
+    ///         .content_type(Some("video/mp4".to_owned()))
+    ///         .medium(Some("video".to_owned()))
+    ///         .file_size(Some(12216320))
+    ///         .width(Some(1280))
+    ///         .height(Some(720))
+    ///         .bitrate(Some("2500".to_owned()))
+    ///         .duration(Some(185))
+    ///         .lang(Some("en".to_owned()))
+    ///         .default_rendition(false)
+    ///         .title(None)
+    ///         .description(None)
+    ///         .thumbnails(Vec::new())
+    ///         .credits(Vec::new())
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> MediaContent {
+        MediaContent {
+            url: self.url.clone(),
+            content_type: self.content_type.clone(),
+            medium: self.medium.clone(),
+            file_size: self.file_size,
+            width: self.width,
+            height: self.height,
+            bitrate: self.bitrate.clone(),
+            duration: self.duration,
+            lang: self.lang.clone(),
+            default_rendition: self.default_rendition,
+            title: self.title.clone(),
+            description: self.description.clone(),
+            thumbnails: self.thumbnails.clone(),
+            credits: self.credits.clone(),
+        }
+    }
+}
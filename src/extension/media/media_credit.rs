@@ -0,0 +1,79 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under media credit can be retrieved by using the methods under
+//! `MediaCredit`.
+
+use extension::media::MediaCredit;
+
+impl MediaCredit {
+    /// Get the name of the entity that exists under `MediaCredit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaCreditBuilder;
+    ///
+    /// let credit = MediaCreditBuilder::new()
+    ///     .name("Jane Doe")
+    ///     .finalize();
+    /// assert_eq!("Jane Doe".to_owned(), credit.name());
+    /// ```
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+
+    /// Get the optional role of the entity that exists under `MediaCredit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaCreditBuilder;
+    ///
+    /// let credit = MediaCreditBuilder::new()
+    ///     .role(Some("producer".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("producer".to_owned()), credit.role());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::MediaCreditBuilder;
+    ///
+    /// let credit = MediaCreditBuilder::new()
+    ///     .role(None)
+    ///     .finalize();
+    /// assert!(credit.role().is_none());
+    /// ```
+    pub fn role(&self) -> Option<String> {
+        self.role.clone()
+    }
+
+
+    /// Get the optional uri of the scheme of the role that exists under
+    /// `MediaCredit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaCreditBuilder;
+    ///
+    /// let credit = MediaCreditBuilder::new()
+    ///     .scheme(Some("urn:ebu".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("urn:ebu".to_owned()), credit.scheme());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::MediaCreditBuilder;
+    ///
+    /// let credit = MediaCreditBuilder::new()
+    ///     .scheme(None)
+    ///     .finalize();
+    /// assert!(credit.scheme().is_none());
+    /// ```
+    pub fn scheme(&self) -> Option<String> {
+        self.scheme.clone()
+    }
+}
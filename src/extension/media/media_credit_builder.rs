@@ -0,0 +1,94 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for media credit by using the methods under
+//! `MediaCreditBuilder`.
+
+use extension::media::{MediaCredit, MediaCreditBuilder};
+
+impl MediaCreditBuilder {
+    /// Construct a new `MediaCreditBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaCreditBuilder;
+    ///
+    /// let credit_builder = MediaCreditBuilder::new();
+    /// ```
+    pub fn new() -> MediaCreditBuilder {
+        MediaCreditBuilder::default()
+    }
+
+
+    /// Set the name of the entity that exists under `MediaCredit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaCreditBuilder;
+    ///
+    /// let mut credit_builder = MediaCreditBuilder::new();
+    /// credit_builder.name("Jane Doe");
+    /// ```
+    pub fn name(&mut self, name: &str) -> &mut MediaCreditBuilder {
+        self.name = name.to_owned();
+        self
+    }
+
+
+    /// Set the optional role of the entity that exists under `MediaCredit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaCreditBuilder;
+    ///
+    /// let mut credit_builder = MediaCreditBuilder::new();
+    /// credit_builder.role(Some("producer".to_owned()));
+    /// ```
+    pub fn role(&mut self, role: Option<String>) -> &mut MediaCreditBuilder {
+        self.role = role;
+        self
+    }
+
+
+    /// Set the optional uri of the scheme of the role that exists under
+    /// `MediaCredit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaCreditBuilder;
+    ///
+    /// let mut credit_builder = MediaCreditBuilder::new();
+    /// credit_builder.scheme(Some("urn:ebu".to_owned()));
+    /// ```
+    pub fn scheme(&mut self, scheme: Option<String>) -> &mut MediaCreditBuilder {
+        self.scheme = scheme;
+        self
+    }
+
+
+    /// Construct the `MediaCredit` from the `MediaCreditBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaCreditBuilder;
+    ///
+    /// let credit = MediaCreditBuilder::new()
+    ///         .name("Jane Doe")
+    ///         .role(Some("producer".to_owned()))
+    ///         .scheme(Some("urn:ebu".to_owned()))
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> MediaCredit {
+        MediaCredit {
+            name: self.name.clone(),
+            role: self.role.clone(),
+            scheme: self.scheme.clone(),
+        }
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under media extension can be retrieved by using the methods under
+//! `MediaExtension`.
+
+use extension::{Extension, ExtensionElement, MEDIA_NAMESPACE};
+use extension::media::{self, MediaContent, MediaCredit, MediaExtension, MediaGroup, MediaText,
+                       MediaThumbnail};
+
+impl MediaExtension {
+    /// Get the groups of renditions that exist under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaExtensionBuilder, MediaGroupBuilder};
+    ///
+    /// let group = MediaGroupBuilder::new()
+    ///     .contents(Vec::new())
+    ///     .finalize();
+    /// let media = MediaExtensionBuilder::new()
+    ///     .groups(vec![group.clone()])
+    ///     .finalize();
+    /// assert_eq!(vec![group], media.groups());
+    /// ```
+    pub fn groups(&self) -> Vec<MediaGroup> {
+        self.groups.clone()
+    }
+
+
+    /// Get the contents that exist under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaExtensionBuilder};
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .url("http://example.com/video.mp4")
+    ///     .finalize();
+    /// let media = MediaExtensionBuilder::new()
+    ///     .contents(vec![content.clone()])
+    ///     .finalize();
+    /// assert_eq!(vec![content], media.contents());
+    /// ```
+    pub fn contents(&self) -> Vec<MediaContent> {
+        self.contents.clone()
+    }
+
+
+    /// Get the optional title that exists under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaExtensionBuilder, MediaTextBuilder};
+    ///
+    /// let text = MediaTextBuilder::new()
+    ///     .text("Fish & Chips")
+    ///     .finalize();
+    /// let media = MediaExtensionBuilder::new()
+    ///     .title(Some(text.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(text), media.title());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::MediaExtensionBuilder;
+    ///
+    /// let media = MediaExtensionBuilder::new()
+    ///     .title(None)
+    ///     .finalize();
+    /// assert!(media.title().is_none());
+    /// ```
+    pub fn title(&self) -> Option<MediaText> {
+        self.title.clone()
+    }
+
+
+    /// Get the optional description that exists under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaExtensionBuilder, MediaTextBuilder};
+    ///
+    /// let text = MediaTextBuilder::new()
+    ///     .text("Fish & Chips")
+    ///     .finalize();
+    /// let media = MediaExtensionBuilder::new()
+    ///     .description(Some(text.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(text), media.description());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::MediaExtensionBuilder;
+    ///
+    /// let media = MediaExtensionBuilder::new()
+    ///     .description(None)
+    ///     .finalize();
+    /// assert!(media.description().is_none());
+    /// ```
+    pub fn description(&self) -> Option<MediaText> {
+        self.description.clone()
+    }
+
+
+    /// Get the thumbnails that exist under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaExtensionBuilder, MediaThumbnailBuilder};
+    ///
+    /// let thumbnail = MediaThumbnailBuilder::new()
+    ///     .url("http://example.com/thumbnail.jpg")
+    ///     .finalize();
+    /// let media = MediaExtensionBuilder::new()
+    ///     .thumbnails(vec![thumbnail.clone()])
+    ///     .finalize();
+    /// assert_eq!(vec![thumbnail], media.thumbnails());
+    /// ```
+    pub fn thumbnails(&self) -> Vec<MediaThumbnail> {
+        self.thumbnails.clone()
+    }
+
+
+    /// Get the credits that exist under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaCreditBuilder, MediaExtensionBuilder};
+    ///
+    /// let credit = MediaCreditBuilder::new()
+    ///     .name("Jane Doe")
+    ///     .finalize();
+    /// let media = MediaExtensionBuilder::new()
+    ///     .credits(vec![credit.clone()])
+    ///     .finalize();
+    /// assert_eq!(vec![credit], media.credits());
+    /// ```
+    pub fn credits(&self) -> Vec<MediaCredit> {
+        self.credits.clone()
+    }
+}
+
+
+impl Extension for MediaExtension {
+    const NAMESPACE: &'static str = MEDIA_NAMESPACE;
+    const PREFIX: &'static str = "media";
+
+
+    fn from_elements(elements: &[ExtensionElement]) -> Option<MediaExtension> {
+        Some(MediaExtension {
+            groups: media::elements_named(elements, "group").map(media::read_group).collect(),
+            contents: media::elements_named(elements, "content").map(media::read_content).collect(),
+            title: media::read_text(elements, "title"),
+            description: media::read_text(elements, "description"),
+            thumbnails: media::read_thumbnails(elements),
+            credits: media::read_credits(elements),
+        })
+    }
+
+
+    fn to_elements(&self) -> Vec<ExtensionElement> {
+        let mut elements = self.groups.iter().map(media::group_element).collect::<Vec<_>>();
+        elements.extend(self.contents.iter().map(media::content_element));
+        media::push_details(&mut elements,
+                            &self.title,
+                            &self.description,
+                            &self.thumbnails,
+                            &self.credits);
+        elements
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for media extension by using the methods under
+//! `MediaExtensionBuilder`.
+
+use extension::media::{MediaContent, MediaCredit, MediaExtension, MediaExtensionBuilder,
+                       MediaGroup, MediaText, MediaThumbnail};
+
+impl MediaExtensionBuilder {
+    /// Construct a new `MediaExtensionBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaExtensionBuilder;
+    ///
+    /// let media_builder = MediaExtensionBuilder::new();
+    /// ```
+    pub fn new() -> MediaExtensionBuilder {
+        MediaExtensionBuilder::default()
+    }
+
+
+    /// Set the groups of renditions that exist under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaExtensionBuilder, MediaGroupBuilder};
+    ///
+    /// let group = MediaGroupBuilder::new()
+    ///     .contents(Vec::new())
+    ///     .finalize();
+    ///
+    /// let mut media_builder = MediaExtensionBuilder::new();
+    /// media_builder.groups(vec![group]);
+    /// ```
+    pub fn groups(&mut self, groups: Vec<MediaGroup>) -> &mut MediaExtensionBuilder {
+        self.groups = groups;
+        self
+    }
+
+
+    /// Set the contents that exist under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaExtensionBuilder};
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .url("http://example.com/video.mp4")
+    ///     .finalize();
+    ///
+    /// let mut media_builder = MediaExtensionBuilder::new();
+    /// media_builder.contents(vec![content]);
+    /// ```
+    pub fn contents(&mut self, contents: Vec<MediaContent>) -> &mut MediaExtensionBuilder {
+        self.contents = contents;
+        self
+    }
+
+
+    /// Set the optional title that exists under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaExtensionBuilder, MediaTextBuilder};
+    ///
+    /// let text = MediaTextBuilder::new()
+    ///     .text("Fish & Chips")
+    ///     .finalize();
+    ///
+    /// let mut media_builder = MediaExtensionBuilder::new();
+    /// media_builder.title(Some(text));
+    /// ```
+    pub fn title(&mut self, title: Option<MediaText>) -> &mut MediaExtensionBuilder {
+        self.title = title;
+        self
+    }
+
+
+    /// Set the optional description that exists under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaExtensionBuilder, MediaTextBuilder};
+    ///
+    /// let text = MediaTextBuilder::new()
+    ///     .text("Fish & Chips")
+    ///     .finalize();
+    ///
+    /// let mut media_builder = MediaExtensionBuilder::new();
+    /// media_builder.description(Some(text));
+    /// ```
+    pub fn description(&mut self, description: Option<MediaText>) -> &mut MediaExtensionBuilder {
+        self.description = description;
+        self
+    }
+
+
+    /// Set the thumbnails that exist under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaExtensionBuilder, MediaThumbnailBuilder};
+    ///
+    /// let thumbnail = MediaThumbnailBuilder::new()
+    ///     .url("http://example.com/thumbnail.jpg")
+    ///     .finalize();
+    ///
+    /// let mut media_builder = MediaExtensionBuilder::new();
+    /// media_builder.thumbnails(vec![thumbnail]);
+    /// ```
+    pub fn thumbnails(&mut self, thumbnails: Vec<MediaThumbnail>) -> &mut MediaExtensionBuilder {
+        self.thumbnails = thumbnails;
+        self
+    }
+
+
+    /// Set the credits that exist under `MediaExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaCreditBuilder, MediaExtensionBuilder};
+    ///
+    /// let credit = MediaCreditBuilder::new()
+    ///     .name("Jane Doe")
+    ///     .finalize();
+    ///
+    /// let mut media_builder = MediaExtensionBuilder::new();
+    /// media_builder.credits(vec![credit]);
+    /// ```
+    pub fn credits(&mut self, credits: Vec<MediaCredit>) -> &mut MediaExtensionBuilder {
+        self.credits = credits;
+        self
+    }
+
+
+    /// Construct the `MediaExtension` from the `MediaExtensionBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaExtensionBuilder;
+    ///
+    /// let media = MediaExtensionBuilder::new()
+    ///         .groups(Vec::new())
+    ///         .contents(Vec::new())
+    ///         .title(None)
+    ///         .description(None)
+    ///         .thumbnails(Vec::new())
+    ///         .credits(Vec::new())
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> MediaExtension {
+        MediaExtension {
+            groups: self.groups.clone(),
+            contents: self.contents.clone(),
+            title: self.title.clone(),
+            description: self.description.clone(),
+            thumbnails: self.thumbnails.clone(),
+            credits: self.credits.clone(),
+        }
+    }
+}
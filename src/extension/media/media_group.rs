@@ -0,0 +1,127 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under media group can be retrieved by using the methods under
+//! `MediaGroup`.
+
+use extension::media::{MediaContent, MediaCredit, MediaGroup, MediaText, MediaThumbnail};
+
+impl MediaGroup {
+    /// Get the renditions of the media that exist under `MediaGroup`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaGroupBuilder};
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .url("http://example.com/video.mp4")
+    ///     .finalize();
+    /// let group = MediaGroupBuilder::new()
+    ///     .contents(vec![content.clone()])
+    ///     .finalize();
+    /// assert_eq!(vec![content], group.contents());
+    /// ```
+    pub fn contents(&self) -> Vec<MediaContent> {
+        self.contents.clone()
+    }
+
+
+    /// Get the optional title that exists under `MediaGroup`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaGroupBuilder, MediaTextBuilder};
+    ///
+    /// let text = MediaTextBuilder::new()
+    ///     .text("Fish & Chips")
+    ///     .finalize();
+    /// let group = MediaGroupBuilder::new()
+    ///     .title(Some(text.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(text), group.title());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::MediaGroupBuilder;
+    ///
+    /// let group = MediaGroupBuilder::new()
+    ///     .title(None)
+    ///     .finalize();
+    /// assert!(group.title().is_none());
+    /// ```
+    pub fn title(&self) -> Option<MediaText> {
+        self.title.clone()
+    }
+
+
+    /// Get the optional description that exists under `MediaGroup`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaGroupBuilder, MediaTextBuilder};
+    ///
+    /// let text = MediaTextBuilder::new()
+    ///     .text("Fish & Chips")
+    ///     .finalize();
+    /// let group = MediaGroupBuilder::new()
+    ///     .description(Some(text.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(text), group.description());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::MediaGroupBuilder;
+    ///
+    /// let group = MediaGroupBuilder::new()
+    ///     .description(None)
+    ///     .finalize();
+    /// assert!(group.description().is_none());
+    /// ```
+    pub fn description(&self) -> Option<MediaText> {
+        self.description.clone()
+    }
+
+
+    /// Get the thumbnails that exist under `MediaGroup`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaGroupBuilder, MediaThumbnailBuilder};
+    ///
+    /// let thumbnail = MediaThumbnailBuilder::new()
+    ///     .url("http://example.com/thumbnail.jpg")
+    ///     .finalize();
+    /// let group = MediaGroupBuilder::new()
+    ///     .thumbnails(vec![thumbnail.clone()])
+    ///     .finalize();
+    /// assert_eq!(vec![thumbnail], group.thumbnails());
+    /// ```
+    pub fn thumbnails(&self) -> Vec<MediaThumbnail> {
+        self.thumbnails.clone()
+    }
+
+
+    /// Get the credits that exist under `MediaGroup`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaCreditBuilder, MediaGroupBuilder};
+    ///
+    /// let credit = MediaCreditBuilder::new()
+    ///     .name("Jane Doe")
+    ///     .finalize();
+    /// let group = MediaGroupBuilder::new()
+    ///     .credits(vec![credit.clone()])
+    ///     .finalize();
+    /// assert_eq!(vec![credit], group.credits());
+    /// ```
+    pub fn credits(&self) -> Vec<MediaCredit> {
+        self.credits.clone()
+    }
+}
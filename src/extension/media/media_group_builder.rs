@@ -0,0 +1,150 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for media group by using the methods under
+//! `MediaGroupBuilder`.
+
+use extension::media::{MediaContent, MediaCredit, MediaGroup, MediaGroupBuilder, MediaText,
+                       MediaThumbnail};
+
+impl MediaGroupBuilder {
+    /// Construct a new `MediaGroupBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaGroupBuilder;
+    ///
+    /// let group_builder = MediaGroupBuilder::new();
+    /// ```
+    pub fn new() -> MediaGroupBuilder {
+        MediaGroupBuilder::default()
+    }
+
+
+    /// Set the renditions of the media that exist under `MediaGroup`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaGroupBuilder};
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .url("http://example.com/video.mp4")
+    ///     .finalize();
+    ///
+    /// let mut group_builder = MediaGroupBuilder::new();
+    /// group_builder.contents(vec![content]);
+    /// ```
+    pub fn contents(&mut self, contents: Vec<MediaContent>) -> &mut MediaGroupBuilder {
+        self.contents = contents;
+        self
+    }
+
+
+    /// Set the optional title that exists under `MediaGroup`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaGroupBuilder, MediaTextBuilder};
+    ///
+    /// let text = MediaTextBuilder::new()
+    ///     .text("Fish & Chips")
+    ///     .finalize();
+    ///
+    /// let mut group_builder = MediaGroupBuilder::new();
+    /// group_builder.title(Some(text));
+    /// ```
+    pub fn title(&mut self, title: Option<MediaText>) -> &mut MediaGroupBuilder {
+        self.title = title;
+        self
+    }
+
+
+    /// Set the optional description that exists under `MediaGroup`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaGroupBuilder, MediaTextBuilder};
+    ///
+    /// let text = MediaTextBuilder::new()
+    ///     .text("Fish & Chips")
+    ///     .finalize();
+    ///
+    /// let mut group_builder = MediaGroupBuilder::new();
+    /// group_builder.description(Some(text));
+    /// ```
+    pub fn description(&mut self, description: Option<MediaText>) -> &mut MediaGroupBuilder {
+        self.description = description;
+        self
+    }
+
+
+    /// Set the thumbnails that exist under `MediaGroup`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaGroupBuilder, MediaThumbnailBuilder};
+    ///
+    /// let thumbnail = MediaThumbnailBuilder::new()
+    ///     .url("http://example.com/thumbnail.jpg")
+    ///     .finalize();
+    ///
+    /// let mut group_builder = MediaGroupBuilder::new();
+    /// group_builder.thumbnails(vec![thumbnail]);
+    /// ```
+    pub fn thumbnails(&mut self, thumbnails: Vec<MediaThumbnail>) -> &mut MediaGroupBuilder {
+        self.thumbnails = thumbnails;
+        self
+    }
+
+
+    /// Set the credits that exist under `MediaGroup`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaCreditBuilder, MediaGroupBuilder};
+    ///
+    /// let credit = MediaCreditBuilder::new()
+    ///     .name("Jane Doe")
+    ///     .finalize();
+    ///
+    /// let mut group_builder = MediaGroupBuilder::new();
+    /// group_builder.credits(vec![credit]);
+    /// ```
+    pub fn credits(&mut self, credits: Vec<MediaCredit>) -> &mut MediaGroupBuilder {
+        self.credits = credits;
+        self
+    }
+
+
+    /// Construct the `MediaGroup` from the `MediaGroupBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaGroupBuilder;
+    ///
+    /// let group = MediaGroupBuilder::new()
+    ///         .contents(Vec::new())
+    ///         .title(None)
+    ///         .description(None)
+    ///         .thumbnails(Vec::new())
+    ///         .credits(Vec::new())
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> MediaGroup {
+        MediaGroup {
+            contents: self.contents.clone(),
+            title: self.title.clone(),
+            description: self.description.clone(),
+            thumbnails: self.thumbnails.clone(),
+            credits: self.credits.clone(),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under media text can be retrieved by using the methods under
+//! `MediaText`.
+
+use extension::media::MediaText;
+
+impl MediaText {
+    /// Get the text that exists under `MediaText`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaTextBuilder;
+    ///
+    /// let text = MediaTextBuilder::new()
+    ///     .text("Fish & Chips")
+    ///     .finalize();
+    /// assert_eq!("Fish & Chips".to_owned(), text.text());
+    /// ```
+    pub fn text(&self) -> String {
+        self.text.clone()
+    }
+
+
+    /// Get the optional type of the text that exists under `MediaText`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaTextBuilder;
+    ///
+    /// let text = MediaTextBuilder::new()
+    ///     .text_type(Some("plain".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("plain".to_owned()), text.text_type());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::MediaTextBuilder;
+    ///
+    /// let text = MediaTextBuilder::new()
+    ///     .text_type(None)
+    ///     .finalize();
+    /// assert!(text.text_type().is_none());
+    /// ```
+    pub fn text_type(&self) -> Option<String> {
+        self.text_type.clone()
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for media text by using the methods under
+//! `MediaTextBuilder`.
+
+use extension::media::{MediaText, MediaTextBuilder};
+
+impl MediaTextBuilder {
+    /// Construct a new `MediaTextBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaTextBuilder;
+    ///
+    /// let text_builder = MediaTextBuilder::new();
+    /// ```
+    pub fn new() -> MediaTextBuilder {
+        MediaTextBuilder::default()
+    }
+
+
+    /// Set the text that exists under `MediaText`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaTextBuilder;
+    ///
+    /// let mut text_builder = MediaTextBuilder::new();
+    /// text_builder.text("Fish & Chips");
+    /// ```
+    pub fn text(&mut self, text: &str) -> &mut MediaTextBuilder {
+        self.text = text.to_owned();
+        self
+    }
+
+
+    /// Set the optional type of the text that exists under `MediaText`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaTextBuilder;
+    ///
+    /// let mut text_builder = MediaTextBuilder::new();
+    /// text_builder.text_type(Some("plain".to_owned()));
+    /// ```
+    pub fn text_type(&mut self, text_type: Option<String>) -> &mut MediaTextBuilder {
+        self.text_type = text_type;
+        self
+    }
+
+
+    /// Construct the `MediaText` from the `MediaTextBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaTextBuilder;
+    ///
+    /// let text = MediaTextBuilder::new()
+    ///         .text("Fish & Chips")
+    ///         .text_type(Some("plain".to_owned()))
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> MediaText {
+        MediaText {
+            text: self.text.clone(),
+            text_type: self.text_type.clone(),
+        }
+    }
+}
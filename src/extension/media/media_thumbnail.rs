@@ -0,0 +1,107 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under media thumbnail can be retrieved by using the methods under
+//! `MediaThumbnail`.
+
+use extension::media::MediaThumbnail;
+
+impl MediaThumbnail {
+    /// Get the url of the image that exists under `MediaThumbnail`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaThumbnailBuilder;
+    ///
+    /// let thumbnail = MediaThumbnailBuilder::new()
+    ///     .url("http://example.com/thumbnail.jpg")
+    ///     .finalize();
+    /// assert_eq!("http://example.com/thumbnail.jpg".to_owned(), thumbnail.url());
+    /// ```
+    pub fn url(&self) -> String {
+        self.url.clone()
+    }
+
+
+    /// Get the optional width of the image in pixels that exists under
+    /// `MediaThumbnail`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaThumbnailBuilder;
+    ///
+    /// let thumbnail = MediaThumbnailBuilder::new()
+    ///     .width(Some(320))
+    ///     .finalize();
+    /// assert_eq!(Some(320), thumbnail.width());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::MediaThumbnailBuilder;
+    ///
+    /// let thumbnail = MediaThumbnailBuilder::new()
+    ///     .width(None)
+    ///     .finalize();
+    /// assert!(thumbnail.width().is_none());
+    /// ```
+    pub fn width(&self) -> Option<i64> {
+        self.width
+    }
+
+
+    /// Get the optional height of the image in pixels that exists under
+    /// `MediaThumbnail`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaThumbnailBuilder;
+    ///
+    /// let thumbnail = MediaThumbnailBuilder::new()
+    ///     .height(Some(180))
+    ///     .finalize();
+    /// assert_eq!(Some(180), thumbnail.height());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::MediaThumbnailBuilder;
+    ///
+    /// let thumbnail = MediaThumbnailBuilder::new()
+    ///     .height(None)
+    ///     .finalize();
+    /// assert!(thumbnail.height().is_none());
+    /// ```
+    pub fn height(&self) -> Option<i64> {
+        self.height
+    }
+
+
+    /// Get the optional time in the media the image was taken at that exists
+    /// under `MediaThumbnail`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaThumbnailBuilder;
+    ///
+    /// let thumbnail = MediaThumbnailBuilder::new()
+    ///     .time(Some("00:01:02.5".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("00:01:02.5".to_owned()), thumbnail.time());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::media::MediaThumbnailBuilder;
+    ///
+    /// let thumbnail = MediaThumbnailBuilder::new()
+    ///     .time(None)
+    ///     .finalize();
+    /// assert!(thumbnail.time().is_none());
+    /// ```
+    pub fn time(&self) -> Option<String> {
+        self.time.clone()
+    }
+}
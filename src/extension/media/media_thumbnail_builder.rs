@@ -0,0 +1,114 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for media thumbnail by using the methods under
+//! `MediaThumbnailBuilder`.
+
+use extension::media::{MediaThumbnail, MediaThumbnailBuilder};
+
+impl MediaThumbnailBuilder {
+    /// Construct a new `MediaThumbnailBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaThumbnailBuilder;
+    ///
+    /// let thumbnail_builder = MediaThumbnailBuilder::new();
+    /// ```
+    pub fn new() -> MediaThumbnailBuilder {
+        MediaThumbnailBuilder::default()
+    }
+
+
+    /// Set the url of the image that exists under `MediaThumbnail`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaThumbnailBuilder;
+    ///
+    /// let mut thumbnail_builder = MediaThumbnailBuilder::new();
+    /// thumbnail_builder.url("http://example.com/thumbnail.jpg");
+    /// ```
+    pub fn url(&mut self, url: &str) -> &mut MediaThumbnailBuilder {
+        self.url = url.to_owned();
+        self
+    }
+
+
+    /// Set the optional width of the image in pixels that exists under
+    /// `MediaThumbnail`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaThumbnailBuilder;
+    ///
+    /// let mut thumbnail_builder = MediaThumbnailBuilder::new();
+    /// thumbnail_builder.width(Some(320));
+    /// ```
+    pub fn width(&mut self, width: Option<i64>) -> &mut MediaThumbnailBuilder {
+        self.width = width;
+        self
+    }
+
+
+    /// Set the optional height of the image in pixels that exists under
+    /// `MediaThumbnail`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaThumbnailBuilder;
+    ///
+    /// let mut thumbnail_builder = MediaThumbnailBuilder::new();
+    /// thumbnail_builder.height(Some(180));
+    /// ```
+    pub fn height(&mut self, height: Option<i64>) -> &mut MediaThumbnailBuilder {
+        self.height = height;
+        self
+    }
+
+
+    /// Set the optional time in the media the image was taken at that exists
+    /// under `MediaThumbnail`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaThumbnailBuilder;
+    ///
+    /// let mut thumbnail_builder = MediaThumbnailBuilder::new();
+    /// thumbnail_builder.time(Some("00:01:02.5".to_owned()));
+    /// ```
+    pub fn time(&mut self, time: Option<String>) -> &mut MediaThumbnailBuilder {
+        self.time = time;
+        self
+    }
+
+
+    /// Construct the `MediaThumbnail` from the `MediaThumbnailBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::MediaThumbnailBuilder;
+    ///
+    /// let thumbnail = MediaThumbnailBuilder::new()
+    ///         .url("http://example.com/thumbnail.jpg")
+    ///         .width(Some(320))
+    ///         .height(Some(180))
+    ///         .time(Some("00:01:02.5".to_owned()))
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> MediaThumbnail {
+        MediaThumbnail {
+            url: self.url.clone(),
+            width: self.width,
+            height: self.height,
+            time: self.time.clone(),
+        }
+    }
+}
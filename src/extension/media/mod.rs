@@ -0,0 +1,346 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! All the structs for the Media RSS elements of a channel and an item.
+
+pub mod media_content;
+pub mod media_content_builder;
+pub mod media_credit;
+pub mod media_credit_builder;
+pub mod media_extension;
+pub mod media_extension_builder;
+pub mod media_group;
+pub mod media_group_builder;
+pub mod media_text;
+pub mod media_text_builder;
+pub mod media_thumbnail;
+pub mod media_thumbnail_builder;
+
+use extension::{ExtensionElement, ExtensionElementBuilder, MEDIA_NAMESPACE};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use util;
+
+/// This `MediaContent` struct contains a rendition of the media of an item,
+/// with the elements describing it.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct MediaContent {
+    url: String,
+    content_type: Option<String>,
+    medium: Option<String>,
+    file_size: Option<i64>,
+    width: Option<i64>,
+    height: Option<i64>,
+    bitrate: Option<String>,
+    duration: Option<i64>,
+    lang: Option<String>,
+    default_rendition: bool,
+    title: Option<MediaText>,
+    description: Option<MediaText>,
+    thumbnails: Vec<MediaThumbnail>,
+    credits: Vec<MediaCredit>,
+}
+
+
+/// This `MediaContentBuilder` struct creates the `MediaContent`.
+#[derive(Default)]
+pub struct MediaContentBuilder {
+    url: String,
+    content_type: Option<String>,
+    medium: Option<String>,
+    file_size: Option<i64>,
+    width: Option<i64>,
+    height: Option<i64>,
+    bitrate: Option<String>,
+    duration: Option<i64>,
+    lang: Option<String>,
+    default_rendition: bool,
+    title: Option<MediaText>,
+    description: Option<MediaText>,
+    thumbnails: Vec<MediaThumbnail>,
+    credits: Vec<MediaCredit>,
+}
+
+
+/// This `MediaCredit` struct contains an entity that contributed to the media.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct MediaCredit {
+    name: String,
+    role: Option<String>,
+    scheme: Option<String>,
+}
+
+
+/// This `MediaCreditBuilder` struct creates the `MediaCredit`.
+#[derive(Default)]
+pub struct MediaCreditBuilder {
+    name: String,
+    role: Option<String>,
+    scheme: Option<String>,
+}
+
+
+/// This `MediaExtension` struct contains the Media RSS elements of a `Channel`
+/// or an `Item`.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct MediaExtension {
+    groups: Vec<MediaGroup>,
+    contents: Vec<MediaContent>,
+    title: Option<MediaText>,
+    description: Option<MediaText>,
+    thumbnails: Vec<MediaThumbnail>,
+    credits: Vec<MediaCredit>,
+}
+
+
+/// This `MediaExtensionBuilder` struct creates the `MediaExtension`.
+#[derive(Default)]
+pub struct MediaExtensionBuilder {
+    groups: Vec<MediaGroup>,
+    contents: Vec<MediaContent>,
+    title: Option<MediaText>,
+    description: Option<MediaText>,
+    thumbnails: Vec<MediaThumbnail>,
+    credits: Vec<MediaCredit>,
+}
+
+
+/// This `MediaGroup` struct contains the renditions of the same media, with
+/// the elements describing all of them.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct MediaGroup {
+    contents: Vec<MediaContent>,
+    title: Option<MediaText>,
+    description: Option<MediaText>,
+    thumbnails: Vec<MediaThumbnail>,
+    credits: Vec<MediaCredit>,
+}
+
+
+/// This `MediaGroupBuilder` struct creates the `MediaGroup`.
+#[derive(Default)]
+pub struct MediaGroupBuilder {
+    contents: Vec<MediaContent>,
+    title: Option<MediaText>,
+    description: Option<MediaText>,
+    thumbnails: Vec<MediaThumbnail>,
+    credits: Vec<MediaCredit>,
+}
+
+
+/// This `MediaText` struct contains the text of a title or a description,
+/// with its type.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct MediaText {
+    text: String,
+    text_type: Option<String>,
+}
+
+
+/// This `MediaTextBuilder` struct creates the `MediaText`.
+#[derive(Default)]
+pub struct MediaTextBuilder {
+    text: String,
+    text_type: Option<String>,
+}
+
+
+/// This `MediaThumbnail` struct contains an image representing the media.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct MediaThumbnail {
+    url: String,
+    width: Option<i64>,
+    height: Option<i64>,
+    time: Option<String>,
+}
+
+
+/// This `MediaThumbnailBuilder` struct creates the `MediaThumbnail`.
+#[derive(Default)]
+pub struct MediaThumbnailBuilder {
+    url: String,
+    width: Option<i64>,
+    height: Option<i64>,
+    time: Option<String>,
+}
+
+
+// Construct the builder of a Media RSS element with the local name.
+fn builder(local_name: &str) -> ExtensionElementBuilder {
+    util::extension_builder(&format!("media:{}", local_name), MEDIA_NAMESPACE)
+}
+
+
+// Construct the attributes of an element from the ones that are set, in the
+// order they are given.
+fn attributes(attributes: &[(&str, Option<String>)]) -> Vec<(String, String)> {
+    attributes.iter()
+              .filter_map(|(key, value)| value.clone().map(|value| ((*key).to_owned(), value)))
+              .collect()
+}
+
+
+// Get the elements with the local name.
+fn elements_named<'a>(elements: &'a [ExtensionElement],
+                      local_name: &'a str)
+                      -> impl Iterator<Item = &'a ExtensionElement> {
+    elements.iter().filter(move |element| element.local_name() == local_name)
+}
+
+
+// Read the number of an attribute, leaving it out when it cannot be read.
+fn attribute_i64(element: &ExtensionElement, attribute: &str) -> Option<i64> {
+    util::option_str_to_option_i64(element.attribute(attribute).as_deref())
+}
+
+
+// Read the `media:title` or `media:description` element with the local name.
+fn read_text(elements: &[ExtensionElement], local_name: &str) -> Option<MediaText> {
+    elements_named(elements, local_name).next().map(|element| {
+        MediaText {
+            text: element.text().unwrap_or_default(),
+            text_type: element.attribute("type"),
+        }
+    })
+}
+
+
+// Read the `media:thumbnail` elements.
+fn read_thumbnails(elements: &[ExtensionElement]) -> Vec<MediaThumbnail> {
+    elements_named(elements, "thumbnail")
+        .map(|element| {
+            MediaThumbnail {
+                url: element.attribute("url").unwrap_or_default(),
+                width: attribute_i64(element, "width"),
+                height: attribute_i64(element, "height"),
+                time: element.attribute("time"),
+            }
+        })
+        .collect()
+}
+
+
+// Read the `media:credit` elements.
+fn read_credits(elements: &[ExtensionElement]) -> Vec<MediaCredit> {
+    elements_named(elements, "credit")
+        .map(|element| {
+            MediaCredit {
+                name: element.text().unwrap_or_default(),
+                role: element.attribute("role"),
+                scheme: element.attribute("scheme"),
+            }
+        })
+        .collect()
+}
+
+
+// Add the elements describing media, which are found under a channel or an
+// item, a group and a content.
+fn push_details(elements: &mut Vec<ExtensionElement>,
+                title: &Option<MediaText>,
+                description: &Option<MediaText>,
+                thumbnails: &[MediaThumbnail],
+                credits: &[MediaCredit]) {
+    for (local_name, text) in [("title", title), ("description", description)] {
+        if let Some(text) = text {
+            elements.push(builder(local_name)
+                              .attributes(attributes(&[("type", text.text_type.clone())]))
+                              .text(Some(text.text.clone()))
+                              .finalize());
+        }
+    }
+    for thumbnail in thumbnails {
+        elements.push(builder("thumbnail")
+                          .attributes(attributes(&[("url", Some(thumbnail.url.clone())),
+                                                   ("width", thumbnail.width.map(|width| width.to_string())),
+                                                   ("height", thumbnail.height.map(|height| height.to_string())),
+                                                   ("time", thumbnail.time.clone())]))
+                          .finalize());
+    }
+    for credit in credits {
+        elements.push(builder("credit")
+                          .attributes(attributes(&[("role", credit.role.clone()),
+                                                   ("scheme", credit.scheme.clone())]))
+                          .text(Some(credit.name.clone()))
+                          .finalize());
+    }
+}
+
+
+// Read a `media:content` element and the elements describing it.
+fn read_content(element: &ExtensionElement) -> MediaContent {
+    let children = element.children();
+    MediaContent {
+        url: element.attribute("url").unwrap_or_default(),
+        content_type: element.attribute("type"),
+        medium: element.attribute("medium"),
+        file_size: attribute_i64(element, "fileSize"),
+        width: attribute_i64(element, "width"),
+        height: attribute_i64(element, "height"),
+        bitrate: element.attribute("bitrate"),
+        duration: attribute_i64(element, "duration"),
+        lang: element.attribute("lang"),
+        default_rendition: element.attribute("isDefault").is_some_and(|default| default.trim() == "true"),
+        title: read_text(&children, "title"),
+        description: read_text(&children, "description"),
+        thumbnails: read_thumbnails(&children),
+        credits: read_credits(&children),
+    }
+}
+
+
+// Construct the `media:content` element of the rendition.
+fn content_element(content: &MediaContent) -> ExtensionElement {
+    let mut children = Vec::new();
+    push_details(&mut children,
+                 &content.title,
+                 &content.description,
+                 &content.thumbnails,
+                 &content.credits);
+    builder("content")
+        .attributes(attributes(&[("url", Some(content.url.clone())),
+                                 ("type", content.content_type.clone()),
+                                 ("medium", content.medium.clone()),
+                                 ("fileSize", content.file_size.map(|size| size.to_string())),
+                                 ("width", content.width.map(|width| width.to_string())),
+                                 ("height", content.height.map(|height| height.to_string())),
+                                 ("bitrate", content.bitrate.clone()),
+                                 ("duration", content.duration.map(|duration| duration.to_string())),
+                                 ("lang", content.lang.clone()),
+                                 ("isDefault", if content.default_rendition { Some("true".to_owned()) } else { None })]))
+        .children(children)
+        .finalize()
+}
+
+
+// Read a `media:group` element, its renditions and the elements describing
+// them.
+fn read_group(element: &ExtensionElement) -> MediaGroup {
+    let children = element.children();
+    MediaGroup {
+        contents: elements_named(&children, "content").map(read_content).collect(),
+        title: read_text(&children, "title"),
+        description: read_text(&children, "description"),
+        thumbnails: read_thumbnails(&children),
+        credits: read_credits(&children),
+    }
+}
+
+
+// Construct the `media:group` element of the renditions.
+fn group_element(group: &MediaGroup) -> ExtensionElement {
+    let mut children = group.contents.iter().map(content_element).collect();
+    push_details(&mut children,
+                 &group.title,
+                 &group.description,
+                 &group.thumbnails,
+                 &group.credits);
+    builder("group").children(children).finalize()
+}
@@ -0,0 +1,133 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! All the structs for the elements of other namespaces that extend rss.
+//!
+//! The elements of a namespace other than the ones of rss found under a
+//! channel or an item are kept as `ExtensionElement` trees in an
+//! `ExtensionMap`, keyed by the uri of their namespace. A type implementing
+//! `Extension` reads the elements of its namespace to typed data and writes
+//! them back, other namespaces stay as they were read.
+//!
+//! There is no registry of handlers the reader calls while parsing. The reader
+//! keeps every namespace as elements, and a typed view such as
+//! `DublinCoreExtension` or a caller's own `Extension` is read from the
+//! `ExtensionMap` when asked for, so no handler has to be known before a feed
+//! is parsed and an element is never lost to a handler that could not read it.
+
+pub mod dublin_core;
+pub mod extension_element;
+pub mod extension_element_builder;
+pub mod itunes;
+pub mod media;
+pub mod podcast;
+
+use std::collections::BTreeMap;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The namespace of atom, whose elements are found in rss feeds.
+pub const ATOM_NAMESPACE: &str = "http://www.w3.org/2005/Atom";
+
+/// The namespace of the content module of RSS 1.0.
+pub const CONTENT_NAMESPACE: &str = "http://purl.org/rss/1.0/modules/content/";
+
+/// The namespace of the Dublin Core elements.
+pub const DUBLIN_CORE_NAMESPACE: &str = "http://purl.org/dc/elements/1.1/";
+
+/// The namespace of the iTunes podcast elements.
+pub const ITUNES_NAMESPACE: &str = "http://www.itunes.com/dtds/podcast-1.0.dtd";
+
+/// The namespace of Media RSS.
+pub const MEDIA_NAMESPACE: &str = "http://search.yahoo.com/mrss/";
+
+/// The namespace of Podcasting 2.0.
+pub const PODCAST_NAMESPACE: &str = "https://podcastindex.org/namespace/1.0";
+
+/// The extension elements under a channel or an item, keyed by the uri of
+/// their namespace and in the order they were read.
+pub type ExtensionMap = BTreeMap<String, Vec<ExtensionElement>>;
+
+/// This `Extension` trait reads the elements of a namespace to typed data and
+/// converts the data back to elements.
+///
+/// # Examples
+///
+/// ```
+/// use feed::extension::{Extension, ExtensionElement, ExtensionElementBuilder};
+/// use feed::feedio::{FeedReader, FeedWriter};
+/// use feed::rss::ChannelBuilder;
+///
+/// struct Weather {
+///     forecast: String,
+/// }
+///
+/// impl Extension for Weather {
+///     const NAMESPACE: &'static str = "http://example.com/weather";
+///     const PREFIX: &'static str = "weather";
+///
+///     fn from_elements(elements: &[ExtensionElement]) -> Option<Weather> {
+///         let forecast = elements.iter().find(|e| e.local_name() == "forecast")?;
+///         Some(Weather { forecast: forecast.text().unwrap_or_default() })
+///     }
+///
+///     fn to_elements(&self) -> Vec<ExtensionElement> {
+///         vec![ExtensionElementBuilder::new()
+///                  .name("weather:forecast")
+///                  .namespace(Weather::NAMESPACE)
+///                  .text(Some(self.forecast.clone()))
+///                  .finalize()]
+///     }
+/// }
+///
+/// let channel = ChannelBuilder::new()
+///     .title("Title")
+///     .extension(&Weather { forecast: "Sunny".to_owned() })
+///     .finalize();
+///
+/// let xml = String::from_utf8(FeedWriter::new(channel).xml()).unwrap();
+/// assert!(xml.contains("xmlns:weather=\"http://example.com/weather\""));
+/// assert!(xml.contains("<weather:forecast>Sunny</weather:forecast>"));
+///
+/// let channel = FeedReader::parse(&xml).unwrap();
+/// assert_eq!("Sunny", channel.extension::<Weather>().unwrap().forecast);
+/// ```
+pub trait Extension: Sized {
+    /// The uri of the namespace of the elements.
+    const NAMESPACE: &'static str;
+
+    /// The prefix the elements are usually written with.
+    const PREFIX: &'static str;
+
+    /// Read the elements of the namespace under a channel or an item, `None`
+    /// when they do not make up the extension.
+    fn from_elements(elements: &[ExtensionElement]) -> Option<Self>;
+
+    /// Convert the extension to the elements of its namespace.
+    fn to_elements(&self) -> Vec<ExtensionElement>;
+}
+
+
+/// This `ExtensionElement` struct contains an element of a namespace that
+/// extends rss, with its attributes, text and child elements.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct ExtensionElement {
+    name: String,
+    namespace: String,
+    attributes: Vec<(String, String)>,
+    text: Option<String>,
+    children: Vec<ExtensionElement>,
+}
+
+
+/// This `ExtensionElementBuilder` struct creates the `ExtensionElement`.
+#[derive(Default)]
+pub struct ExtensionElementBuilder {
+    name: String,
+    namespace: String,
+    attributes: Vec<(String, String)>,
+    text: Option<String>,
+    children: Vec<ExtensionElement>,
+}
@@ -0,0 +1,486 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! All the structs for the Podcasting 2.0 elements of a channel and an item.
+
+pub mod podcast_alternate_enclosure;
+pub mod podcast_alternate_enclosure_builder;
+pub mod podcast_channel_extension;
+pub mod podcast_channel_extension_builder;
+pub mod podcast_chapters;
+pub mod podcast_chapters_builder;
+pub mod podcast_funding;
+pub mod podcast_funding_builder;
+pub mod podcast_item_extension;
+pub mod podcast_item_extension_builder;
+pub mod podcast_person;
+pub mod podcast_person_builder;
+pub mod podcast_transcript;
+pub mod podcast_transcript_builder;
+pub mod podcast_value;
+pub mod podcast_value_builder;
+pub mod podcast_value_recipient;
+pub mod podcast_value_recipient_builder;
+
+use extension::{ExtensionElement, ExtensionElementBuilder, PODCAST_NAMESPACE};
+use rss::{Enclosure, EnclosureBuilder};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use util;
+
+/// This `PodcastAlternateEnclosure` struct contains another rendition of the
+/// media of an item, with the sources it can be downloaded from.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct PodcastAlternateEnclosure {
+    enclosure_type: String,
+    length: Option<i64>,
+    bitrate: Option<String>,
+    height: Option<i64>,
+    lang: Option<String>,
+    title: Option<String>,
+    rel: Option<String>,
+    codecs: Option<String>,
+    default_rendition: bool,
+    sources: Vec<Enclosure>,
+}
+
+
+/// This `PodcastAlternateEnclosureBuilder` struct creates the
+/// `PodcastAlternateEnclosure`.
+#[derive(Default)]
+pub struct PodcastAlternateEnclosureBuilder {
+    enclosure_type: String,
+    length: Option<i64>,
+    bitrate: Option<String>,
+    height: Option<i64>,
+    lang: Option<String>,
+    title: Option<String>,
+    rel: Option<String>,
+    codecs: Option<String>,
+    default_rendition: bool,
+    sources: Vec<Enclosure>,
+}
+
+
+/// This `PodcastChannelExtension` struct contains the Podcasting 2.0 elements
+/// of a `Channel`.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct PodcastChannelExtension {
+    guid: Option<String>,
+    locked: Option<bool>,
+    locked_owner: Option<String>,
+    funding: Vec<PodcastFunding>,
+    persons: Vec<PodcastPerson>,
+    value: Option<PodcastValue>,
+}
+
+
+/// This `PodcastChannelExtensionBuilder` struct creates the
+/// `PodcastChannelExtension`.
+#[derive(Default)]
+pub struct PodcastChannelExtensionBuilder {
+    guid: Option<String>,
+    locked: Option<bool>,
+    locked_owner: Option<String>,
+    funding: Vec<PodcastFunding>,
+    persons: Vec<PodcastPerson>,
+    value: Option<PodcastValue>,
+}
+
+
+/// This `PodcastChapters` struct contains the link to the chapters of an
+/// episode.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct PodcastChapters {
+    url: String,
+    chapters_type: String,
+}
+
+
+/// This `PodcastChaptersBuilder` struct creates the `PodcastChapters`.
+#[derive(Default)]
+pub struct PodcastChaptersBuilder {
+    url: String,
+    chapters_type: String,
+}
+
+
+/// This `PodcastFunding` struct contains a link to donate to a podcast.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct PodcastFunding {
+    url: String,
+    text: Option<String>,
+}
+
+
+/// This `PodcastFundingBuilder` struct creates the `PodcastFunding`.
+#[derive(Default)]
+pub struct PodcastFundingBuilder {
+    url: String,
+    text: Option<String>,
+}
+
+
+/// This `PodcastItemExtension` struct contains the Podcasting 2.0 elements of
+/// an `Item`.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct PodcastItemExtension {
+    transcripts: Vec<PodcastTranscript>,
+    chapters: Option<PodcastChapters>,
+    persons: Vec<PodcastPerson>,
+    value: Option<PodcastValue>,
+    alternate_enclosures: Vec<PodcastAlternateEnclosure>,
+}
+
+
+/// This `PodcastItemExtensionBuilder` struct creates the
+/// `PodcastItemExtension`.
+#[derive(Default)]
+pub struct PodcastItemExtensionBuilder {
+    transcripts: Vec<PodcastTranscript>,
+    chapters: Option<PodcastChapters>,
+    persons: Vec<PodcastPerson>,
+    value: Option<PodcastValue>,
+    alternate_enclosures: Vec<PodcastAlternateEnclosure>,
+}
+
+
+/// This `PodcastPerson` struct contains a person of a podcast or an episode.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct PodcastPerson {
+    name: String,
+    role: Option<String>,
+    group: Option<String>,
+    img: Option<String>,
+    href: Option<String>,
+}
+
+
+/// This `PodcastPersonBuilder` struct creates the `PodcastPerson`.
+#[derive(Default)]
+pub struct PodcastPersonBuilder {
+    name: String,
+    role: Option<String>,
+    group: Option<String>,
+    img: Option<String>,
+    href: Option<String>,
+}
+
+
+/// This `PodcastTranscript` struct contains the link to a transcript of an
+/// episode.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct PodcastTranscript {
+    url: String,
+    transcript_type: String,
+    language: Option<String>,
+    rel: Option<String>,
+}
+
+
+/// This `PodcastTranscriptBuilder` struct creates the `PodcastTranscript`.
+#[derive(Default)]
+pub struct PodcastTranscriptBuilder {
+    url: String,
+    transcript_type: String,
+    language: Option<String>,
+    rel: Option<String>,
+}
+
+
+/// This `PodcastValue` struct contains how listeners pay a podcast or an
+/// episode, and who gets the payments.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct PodcastValue {
+    value_type: String,
+    method: String,
+    suggested: Option<String>,
+    recipients: Vec<PodcastValueRecipient>,
+}
+
+
+/// This `PodcastValueBuilder` struct creates the `PodcastValue`.
+#[derive(Default)]
+pub struct PodcastValueBuilder {
+    value_type: String,
+    method: String,
+    suggested: Option<String>,
+    recipients: Vec<PodcastValueRecipient>,
+}
+
+
+/// This `PodcastValueRecipient` struct contains a recipient of the payments
+/// to a podcast or an episode.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct PodcastValueRecipient {
+    name: Option<String>,
+    custom_key: Option<String>,
+    custom_value: Option<String>,
+    recipient_type: String,
+    address: String,
+    split: i64,
+    fee: bool,
+}
+
+
+/// This `PodcastValueRecipientBuilder` struct creates the
+/// `PodcastValueRecipient`.
+#[derive(Default)]
+pub struct PodcastValueRecipientBuilder {
+    name: Option<String>,
+    custom_key: Option<String>,
+    custom_value: Option<String>,
+    recipient_type: String,
+    address: String,
+    split: i64,
+    fee: bool,
+}
+
+
+// Construct the builder of a Podcasting 2.0 element with the local name.
+fn builder(local_name: &str) -> ExtensionElementBuilder {
+    util::extension_builder(&format!("podcast:{}", local_name), PODCAST_NAMESPACE)
+}
+
+
+// Construct the attributes of an element from the ones that are set, in the
+// order they are given.
+fn attributes(attributes: &[(&str, Option<&str>)]) -> Vec<(String, String)> {
+    attributes.iter()
+              .filter_map(|&(key, value)| value.map(|value| (key.to_owned(), value.to_owned())))
+              .collect()
+}
+
+
+// Get the elements with the local name.
+fn elements_named<'a>(elements: &'a [ExtensionElement],
+                      local_name: &'a str)
+                      -> impl Iterator<Item = &'a ExtensionElement> {
+    elements.iter().filter(move |element| element.local_name() == local_name)
+}
+
+
+// Read a Podcasting 2.0 boolean, which is `yes` or `no` and in some feeds
+// `true` or `false`.
+fn parse_yes_no(text: &str) -> Option<bool> {
+    match text.trim().to_lowercase().as_str() {
+        "yes" | "true" => Some(true),
+        "no" | "false" => Some(false),
+        _ => None,
+    }
+}
+
+
+// Read a `podcast:alternateEnclosure` element, each `podcast:source` becoming
+// an `Enclosure` of the type of the source or else of the rendition.
+fn read_alternate_enclosure(element: &ExtensionElement) -> PodcastAlternateEnclosure {
+    let enclosure_type = element.attribute("type").unwrap_or_default();
+    let length_raw = element.attribute("length");
+    let length = util::option_str_to_option_i64(length_raw.as_deref());
+    let sources = elements_named(&element.children(), "source")
+                      .map(|source| {
+                          let mut builder = EnclosureBuilder::new();
+                          builder.url(&source.attribute("uri").unwrap_or_default())
+                                 .length(length.unwrap_or_default())
+                                 .length_raw(length_raw.clone())
+                                 .enclosure_type(&source.attribute("contentType")
+                                                        .unwrap_or_else(|| enclosure_type.clone()));
+                          builder.finalize()
+                      })
+                      .collect();
+    PodcastAlternateEnclosure {
+        enclosure_type,
+        length,
+        bitrate: element.attribute("bitrate"),
+        height: util::option_str_to_option_i64(element.attribute("height").as_deref()),
+        lang: element.attribute("lang"),
+        title: element.attribute("title"),
+        rel: element.attribute("rel"),
+        codecs: element.attribute("codecs"),
+        default_rendition: element.attribute("default").and_then(|default| parse_yes_no(&default)) ==
+                           Some(true),
+        sources,
+    }
+}
+
+
+// Construct the `podcast:alternateEnclosure` element of the rendition, the
+// type of a source being written when it is not the one of the rendition.
+fn alternate_enclosure_element(alternate: &PodcastAlternateEnclosure) -> ExtensionElement {
+    let length = alternate.length.map(|length| length.to_string());
+    let height = alternate.height.map(|height| height.to_string());
+    let sources = alternate.sources
+                           .iter()
+                           .map(|source| {
+                               let url = source.url();
+                               let content_type = source.enclosure_type();
+                               let content_type = if content_type.is_empty() ||
+                                                     content_type == alternate.enclosure_type {
+                                   None
+                               } else {
+                                   Some(content_type.as_str())
+                               };
+                               builder("source")
+                                   .attributes(attributes(&[("uri", Some(&url)),
+                                                            ("contentType", content_type)]))
+                                   .finalize()
+                           })
+                           .collect();
+    builder("alternateEnclosure")
+        .attributes(attributes(&[("type", Some(&alternate.enclosure_type)),
+                                 ("length", length.as_deref()),
+                                 ("bitrate", alternate.bitrate.as_deref()),
+                                 ("height", height.as_deref()),
+                                 ("lang", alternate.lang.as_deref()),
+                                 ("title", alternate.title.as_deref()),
+                                 ("rel", alternate.rel.as_deref()),
+                                 ("codecs", alternate.codecs.as_deref()),
+                                 ("default", if alternate.default_rendition { Some("true") } else { None })]))
+        .children(sources)
+        .finalize()
+}
+
+
+// Read a `podcast:chapters` element.
+fn read_chapters(element: &ExtensionElement) -> PodcastChapters {
+    PodcastChapters {
+        url: element.attribute("url").unwrap_or_default(),
+        chapters_type: element.attribute("type").unwrap_or_default(),
+    }
+}
+
+
+// Construct the `podcast:chapters` element of the chapters.
+fn chapters_element(chapters: &PodcastChapters) -> ExtensionElement {
+    builder("chapters")
+        .attributes(attributes(&[("url", Some(&chapters.url)), ("type", Some(&chapters.chapters_type))]))
+        .finalize()
+}
+
+
+// Read a `podcast:funding` element.
+fn read_funding(element: &ExtensionElement) -> PodcastFunding {
+    PodcastFunding {
+        url: element.attribute("url").unwrap_or_default(),
+        text: element.text(),
+    }
+}
+
+
+// Construct the `podcast:funding` element of the link.
+fn funding_element(funding: &PodcastFunding) -> ExtensionElement {
+    builder("funding")
+        .attributes(attributes(&[("url", Some(&funding.url))]))
+        .text(funding.text.clone())
+        .finalize()
+}
+
+
+// Read a `podcast:person` element.
+fn read_person(element: &ExtensionElement) -> PodcastPerson {
+    PodcastPerson {
+        name: element.text().unwrap_or_default(),
+        role: element.attribute("role"),
+        group: element.attribute("group"),
+        img: element.attribute("img"),
+        href: element.attribute("href"),
+    }
+}
+
+
+// Construct the `podcast:person` element of the person.
+fn person_element(person: &PodcastPerson) -> ExtensionElement {
+    builder("person")
+        .attributes(attributes(&[("role", person.role.as_deref()),
+                                 ("group", person.group.as_deref()),
+                                 ("img", person.img.as_deref()),
+                                 ("href", person.href.as_deref())]))
+        .text(Some(person.name.clone()))
+        .finalize()
+}
+
+
+// Read a `podcast:transcript` element.
+fn read_transcript(element: &ExtensionElement) -> PodcastTranscript {
+    PodcastTranscript {
+        url: element.attribute("url").unwrap_or_default(),
+        transcript_type: element.attribute("type").unwrap_or_default(),
+        language: element.attribute("language"),
+        rel: element.attribute("rel"),
+    }
+}
+
+
+// Construct the `podcast:transcript` element of the transcript.
+fn transcript_element(transcript: &PodcastTranscript) -> ExtensionElement {
+    builder("transcript")
+        .attributes(attributes(&[("url", Some(&transcript.url)),
+                                 ("type", Some(&transcript.transcript_type)),
+                                 ("language", transcript.language.as_deref()),
+                                 ("rel", transcript.rel.as_deref())]))
+        .finalize()
+}
+
+
+// Read a `podcast:value` element and its `podcast:valueRecipient` elements.
+fn read_value(element: &ExtensionElement) -> PodcastValue {
+    PodcastValue {
+        value_type: element.attribute("type").unwrap_or_default(),
+        method: element.attribute("method").unwrap_or_default(),
+        suggested: element.attribute("suggested"),
+        recipients: elements_named(&element.children(), "valueRecipient")
+                        .map(read_value_recipient)
+                        .collect(),
+    }
+}
+
+
+// Construct the `podcast:value` element of the payments.
+fn value_element(value: &PodcastValue) -> ExtensionElement {
+    builder("value")
+        .attributes(attributes(&[("type", Some(&value.value_type)),
+                                 ("method", Some(&value.method)),
+                                 ("suggested", value.suggested.as_deref())]))
+        .children(value.recipients.iter().map(value_recipient_element).collect())
+        .finalize()
+}
+
+
+// Read a `podcast:valueRecipient` element, a split that cannot be read being
+// no share.
+fn read_value_recipient(element: &ExtensionElement) -> PodcastValueRecipient {
+    PodcastValueRecipient {
+        name: element.attribute("name"),
+        custom_key: element.attribute("customKey"),
+        custom_value: element.attribute("customValue"),
+        recipient_type: element.attribute("type").unwrap_or_default(),
+        address: element.attribute("address").unwrap_or_default(),
+        split: util::option_str_to_option_i64(element.attribute("split").as_deref()).unwrap_or_default(),
+        fee: element.attribute("fee").and_then(|fee| parse_yes_no(&fee)) == Some(true),
+    }
+}
+
+
+// Construct the `podcast:valueRecipient` element of the recipient.
+fn value_recipient_element(recipient: &PodcastValueRecipient) -> ExtensionElement {
+    let split = recipient.split.to_string();
+    builder("valueRecipient")
+        .attributes(attributes(&[("name", recipient.name.as_deref()),
+                                 ("customKey", recipient.custom_key.as_deref()),
+                                 ("customValue", recipient.custom_value.as_deref()),
+                                 ("type", Some(&recipient.recipient_type)),
+                                 ("address", Some(&recipient.address)),
+                                 ("split", Some(&split)),
+                                 ("fee", if recipient.fee { Some("true") } else { None })]))
+        .finalize()
+}
@@ -0,0 +1,258 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under podcast alternate enclosure can be retrieved by using the
+//! methods under `PodcastAlternateEnclosure`.
+
+use extension::podcast::PodcastAlternateEnclosure;
+use rss::Enclosure;
+
+impl PodcastAlternateEnclosure {
+    /// Get the mime type of the media that exists under
+    /// `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let alternate = PodcastAlternateEnclosureBuilder::new()
+    ///     .enclosure_type("audio/opus")
+    ///     .finalize();
+    /// assert_eq!("audio/opus".to_owned(), alternate.enclosure_type());
+    /// ```
+    pub fn enclosure_type(&self) -> String {
+        self.enclosure_type.clone()
+    }
+
+
+    /// Get the optional length of the media in bytes that exists under
+    /// `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let alternate = PodcastAlternateEnclosureBuilder::new()
+    ///     .length(Some(32400000))
+    ///     .finalize();
+    /// assert_eq!(Some(32400000), alternate.length());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let alternate = PodcastAlternateEnclosureBuilder::new()
+    ///     .length(None)
+    ///     .finalize();
+    /// assert!(alternate.length().is_none());
+    /// ```
+    pub fn length(&self) -> Option<i64> {
+        self.length
+    }
+
+
+    /// Get the optional bitrate of the media in bits per second that exists
+    /// under `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let alternate = PodcastAlternateEnclosureBuilder::new()
+    ///     .bitrate(Some("96000".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("96000".to_owned()), alternate.bitrate());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let alternate = PodcastAlternateEnclosureBuilder::new()
+    ///     .bitrate(None)
+    ///     .finalize();
+    /// assert!(alternate.bitrate().is_none());
+    /// ```
+    pub fn bitrate(&self) -> Option<String> {
+        self.bitrate.clone()
+    }
+
+
+    /// Get the optional height of the video in pixels that exists under
+    /// `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let alternate = PodcastAlternateEnclosureBuilder::new()
+    ///     .height(Some(1080))
+    ///     .finalize();
+    /// assert_eq!(Some(1080), alternate.height());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let alternate = PodcastAlternateEnclosureBuilder::new()
+    ///     .height(None)
+    ///     .finalize();
+    /// assert!(alternate.height().is_none());
+    /// ```
+    pub fn height(&self) -> Option<i64> {
+        self.height
+    }
+
+
+    /// Get the optional language of the media that exists under
+    /// `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let alternate = PodcastAlternateEnclosureBuilder::new()
+    ///     .lang(Some("en".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("en".to_owned()), alternate.lang());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let alternate = PodcastAlternateEnclosureBuilder::new()
+    ///     .lang(None)
+    ///     .finalize();
+    /// assert!(alternate.lang().is_none());
+    /// ```
+    pub fn lang(&self) -> Option<String> {
+        self.lang.clone()
+    }
+
+
+    /// Get the optional title of the rendition that exists under
+    /// `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let alternate = PodcastAlternateEnclosureBuilder::new()
+    ///     .title(Some("Standard".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("Standard".to_owned()), alternate.title());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let alternate = PodcastAlternateEnclosureBuilder::new()
+    ///     .title(None)
+    ///     .finalize();
+    /// assert!(alternate.title().is_none());
+    /// ```
+    pub fn title(&self) -> Option<String> {
+        self.title.clone()
+    }
+
+
+    /// Get the optional name of the group of the renditions that exists under
+    /// `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let alternate = PodcastAlternateEnclosureBuilder::new()
+    ///     .rel(Some("Opus Audio".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("Opus Audio".to_owned()), alternate.rel());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let alternate = PodcastAlternateEnclosureBuilder::new()
+    ///     .rel(None)
+    ///     .finalize();
+    /// assert!(alternate.rel().is_none());
+    /// ```
+    pub fn rel(&self) -> Option<String> {
+        self.rel.clone()
+    }
+
+
+    /// Get the optional codecs of the media that exists under
+    /// `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let alternate = PodcastAlternateEnclosureBuilder::new()
+    ///     .codecs(Some("opus".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("opus".to_owned()), alternate.codecs());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let alternate = PodcastAlternateEnclosureBuilder::new()
+    ///     .codecs(None)
+    ///     .finalize();
+    /// assert!(alternate.codecs().is_none());
+    /// ```
+    pub fn codecs(&self) -> Option<String> {
+        self.codecs.clone()
+    }
+
+
+    /// Get whether the rendition is the same as the enclosure of the item under
+    /// `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let alternate = PodcastAlternateEnclosureBuilder::new()
+    ///     .default_rendition(true)
+    ///     .finalize();
+    /// assert!(alternate.default_rendition());
+    /// ```
+    pub fn default_rendition(&self) -> bool {
+        self.default_rendition
+    }
+
+
+    /// Get the sources the media can be downloaded from that exist under
+    /// `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    /// use feed::rss::EnclosureBuilder;
+    ///
+    /// let source = EnclosureBuilder::new()
+    ///     .url("http://example.com/episode.opus")
+    ///     .enclosure_type("audio/opus")
+    ///     .finalize();
+    /// let alternate = PodcastAlternateEnclosureBuilder::new()
+    ///     .sources(vec![source.clone()])
+    ///     .finalize();
+    /// assert_eq!(vec![source], alternate.sources());
+    /// ```
+    pub fn sources(&self) -> Vec<Enclosure> {
+        self.sources.clone()
+    }
+}
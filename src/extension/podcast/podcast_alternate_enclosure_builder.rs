@@ -0,0 +1,238 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for podcast alternate enclosure by using the methods
+//! under `PodcastAlternateEnclosureBuilder`.
+
+use extension::podcast::{PodcastAlternateEnclosure, PodcastAlternateEnclosureBuilder};
+use rss::Enclosure;
+
+impl PodcastAlternateEnclosureBuilder {
+    /// Construct a new `PodcastAlternateEnclosureBuilder` and return default
+    /// values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let alternate_builder = PodcastAlternateEnclosureBuilder::new();
+    /// ```
+    pub fn new() -> PodcastAlternateEnclosureBuilder {
+        PodcastAlternateEnclosureBuilder::default()
+    }
+
+
+    /// Set the mime type of the media that exists under
+    /// `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let mut alternate_builder = PodcastAlternateEnclosureBuilder::new();
+    /// alternate_builder.enclosure_type("audio/opus");
+    /// ```
+    pub fn enclosure_type(&mut self, enclosure_type: &str) -> &mut PodcastAlternateEnclosureBuilder {
+        self.enclosure_type = enclosure_type.to_owned();
+        self
+    }
+
+
+    /// Set the optional length of the media in bytes that exists under
+    /// `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let mut alternate_builder = PodcastAlternateEnclosureBuilder::new();
+    /// alternate_builder.length(Some(32400000));
+    /// ```
+    pub fn length(&mut self, length: Option<i64>) -> &mut PodcastAlternateEnclosureBuilder {
+        self.length = length;
+        self
+    }
+
+
+    /// Set the optional bitrate of the media in bits per second that exists
+    /// under `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let mut alternate_builder = PodcastAlternateEnclosureBuilder::new();
+    /// alternate_builder.bitrate(Some("96000".to_owned()));
+    /// ```
+    pub fn bitrate(&mut self, bitrate: Option<String>) -> &mut PodcastAlternateEnclosureBuilder {
+        self.bitrate = bitrate;
+        self
+    }
+
+
+    /// Set the optional height of the video in pixels that exists under
+    /// `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let mut alternate_builder = PodcastAlternateEnclosureBuilder::new();
+    /// alternate_builder.height(Some(1080));
+    /// ```
+    pub fn height(&mut self, height: Option<i64>) -> &mut PodcastAlternateEnclosureBuilder {
+        self.height = height;
+        self
+    }
+
+
+    /// Set the optional language of the media that exists under
+    /// `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let mut alternate_builder = PodcastAlternateEnclosureBuilder::new();
+    /// alternate_builder.lang(Some("en".to_owned()));
+    /// ```
+    pub fn lang(&mut self, lang: Option<String>) -> &mut PodcastAlternateEnclosureBuilder {
+        self.lang = lang;
+        self
+    }
+
+
+    /// Set the optional title of the rendition that exists under
+    /// `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let mut alternate_builder = PodcastAlternateEnclosureBuilder::new();
+    /// alternate_builder.title(Some("Standard".to_owned()));
+    /// ```
+    pub fn title(&mut self, title: Option<String>) -> &mut PodcastAlternateEnclosureBuilder {
+        self.title = title;
+        self
+    }
+
+
+    /// Set the optional name of the group of the renditions that exists under
+    /// `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let mut alternate_builder = PodcastAlternateEnclosureBuilder::new();
+    /// alternate_builder.rel(Some("Opus Audio".to_owned()));
+    /// ```
+    pub fn rel(&mut self, rel: Option<String>) -> &mut PodcastAlternateEnclosureBuilder {
+        self.rel = rel;
+        self
+    }
+
+
+    /// Set the optional codecs of the media that exists under
+    /// `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let mut alternate_builder = PodcastAlternateEnclosureBuilder::new();
+    /// alternate_builder.codecs(Some("opus".to_owned()));
+    /// ```
+    pub fn codecs(&mut self, codecs: Option<String>) -> &mut PodcastAlternateEnclosureBuilder {
+        self.codecs = codecs;
+        self
+    }
+
+
+    /// Set whether the rendition is the same as the enclosure of the item under
+    /// `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let mut alternate_builder = PodcastAlternateEnclosureBuilder::new();
+    /// alternate_builder.default_rendition(true);
+    /// ```
+    pub fn default_rendition(&mut self, default_rendition: bool) -> &mut PodcastAlternateEnclosureBuilder {
+        self.default_rendition = default_rendition;
+        self
+    }
+
+
+    /// Set the sources the media can be downloaded from that exist under
+    /// `PodcastAlternateEnclosure`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    /// use feed::rss::EnclosureBuilder;
+    ///
+    /// let source = EnclosureBuilder::new()
+    ///     .url("http://example.com/episode.opus")
+    ///     .enclosure_type("audio/opus")
+    ///     .finalize();
+    ///
+    /// let mut alternate_builder = PodcastAlternateEnclosureBuilder::new();
+    /// alternate_builder.sources(vec![source]);
+    /// ```
+    pub fn sources(&mut self, sources: Vec<Enclosure>) -> &mut PodcastAlternateEnclosureBuilder {
+        self.sources = sources;
+        self
+    }
+
+
+    /// Construct the `PodcastAlternateEnclosure` from the
+    /// `PodcastAlternateEnclosureBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastAlternateEnclosureBuilder;
+    ///
+    /// let alternate = PodcastAlternateEnclosureBuilder::new()
+    ///         .enclosure_type("audio/opus")
+    ///         .length(Some(32400000))
+    ///         .bitrate(Some("96000".to_owned()))
+    ///         .height(Some(1080))
+    ///         .lang(Some("en".to_owned()))
+    ///         .title(Some("Standard".to_owned()))
+    ///         .rel(Some("Opus Audio".to_owned()))
+    ///         .codecs(Some("opus".to_owned()))
+    ///         .default_rendition(false)
+    ///         .sources(Vec::new())
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> PodcastAlternateEnclosure {
+        PodcastAlternateEnclosure {
+            enclosure_type: self.enclosure_type.clone(),
+            length: self.length,
+            bitrate: self.bitrate.clone(),
+            height: self.height,
+            lang: self.lang.clone(),
+            title: self.title.clone(),
+            rel: self.rel.clone(),
+            codecs: self.codecs.clone(),
+            default_rendition: self.default_rendition,
+            sources: self.sources.clone(),
+        }
+    }
+}
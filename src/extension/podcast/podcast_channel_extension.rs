@@ -0,0 +1,203 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under podcast channel extension can be retrieved by using the
+//! methods under `PodcastChannelExtension`.
+
+use extension::{Extension, ExtensionElement, PODCAST_NAMESPACE};
+use extension::podcast::{self, PodcastChannelExtension, PodcastFunding, PodcastPerson, PodcastValue};
+use util;
+
+impl PodcastChannelExtension {
+    /// Get the optional globally unique identifier of the podcast that exists
+    /// under `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChannelExtensionBuilder;
+    ///
+    /// let podcast = PodcastChannelExtensionBuilder::new()
+    ///     .guid(Some("917393e3-1b1e-5cef-ace4-edaa54e1f810".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("917393e3-1b1e-5cef-ace4-edaa54e1f810".to_owned()), podcast.guid());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChannelExtensionBuilder;
+    ///
+    /// let podcast = PodcastChannelExtensionBuilder::new()
+    ///     .guid(None)
+    ///     .finalize();
+    /// assert!(podcast.guid().is_none());
+    /// ```
+    pub fn guid(&self) -> Option<String> {
+        self.guid.clone()
+    }
+
+
+    /// Get the optional lock against moving the podcast to another platform
+    /// that exists under `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChannelExtensionBuilder;
+    ///
+    /// let podcast = PodcastChannelExtensionBuilder::new()
+    ///     .locked(Some(true))
+    ///     .finalize();
+    /// assert_eq!(Some(true), podcast.locked());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChannelExtensionBuilder;
+    ///
+    /// let podcast = PodcastChannelExtensionBuilder::new()
+    ///     .locked(None)
+    ///     .finalize();
+    /// assert!(podcast.locked().is_none());
+    /// ```
+    pub fn locked(&self) -> Option<bool> {
+        self.locked
+    }
+
+
+    /// Get the optional email of the owner who can unlock the podcast that
+    /// exists under `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChannelExtensionBuilder;
+    ///
+    /// let podcast = PodcastChannelExtensionBuilder::new()
+    ///     .locked_owner(Some("jane@example.com".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("jane@example.com".to_owned()), podcast.locked_owner());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChannelExtensionBuilder;
+    ///
+    /// let podcast = PodcastChannelExtensionBuilder::new()
+    ///     .locked_owner(None)
+    ///     .finalize();
+    /// assert!(podcast.locked_owner().is_none());
+    /// ```
+    pub fn locked_owner(&self) -> Option<String> {
+        self.locked_owner.clone()
+    }
+
+
+    /// Get the links to fund the podcast that exist under
+    /// `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastFundingBuilder};
+    ///
+    /// let funding = PodcastFundingBuilder::new()
+    ///     .url("http://example.com/donate")
+    ///     .finalize();
+    /// let podcast = PodcastChannelExtensionBuilder::new()
+    ///     .funding(vec![funding.clone()])
+    ///     .finalize();
+    /// assert_eq!(vec![funding], podcast.funding());
+    /// ```
+    pub fn funding(&self) -> Vec<PodcastFunding> {
+        self.funding.clone()
+    }
+
+
+    /// Get the persons of the podcast that exist under
+    /// `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastPersonBuilder};
+    ///
+    /// let person = PodcastPersonBuilder::new()
+    ///     .name("Jane Doe")
+    ///     .finalize();
+    /// let podcast = PodcastChannelExtensionBuilder::new()
+    ///     .persons(vec![person.clone()])
+    ///     .finalize();
+    /// assert_eq!(vec![person], podcast.persons());
+    /// ```
+    pub fn persons(&self) -> Vec<PodcastPerson> {
+        self.persons.clone()
+    }
+
+
+    /// Get the optional payments to the podcast that exists under
+    /// `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastValueBuilder};
+    ///
+    /// let value = PodcastValueBuilder::new()
+    ///     .value_type("lightning")
+    ///     .method("keysend")
+    ///     .finalize();
+    /// let podcast = PodcastChannelExtensionBuilder::new()
+    ///     .value(Some(value.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(value), podcast.value());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChannelExtensionBuilder;
+    ///
+    /// let podcast = PodcastChannelExtensionBuilder::new()
+    ///     .value(None)
+    ///     .finalize();
+    /// assert!(podcast.value().is_none());
+    /// ```
+    pub fn value(&self) -> Option<PodcastValue> {
+        self.value.clone()
+    }
+}
+
+
+impl Extension for PodcastChannelExtension {
+    const NAMESPACE: &'static str = PODCAST_NAMESPACE;
+    const PREFIX: &'static str = "podcast";
+
+
+    // Read the Podcasting 2.0 elements of a channel, leaving out the ones of
+    // an episode.
+    fn from_elements(elements: &[ExtensionElement]) -> Option<PodcastChannelExtension> {
+        Some(PodcastChannelExtension {
+            guid: util::extension_text(elements, "guid"),
+            locked: util::extension_text(elements, "locked").and_then(|locked| podcast::parse_yes_no(&locked)),
+            locked_owner: util::extension_attribute(elements, "locked", "owner"),
+            funding: podcast::elements_named(elements, "funding").map(podcast::read_funding).collect(),
+            persons: podcast::elements_named(elements, "person").map(podcast::read_person).collect(),
+            value: podcast::elements_named(elements, "value").next().map(podcast::read_value),
+        })
+    }
+
+
+    fn to_elements(&self) -> Vec<ExtensionElement> {
+        let mut elements = Vec::new();
+        if let Some(ref guid) = self.guid {
+            elements.push(util::extension_text_element("podcast:guid", PODCAST_NAMESPACE, guid));
+        }
+        if let Some(locked) = self.locked {
+            elements.push(podcast::builder("locked")
+                              .attributes(podcast::attributes(&[("owner", self.locked_owner.as_deref())]))
+                              .text(Some(if locked { "yes" } else { "no" }.to_owned()))
+                              .finalize());
+        }
+        elements.extend(self.funding.iter().map(podcast::funding_element));
+        elements.extend(self.persons.iter().map(podcast::person_element));
+        elements.extend(self.value.iter().map(podcast::value_element));
+        elements
+    }
+}
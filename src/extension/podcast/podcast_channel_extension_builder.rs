@@ -0,0 +1,169 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for podcast channel extension by using the methods
+//! under `PodcastChannelExtensionBuilder`.
+
+use extension::podcast::{PodcastChannelExtension, PodcastChannelExtensionBuilder, PodcastFunding,
+                         PodcastPerson, PodcastValue};
+
+impl PodcastChannelExtensionBuilder {
+    /// Construct a new `PodcastChannelExtensionBuilder` and return default
+    /// values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChannelExtensionBuilder;
+    ///
+    /// let podcast_builder = PodcastChannelExtensionBuilder::new();
+    /// ```
+    pub fn new() -> PodcastChannelExtensionBuilder {
+        PodcastChannelExtensionBuilder::default()
+    }
+
+
+    /// Set the optional globally unique identifier of the podcast that exists
+    /// under `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChannelExtensionBuilder;
+    ///
+    /// let mut podcast_builder = PodcastChannelExtensionBuilder::new();
+    /// podcast_builder.guid(Some("917393e3-1b1e-5cef-ace4-edaa54e1f810".to_owned()));
+    /// ```
+    pub fn guid(&mut self, guid: Option<String>) -> &mut PodcastChannelExtensionBuilder {
+        self.guid = guid;
+        self
+    }
+
+
+    /// Set the optional lock against moving the podcast to another platform
+    /// that exists under `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChannelExtensionBuilder;
+    ///
+    /// let mut podcast_builder = PodcastChannelExtensionBuilder::new();
+    /// podcast_builder.locked(Some(true));
+    /// ```
+    pub fn locked(&mut self, locked: Option<bool>) -> &mut PodcastChannelExtensionBuilder {
+        self.locked = locked;
+        self
+    }
+
+
+    /// Set the optional email of the owner who can unlock the podcast that
+    /// exists under `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChannelExtensionBuilder;
+    ///
+    /// let mut podcast_builder = PodcastChannelExtensionBuilder::new();
+    /// podcast_builder.locked_owner(Some("jane@example.com".to_owned()));
+    /// ```
+    pub fn locked_owner(&mut self, locked_owner: Option<String>) -> &mut PodcastChannelExtensionBuilder {
+        self.locked_owner = locked_owner;
+        self
+    }
+
+
+    /// Set the links to fund the podcast that exist under
+    /// `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastFundingBuilder};
+    ///
+    /// let funding = PodcastFundingBuilder::new()
+    ///     .url("http://example.com/donate")
+    ///     .finalize();
+    ///
+    /// let mut podcast_builder = PodcastChannelExtensionBuilder::new();
+    /// podcast_builder.funding(vec![funding]);
+    /// ```
+    pub fn funding(&mut self, funding: Vec<PodcastFunding>) -> &mut PodcastChannelExtensionBuilder {
+        self.funding = funding;
+        self
+    }
+
+
+    /// Set the persons of the podcast that exist under
+    /// `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastPersonBuilder};
+    ///
+    /// let person = PodcastPersonBuilder::new()
+    ///     .name("Jane Doe")
+    ///     .finalize();
+    ///
+    /// let mut podcast_builder = PodcastChannelExtensionBuilder::new();
+    /// podcast_builder.persons(vec![person]);
+    /// ```
+    pub fn persons(&mut self, persons: Vec<PodcastPerson>) -> &mut PodcastChannelExtensionBuilder {
+        self.persons = persons;
+        self
+    }
+
+
+    /// Set the optional payments to the podcast that exists under
+    /// `PodcastChannelExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChannelExtensionBuilder, PodcastValueBuilder};
+    ///
+    /// let value = PodcastValueBuilder::new()
+    ///     .value_type("lightning")
+    ///     .method("keysend")
+    ///     .finalize();
+    ///
+    /// let mut podcast_builder = PodcastChannelExtensionBuilder::new();
+    /// podcast_builder.value(Some(value));
+    /// ```
+    pub fn value(&mut self, value: Option<PodcastValue>) -> &mut PodcastChannelExtensionBuilder {
+        self.value = value;
+        self
+    }
+
+
+    /// Construct the `PodcastChannelExtension` from the
+    /// `PodcastChannelExtensionBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChannelExtensionBuilder;
+    ///
+    /// let podcast = PodcastChannelExtensionBuilder::new()
+    ///         .guid(Some("917393e3-1b1e-5cef-ace4-edaa54e1f810".to_owned()))
+    ///         .locked(Some(true))
+    ///         .locked_owner(Some("jane@example.com".to_owned()))
+    ///         .funding(Vec::new())
+    ///         .persons(Vec::new())
+    ///         .value(None)
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> PodcastChannelExtension {
+        PodcastChannelExtension {
+            guid: self.guid.clone(),
+            locked: self.locked,
+            locked_owner: self.locked_owner.clone(),
+            funding: self.funding.clone(),
+            persons: self.persons.clone(),
+            value: self.value.clone(),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under podcast chapters can be retrieved by using the methods
+//! under `PodcastChapters`.
+
+use extension::podcast::PodcastChapters;
+
+impl PodcastChapters {
+    /// Get the url of the chapters that exists under `PodcastChapters`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChaptersBuilder;
+    ///
+    /// let chapters = PodcastChaptersBuilder::new()
+    ///     .url("http://example.com/chapters.json")
+    ///     .finalize();
+    /// assert_eq!("http://example.com/chapters.json".to_owned(), chapters.url());
+    /// ```
+    pub fn url(&self) -> String {
+        self.url.clone()
+    }
+
+
+    /// Get the mime type of the chapters that exists under `PodcastChapters`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChaptersBuilder;
+    ///
+    /// let chapters = PodcastChaptersBuilder::new()
+    ///     .chapters_type("application/json+chapters")
+    ///     .finalize();
+    /// assert_eq!("application/json+chapters".to_owned(), chapters.chapters_type());
+    /// ```
+    pub fn chapters_type(&self) -> String {
+        self.chapters_type.clone()
+    }
+}
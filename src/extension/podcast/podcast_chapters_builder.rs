@@ -0,0 +1,75 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for podcast chapters by using the methods under
+//! `PodcastChaptersBuilder`.
+
+use extension::podcast::{PodcastChapters, PodcastChaptersBuilder};
+
+impl PodcastChaptersBuilder {
+    /// Construct a new `PodcastChaptersBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChaptersBuilder;
+    ///
+    /// let chapters_builder = PodcastChaptersBuilder::new();
+    /// ```
+    pub fn new() -> PodcastChaptersBuilder {
+        PodcastChaptersBuilder::default()
+    }
+
+
+    /// Set the url of the chapters that exists under `PodcastChapters`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChaptersBuilder;
+    ///
+    /// let mut chapters_builder = PodcastChaptersBuilder::new();
+    /// chapters_builder.url("http://example.com/chapters.json");
+    /// ```
+    pub fn url(&mut self, url: &str) -> &mut PodcastChaptersBuilder {
+        self.url = url.to_owned();
+        self
+    }
+
+
+    /// Set the mime type of the chapters that exists under `PodcastChapters`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChaptersBuilder;
+    ///
+    /// let mut chapters_builder = PodcastChaptersBuilder::new();
+    /// chapters_builder.chapters_type("application/json+chapters");
+    /// ```
+    pub fn chapters_type(&mut self, chapters_type: &str) -> &mut PodcastChaptersBuilder {
+        self.chapters_type = chapters_type.to_owned();
+        self
+    }
+
+
+    /// Construct the `PodcastChapters` from the `PodcastChaptersBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChaptersBuilder;
+    ///
+    /// let chapters = PodcastChaptersBuilder::new()
+    ///         .url("http://example.com/chapters.json")
+    ///         .chapters_type("application/json+chapters")
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> PodcastChapters {
+        PodcastChapters {
+            url: self.url.clone(),
+            chapters_type: self.chapters_type.clone(),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under podcast funding can be retrieved by using the methods under
+//! `PodcastFunding`.
+
+use extension::podcast::PodcastFunding;
+
+impl PodcastFunding {
+    /// Get the url of the donation page that exists under `PodcastFunding`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastFundingBuilder;
+    ///
+    /// let funding = PodcastFundingBuilder::new()
+    ///     .url("http://example.com/donate")
+    ///     .finalize();
+    /// assert_eq!("http://example.com/donate".to_owned(), funding.url());
+    /// ```
+    pub fn url(&self) -> String {
+        self.url.clone()
+    }
+
+
+    /// Get the optional text of the link that exists under `PodcastFunding`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastFundingBuilder;
+    ///
+    /// let funding = PodcastFundingBuilder::new()
+    ///     .text(Some("Support the show!".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("Support the show!".to_owned()), funding.text());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastFundingBuilder;
+    ///
+    /// let funding = PodcastFundingBuilder::new()
+    ///     .text(None)
+    ///     .finalize();
+    /// assert!(funding.text().is_none());
+    /// ```
+    pub fn text(&self) -> Option<String> {
+        self.text.clone()
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for podcast funding by using the methods under
+//! `PodcastFundingBuilder`.
+
+use extension::podcast::{PodcastFunding, PodcastFundingBuilder};
+
+impl PodcastFundingBuilder {
+    /// Construct a new `PodcastFundingBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastFundingBuilder;
+    ///
+    /// let funding_builder = PodcastFundingBuilder::new();
+    /// ```
+    pub fn new() -> PodcastFundingBuilder {
+        PodcastFundingBuilder::default()
+    }
+
+
+    /// Set the url of the donation page that exists under `PodcastFunding`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastFundingBuilder;
+    ///
+    /// let mut funding_builder = PodcastFundingBuilder::new();
+    /// funding_builder.url("http://example.com/donate");
+    /// ```
+    pub fn url(&mut self, url: &str) -> &mut PodcastFundingBuilder {
+        self.url = url.to_owned();
+        self
+    }
+
+
+    /// Set the optional text of the link that exists under `PodcastFunding`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastFundingBuilder;
+    ///
+    /// let mut funding_builder = PodcastFundingBuilder::new();
+    /// funding_builder.text(Some("Support the show!".to_owned()));
+    /// ```
+    pub fn text(&mut self, text: Option<String>) -> &mut PodcastFundingBuilder {
+        self.text = text;
+        self
+    }
+
+
+    /// Construct the `PodcastFunding` from the `PodcastFundingBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastFundingBuilder;
+    ///
+    /// let funding = PodcastFundingBuilder::new()
+    ///         .url("http://example.com/donate")
+    ///         .text(Some("Support the show!".to_owned()))
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> PodcastFunding {
+        PodcastFunding {
+            url: self.url.clone(),
+            text: self.text.clone(),
+        }
+    }
+}
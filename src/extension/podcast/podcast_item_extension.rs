@@ -0,0 +1,166 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under podcast item extension can be retrieved by using the
+//! methods under `PodcastItemExtension`.
+
+use extension::{Extension, ExtensionElement, PODCAST_NAMESPACE};
+use extension::podcast::{self, PodcastAlternateEnclosure, PodcastChapters, PodcastItemExtension,
+                         PodcastPerson, PodcastTranscript, PodcastValue};
+
+impl PodcastItemExtension {
+    /// Get the transcripts of the episode that exist under
+    /// `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastTranscriptBuilder};
+    ///
+    /// let transcript = PodcastTranscriptBuilder::new()
+    ///     .url("http://example.com/episode.vtt")
+    ///     .transcript_type("text/vtt")
+    ///     .finalize();
+    /// let podcast = PodcastItemExtensionBuilder::new()
+    ///     .transcripts(vec![transcript.clone()])
+    ///     .finalize();
+    /// assert_eq!(vec![transcript], podcast.transcripts());
+    /// ```
+    pub fn transcripts(&self) -> Vec<PodcastTranscript> {
+        self.transcripts.clone()
+    }
+
+
+    /// Get the optional chapters of the episode that exists under
+    /// `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChaptersBuilder, PodcastItemExtensionBuilder};
+    ///
+    /// let chapters = PodcastChaptersBuilder::new()
+    ///     .url("http://example.com/chapters.json")
+    ///     .chapters_type("application/json+chapters")
+    ///     .finalize();
+    /// let podcast = PodcastItemExtensionBuilder::new()
+    ///     .chapters(Some(chapters.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(chapters), podcast.chapters());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastItemExtensionBuilder;
+    ///
+    /// let podcast = PodcastItemExtensionBuilder::new()
+    ///     .chapters(None)
+    ///     .finalize();
+    /// assert!(podcast.chapters().is_none());
+    /// ```
+    pub fn chapters(&self) -> Option<PodcastChapters> {
+        self.chapters.clone()
+    }
+
+
+    /// Get the persons of the episode that exist under `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastPersonBuilder};
+    ///
+    /// let person = PodcastPersonBuilder::new()
+    ///     .name("Jane Doe")
+    ///     .finalize();
+    /// let podcast = PodcastItemExtensionBuilder::new()
+    ///     .persons(vec![person.clone()])
+    ///     .finalize();
+    /// assert_eq!(vec![person], podcast.persons());
+    /// ```
+    pub fn persons(&self) -> Vec<PodcastPerson> {
+        self.persons.clone()
+    }
+
+
+    /// Get the optional payments to the episode that exists under
+    /// `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastValueBuilder};
+    ///
+    /// let value = PodcastValueBuilder::new()
+    ///     .value_type("lightning")
+    ///     .method("keysend")
+    ///     .finalize();
+    /// let podcast = PodcastItemExtensionBuilder::new()
+    ///     .value(Some(value.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(value), podcast.value());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastItemExtensionBuilder;
+    ///
+    /// let podcast = PodcastItemExtensionBuilder::new()
+    ///     .value(None)
+    ///     .finalize();
+    /// assert!(podcast.value().is_none());
+    /// ```
+    pub fn value(&self) -> Option<PodcastValue> {
+        self.value.clone()
+    }
+
+
+    /// Get the alternate enclosures of the episode that exist under
+    /// `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastItemExtensionBuilder};
+    ///
+    /// let alternate = PodcastAlternateEnclosureBuilder::new()
+    ///     .enclosure_type("audio/opus")
+    ///     .finalize();
+    /// let podcast = PodcastItemExtensionBuilder::new()
+    ///     .alternate_enclosures(vec![alternate.clone()])
+    ///     .finalize();
+    /// assert_eq!(vec![alternate], podcast.alternate_enclosures());
+    /// ```
+    pub fn alternate_enclosures(&self) -> Vec<PodcastAlternateEnclosure> {
+        self.alternate_enclosures.clone()
+    }
+}
+
+
+impl Extension for PodcastItemExtension {
+    const NAMESPACE: &'static str = PODCAST_NAMESPACE;
+    const PREFIX: &'static str = "podcast";
+
+
+    fn from_elements(elements: &[ExtensionElement]) -> Option<PodcastItemExtension> {
+        Some(PodcastItemExtension {
+            transcripts: podcast::elements_named(elements, "transcript").map(podcast::read_transcript).collect(),
+            chapters: podcast::elements_named(elements, "chapters").next().map(podcast::read_chapters),
+            persons: podcast::elements_named(elements, "person").map(podcast::read_person).collect(),
+            value: podcast::elements_named(elements, "value").next().map(podcast::read_value),
+            alternate_enclosures: podcast::elements_named(elements, "alternateEnclosure")
+                                      .map(podcast::read_alternate_enclosure)
+                                      .collect(),
+        })
+    }
+
+
+    fn to_elements(&self) -> Vec<ExtensionElement> {
+        let mut elements = Vec::new();
+        elements.extend(self.transcripts.iter().map(podcast::transcript_element));
+        elements.extend(self.chapters.iter().map(podcast::chapters_element));
+        elements.extend(self.persons.iter().map(podcast::person_element));
+        elements.extend(self.value.iter().map(podcast::value_element));
+        elements.extend(self.alternate_enclosures.iter().map(podcast::alternate_enclosure_element));
+        elements
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for podcast item extension by using the methods under
+//! `PodcastItemExtensionBuilder`.
+
+use extension::podcast::{PodcastAlternateEnclosure, PodcastChapters, PodcastItemExtension,
+                         PodcastItemExtensionBuilder, PodcastPerson, PodcastTranscript,
+                         PodcastValue};
+
+impl PodcastItemExtensionBuilder {
+    /// Construct a new `PodcastItemExtensionBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastItemExtensionBuilder;
+    ///
+    /// let podcast_builder = PodcastItemExtensionBuilder::new();
+    /// ```
+    pub fn new() -> PodcastItemExtensionBuilder {
+        PodcastItemExtensionBuilder::default()
+    }
+
+
+    /// Set the transcripts of the episode that exist under
+    /// `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastTranscriptBuilder};
+    ///
+    /// let transcript = PodcastTranscriptBuilder::new()
+    ///     .url("http://example.com/episode.vtt")
+    ///     .transcript_type("text/vtt")
+    ///     .finalize();
+    ///
+    /// let mut podcast_builder = PodcastItemExtensionBuilder::new();
+    /// podcast_builder.transcripts(vec![transcript]);
+    /// ```
+    pub fn transcripts(&mut self, transcripts: Vec<PodcastTranscript>) -> &mut PodcastItemExtensionBuilder {
+        self.transcripts = transcripts;
+        self
+    }
+
+
+    /// Set the optional chapters of the episode that exists under
+    /// `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastChaptersBuilder, PodcastItemExtensionBuilder};
+    ///
+    /// let chapters = PodcastChaptersBuilder::new()
+    ///     .url("http://example.com/chapters.json")
+    ///     .chapters_type("application/json+chapters")
+    ///     .finalize();
+    ///
+    /// let mut podcast_builder = PodcastItemExtensionBuilder::new();
+    /// podcast_builder.chapters(Some(chapters));
+    /// ```
+    pub fn chapters(&mut self, chapters: Option<PodcastChapters>) -> &mut PodcastItemExtensionBuilder {
+        self.chapters = chapters;
+        self
+    }
+
+
+    /// Set the persons of the episode that exist under `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastPersonBuilder};
+    ///
+    /// let person = PodcastPersonBuilder::new()
+    ///     .name("Jane Doe")
+    ///     .finalize();
+    ///
+    /// let mut podcast_builder = PodcastItemExtensionBuilder::new();
+    /// podcast_builder.persons(vec![person]);
+    /// ```
+    pub fn persons(&mut self, persons: Vec<PodcastPerson>) -> &mut PodcastItemExtensionBuilder {
+        self.persons = persons;
+        self
+    }
+
+
+    /// Set the optional payments to the episode that exists under
+    /// `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastValueBuilder};
+    ///
+    /// let value = PodcastValueBuilder::new()
+    ///     .value_type("lightning")
+    ///     .method("keysend")
+    ///     .finalize();
+    ///
+    /// let mut podcast_builder = PodcastItemExtensionBuilder::new();
+    /// podcast_builder.value(Some(value));
+    /// ```
+    pub fn value(&mut self, value: Option<PodcastValue>) -> &mut PodcastItemExtensionBuilder {
+        self.value = value;
+        self
+    }
+
+
+    /// Set the alternate enclosures of the episode that exist under
+    /// `PodcastItemExtension`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastAlternateEnclosureBuilder, PodcastItemExtensionBuilder};
+    ///
+    /// let alternate = PodcastAlternateEnclosureBuilder::new()
+    ///     .enclosure_type("audio/opus")
+    ///     .finalize();
+    ///
+    /// let mut podcast_builder = PodcastItemExtensionBuilder::new();
+    /// podcast_builder.alternate_enclosures(vec![alternate]);
+    /// ```
+    pub fn alternate_enclosures(&mut self, alternate_enclosures: Vec<PodcastAlternateEnclosure>) -> &mut PodcastItemExtensionBuilder {
+        self.alternate_enclosures = alternate_enclosures;
+        self
+    }
+
+
+    /// Construct the `PodcastItemExtension` from the
+    /// `PodcastItemExtensionBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastItemExtensionBuilder;
+    ///
+    /// let podcast = PodcastItemExtensionBuilder::new()
+    ///         .transcripts(Vec::new())
+    ///         .chapters(None)
+    ///         .persons(Vec::new())
+    ///         .value(None)
+    ///         .alternate_enclosures(Vec::new())
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> PodcastItemExtension {
+        PodcastItemExtension {
+            transcripts: self.transcripts.clone(),
+            chapters: self.chapters.clone(),
+            persons: self.persons.clone(),
+            value: self.value.clone(),
+            alternate_enclosures: self.alternate_enclosures.clone(),
+        }
+    }
+}
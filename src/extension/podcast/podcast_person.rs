@@ -0,0 +1,132 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under podcast person can be retrieved by using the methods under
+//! `PodcastPerson`.
+
+use extension::podcast::PodcastPerson;
+
+impl PodcastPerson {
+    /// Get the name of the person that exists under `PodcastPerson`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastPersonBuilder;
+    ///
+    /// let person = PodcastPersonBuilder::new()
+    ///     .name("Jane Doe")
+    ///     .finalize();
+    /// assert_eq!("Jane Doe".to_owned(), person.name());
+    /// ```
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+
+    /// Get the optional role of the person that exists under `PodcastPerson`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastPersonBuilder;
+    ///
+    /// let person = PodcastPersonBuilder::new()
+    ///     .role(Some("guest".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("guest".to_owned()), person.role());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastPersonBuilder;
+    ///
+    /// let person = PodcastPersonBuilder::new()
+    ///     .role(None)
+    ///     .finalize();
+    /// assert!(person.role().is_none());
+    /// ```
+    pub fn role(&self) -> Option<String> {
+        self.role.clone()
+    }
+
+
+    /// Get the optional group of the role that exists under `PodcastPerson`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastPersonBuilder;
+    ///
+    /// let person = PodcastPersonBuilder::new()
+    ///     .group(Some("cast".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("cast".to_owned()), person.group());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastPersonBuilder;
+    ///
+    /// let person = PodcastPersonBuilder::new()
+    ///     .group(None)
+    ///     .finalize();
+    /// assert!(person.group().is_none());
+    /// ```
+    pub fn group(&self) -> Option<String> {
+        self.group.clone()
+    }
+
+
+    /// Get the optional url of a picture of the person that exists under
+    /// `PodcastPerson`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastPersonBuilder;
+    ///
+    /// let person = PodcastPersonBuilder::new()
+    ///     .img(Some("http://example.com/jane.jpg".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("http://example.com/jane.jpg".to_owned()), person.img());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastPersonBuilder;
+    ///
+    /// let person = PodcastPersonBuilder::new()
+    ///     .img(None)
+    ///     .finalize();
+    /// assert!(person.img().is_none());
+    /// ```
+    pub fn img(&self) -> Option<String> {
+        self.img.clone()
+    }
+
+
+    /// Get the optional url of a page about the person that exists under
+    /// `PodcastPerson`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastPersonBuilder;
+    ///
+    /// let person = PodcastPersonBuilder::new()
+    ///     .href(Some("http://example.com/jane".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("http://example.com/jane".to_owned()), person.href());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastPersonBuilder;
+    ///
+    /// let person = PodcastPersonBuilder::new()
+    ///     .href(None)
+    ///     .finalize();
+    /// assert!(person.href().is_none());
+    /// ```
+    pub fn href(&self) -> Option<String> {
+        self.href.clone()
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for podcast person by using the methods under
+//! `PodcastPersonBuilder`.
+
+use extension::podcast::{PodcastPerson, PodcastPersonBuilder};
+
+impl PodcastPersonBuilder {
+    /// Construct a new `PodcastPersonBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastPersonBuilder;
+    ///
+    /// let person_builder = PodcastPersonBuilder::new();
+    /// ```
+    pub fn new() -> PodcastPersonBuilder {
+        PodcastPersonBuilder::default()
+    }
+
+
+    /// Set the name of the person that exists under `PodcastPerson`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastPersonBuilder;
+    ///
+    /// let mut person_builder = PodcastPersonBuilder::new();
+    /// person_builder.name("Jane Doe");
+    /// ```
+    pub fn name(&mut self, name: &str) -> &mut PodcastPersonBuilder {
+        self.name = name.to_owned();
+        self
+    }
+
+
+    /// Set the optional role of the person that exists under `PodcastPerson`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastPersonBuilder;
+    ///
+    /// let mut person_builder = PodcastPersonBuilder::new();
+    /// person_builder.role(Some("guest".to_owned()));
+    /// ```
+    pub fn role(&mut self, role: Option<String>) -> &mut PodcastPersonBuilder {
+        self.role = role;
+        self
+    }
+
+
+    /// Set the optional group of the role that exists under `PodcastPerson`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastPersonBuilder;
+    ///
+    /// let mut person_builder = PodcastPersonBuilder::new();
+    /// person_builder.group(Some("cast".to_owned()));
+    /// ```
+    pub fn group(&mut self, group: Option<String>) -> &mut PodcastPersonBuilder {
+        self.group = group;
+        self
+    }
+
+
+    /// Set the optional url of a picture of the person that exists under
+    /// `PodcastPerson`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastPersonBuilder;
+    ///
+    /// let mut person_builder = PodcastPersonBuilder::new();
+    /// person_builder.img(Some("http://example.com/jane.jpg".to_owned()));
+    /// ```
+    pub fn img(&mut self, img: Option<String>) -> &mut PodcastPersonBuilder {
+        self.img = img;
+        self
+    }
+
+
+    /// Set the optional url of a page about the person that exists under
+    /// `PodcastPerson`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastPersonBuilder;
+    ///
+    /// let mut person_builder = PodcastPersonBuilder::new();
+    /// person_builder.href(Some("http://example.com/jane".to_owned()));
+    /// ```
+    pub fn href(&mut self, href: Option<String>) -> &mut PodcastPersonBuilder {
+        self.href = href;
+        self
+    }
+
+
+    /// Construct the `PodcastPerson` from the `PodcastPersonBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastPersonBuilder;
+    ///
+    /// let person = PodcastPersonBuilder::new()
+    ///         .name("Jane Doe")
+    ///         .role(Some("guest".to_owned()))
+    ///         .group(Some("cast".to_owned()))
+    ///         .img(Some("http://example.com/jane.jpg".to_owned()))
+    ///         .href(Some("http://example.com/jane".to_owned()))
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> PodcastPerson {
+        PodcastPerson {
+            name: self.name.clone(),
+            role: self.role.clone(),
+            group: self.group.clone(),
+            img: self.img.clone(),
+            href: self.href.clone(),
+        }
+    }
+}
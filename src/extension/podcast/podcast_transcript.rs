@@ -0,0 +1,98 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under podcast transcript can be retrieved by using the methods
+//! under `PodcastTranscript`.
+
+use extension::podcast::PodcastTranscript;
+
+impl PodcastTranscript {
+    /// Get the url of the transcript that exists under `PodcastTranscript`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastTranscriptBuilder;
+    ///
+    /// let transcript = PodcastTranscriptBuilder::new()
+    ///     .url("http://example.com/episode.vtt")
+    ///     .finalize();
+    /// assert_eq!("http://example.com/episode.vtt".to_owned(), transcript.url());
+    /// ```
+    pub fn url(&self) -> String {
+        self.url.clone()
+    }
+
+
+    /// Get the mime type of the transcript that exists under
+    /// `PodcastTranscript`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastTranscriptBuilder;
+    ///
+    /// let transcript = PodcastTranscriptBuilder::new()
+    ///     .transcript_type("text/vtt")
+    ///     .finalize();
+    /// assert_eq!("text/vtt".to_owned(), transcript.transcript_type());
+    /// ```
+    pub fn transcript_type(&self) -> String {
+        self.transcript_type.clone()
+    }
+
+
+    /// Get the optional language of the transcript that exists under
+    /// `PodcastTranscript`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastTranscriptBuilder;
+    ///
+    /// let transcript = PodcastTranscriptBuilder::new()
+    ///     .language(Some("en".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("en".to_owned()), transcript.language());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastTranscriptBuilder;
+    ///
+    /// let transcript = PodcastTranscriptBuilder::new()
+    ///     .language(None)
+    ///     .finalize();
+    /// assert!(transcript.language().is_none());
+    /// ```
+    pub fn language(&self) -> Option<String> {
+        self.language.clone()
+    }
+
+
+    /// Get the optional relation of the transcript that exists under
+    /// `PodcastTranscript`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastTranscriptBuilder;
+    ///
+    /// let transcript = PodcastTranscriptBuilder::new()
+    ///     .rel(Some("captions".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("captions".to_owned()), transcript.rel());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastTranscriptBuilder;
+    ///
+    /// let transcript = PodcastTranscriptBuilder::new()
+    ///     .rel(None)
+    ///     .finalize();
+    /// assert!(transcript.rel().is_none());
+    /// ```
+    pub fn rel(&self) -> Option<String> {
+        self.rel.clone()
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for podcast transcript by using the methods under
+//! `PodcastTranscriptBuilder`.
+
+use extension::podcast::{PodcastTranscript, PodcastTranscriptBuilder};
+
+impl PodcastTranscriptBuilder {
+    /// Construct a new `PodcastTranscriptBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastTranscriptBuilder;
+    ///
+    /// let transcript_builder = PodcastTranscriptBuilder::new();
+    /// ```
+    pub fn new() -> PodcastTranscriptBuilder {
+        PodcastTranscriptBuilder::default()
+    }
+
+
+    /// Set the url of the transcript that exists under `PodcastTranscript`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastTranscriptBuilder;
+    ///
+    /// let mut transcript_builder = PodcastTranscriptBuilder::new();
+    /// transcript_builder.url("http://example.com/episode.vtt");
+    /// ```
+    pub fn url(&mut self, url: &str) -> &mut PodcastTranscriptBuilder {
+        self.url = url.to_owned();
+        self
+    }
+
+
+    /// Set the mime type of the transcript that exists under
+    /// `PodcastTranscript`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastTranscriptBuilder;
+    ///
+    /// let mut transcript_builder = PodcastTranscriptBuilder::new();
+    /// transcript_builder.transcript_type("text/vtt");
+    /// ```
+    pub fn transcript_type(&mut self, transcript_type: &str) -> &mut PodcastTranscriptBuilder {
+        self.transcript_type = transcript_type.to_owned();
+        self
+    }
+
+
+    /// Set the optional language of the transcript that exists under
+    /// `PodcastTranscript`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastTranscriptBuilder;
+    ///
+    /// let mut transcript_builder = PodcastTranscriptBuilder::new();
+    /// transcript_builder.language(Some("en".to_owned()));
+    /// ```
+    pub fn language(&mut self, language: Option<String>) -> &mut PodcastTranscriptBuilder {
+        self.language = language;
+        self
+    }
+
+
+    /// Set the optional relation of the transcript that exists under
+    /// `PodcastTranscript`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastTranscriptBuilder;
+    ///
+    /// let mut transcript_builder = PodcastTranscriptBuilder::new();
+    /// transcript_builder.rel(Some("captions".to_owned()));
+    /// ```
+    pub fn rel(&mut self, rel: Option<String>) -> &mut PodcastTranscriptBuilder {
+        self.rel = rel;
+        self
+    }
+
+
+    /// Construct the `PodcastTranscript` from the `PodcastTranscriptBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastTranscriptBuilder;
+    ///
+    /// let transcript = PodcastTranscriptBuilder::new()
+    ///         .url("http://example.com/episode.vtt")
+    ///         .transcript_type("text/vtt")
+    ///         .language(Some("en".to_owned()))
+    ///         .rel(Some("captions".to_owned()))
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> PodcastTranscript {
+        PodcastTranscript {
+            url: self.url.clone(),
+            transcript_type: self.transcript_type.clone(),
+            language: self.language.clone(),
+            rel: self.rel.clone(),
+        }
+    }
+}
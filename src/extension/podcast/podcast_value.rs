@@ -0,0 +1,93 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under podcast value can be retrieved by using the methods under
+//! `PodcastValue`.
+
+use extension::podcast::{PodcastValue, PodcastValueRecipient};
+
+impl PodcastValue {
+    /// Get the cryptocurrency or payment layer that exists under
+    /// `PodcastValue`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueBuilder;
+    ///
+    /// let value = PodcastValueBuilder::new()
+    ///     .value_type("lightning")
+    ///     .finalize();
+    /// assert_eq!("lightning".to_owned(), value.value_type());
+    /// ```
+    pub fn value_type(&self) -> String {
+        self.value_type.clone()
+    }
+
+
+    /// Get the method of the payments that exists under `PodcastValue`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueBuilder;
+    ///
+    /// let value = PodcastValueBuilder::new()
+    ///     .method("keysend")
+    ///     .finalize();
+    /// assert_eq!("keysend".to_owned(), value.method());
+    /// ```
+    pub fn method(&self) -> String {
+        self.method.clone()
+    }
+
+
+    /// Get the optional suggested amount per minute that exists under
+    /// `PodcastValue`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueBuilder;
+    ///
+    /// let value = PodcastValueBuilder::new()
+    ///     .suggested(Some("0.00000015000".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("0.00000015000".to_owned()), value.suggested());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueBuilder;
+    ///
+    /// let value = PodcastValueBuilder::new()
+    ///     .suggested(None)
+    ///     .finalize();
+    /// assert!(value.suggested().is_none());
+    /// ```
+    pub fn suggested(&self) -> Option<String> {
+        self.suggested.clone()
+    }
+
+
+    /// Get the recipients of the payments that exist under `PodcastValue`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastValueBuilder, PodcastValueRecipientBuilder};
+    ///
+    /// let recipient = PodcastValueRecipientBuilder::new()
+    ///     .recipient_type("node")
+    ///     .address("02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52")
+    ///     .split(100)
+    ///     .finalize();
+    /// let value = PodcastValueBuilder::new()
+    ///     .recipients(vec![recipient.clone()])
+    ///     .finalize();
+    /// assert_eq!(vec![recipient], value.recipients());
+    /// ```
+    pub fn recipients(&self) -> Vec<PodcastValueRecipient> {
+        self.recipients.clone()
+    }
+}
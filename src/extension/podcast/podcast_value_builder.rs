@@ -0,0 +1,119 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for podcast value by using the methods under
+//! `PodcastValueBuilder`.
+
+use extension::podcast::{PodcastValue, PodcastValueBuilder, PodcastValueRecipient};
+
+impl PodcastValueBuilder {
+    /// Construct a new `PodcastValueBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueBuilder;
+    ///
+    /// let value_builder = PodcastValueBuilder::new();
+    /// ```
+    pub fn new() -> PodcastValueBuilder {
+        PodcastValueBuilder::default()
+    }
+
+
+    /// Set the cryptocurrency or payment layer that exists under
+    /// `PodcastValue`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueBuilder;
+    ///
+    /// let mut value_builder = PodcastValueBuilder::new();
+    /// value_builder.value_type("lightning");
+    /// ```
+    pub fn value_type(&mut self, value_type: &str) -> &mut PodcastValueBuilder {
+        self.value_type = value_type.to_owned();
+        self
+    }
+
+
+    /// Set the method of the payments that exists under `PodcastValue`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueBuilder;
+    ///
+    /// let mut value_builder = PodcastValueBuilder::new();
+    /// value_builder.method("keysend");
+    /// ```
+    pub fn method(&mut self, method: &str) -> &mut PodcastValueBuilder {
+        self.method = method.to_owned();
+        self
+    }
+
+
+    /// Set the optional suggested amount per minute that exists under
+    /// `PodcastValue`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueBuilder;
+    ///
+    /// let mut value_builder = PodcastValueBuilder::new();
+    /// value_builder.suggested(Some("0.00000015000".to_owned()));
+    /// ```
+    pub fn suggested(&mut self, suggested: Option<String>) -> &mut PodcastValueBuilder {
+        self.suggested = suggested;
+        self
+    }
+
+
+    /// Set the recipients of the payments that exist under `PodcastValue`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastValueBuilder, PodcastValueRecipientBuilder};
+    ///
+    /// let recipient = PodcastValueRecipientBuilder::new()
+    ///     .recipient_type("node")
+    ///     .address("02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52")
+    ///     .split(100)
+    ///     .finalize();
+    ///
+    /// let mut value_builder = PodcastValueBuilder::new();
+    /// value_builder.recipients(vec![recipient]);
+    /// ```
+    pub fn recipients(&mut self, recipients: Vec<PodcastValueRecipient>) -> &mut PodcastValueBuilder {
+        self.recipients = recipients;
+        self
+    }
+
+
+    /// Construct the `PodcastValue` from the `PodcastValueBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueBuilder;
+    ///
+    /// let value = PodcastValueBuilder::new()
+    ///         .value_type("lightning")
+    ///         .method("keysend")
+    ///         .suggested(Some("0.00000015000".to_owned()))
+    ///         .recipients(Vec::new())
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> PodcastValue {
+        PodcastValue {
+            value_type: self.value_type.clone(),
+            method: self.method.clone(),
+            suggested: self.suggested.clone(),
+            recipients: self.recipients.clone(),
+        }
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under podcast value recipient can be retrieved by using the
+//! methods under `PodcastValueRecipient`.
+
+use extension::podcast::PodcastValueRecipient;
+
+impl PodcastValueRecipient {
+    /// Get the optional name of the recipient that exists under
+    /// `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let recipient = PodcastValueRecipientBuilder::new()
+    ///     .name(Some("Jane Doe".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("Jane Doe".to_owned()), recipient.name());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let recipient = PodcastValueRecipientBuilder::new()
+    ///     .name(None)
+    ///     .finalize();
+    /// assert!(recipient.name().is_none());
+    /// ```
+    pub fn name(&self) -> Option<String> {
+        self.name.clone()
+    }
+
+
+    /// Get the optional key of a custom record sent with the payment that
+    /// exists under `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let recipient = PodcastValueRecipientBuilder::new()
+    ///     .custom_key(Some("112111100".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("112111100".to_owned()), recipient.custom_key());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let recipient = PodcastValueRecipientBuilder::new()
+    ///     .custom_key(None)
+    ///     .finalize();
+    /// assert!(recipient.custom_key().is_none());
+    /// ```
+    pub fn custom_key(&self) -> Option<String> {
+        self.custom_key.clone()
+    }
+
+
+    /// Get the optional value of a custom record sent with the payment that
+    /// exists under `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let recipient = PodcastValueRecipientBuilder::new()
+    ///     .custom_value(Some("wal_hfPXCiAHbnTr".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("wal_hfPXCiAHbnTr".to_owned()), recipient.custom_value());
+    /// ```
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let recipient = PodcastValueRecipientBuilder::new()
+    ///     .custom_value(None)
+    ///     .finalize();
+    /// assert!(recipient.custom_value().is_none());
+    /// ```
+    pub fn custom_value(&self) -> Option<String> {
+        self.custom_value.clone()
+    }
+
+
+    /// Get the type of the address that exists under `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let recipient = PodcastValueRecipientBuilder::new()
+    ///     .recipient_type("node")
+    ///     .finalize();
+    /// assert_eq!("node".to_owned(), recipient.recipient_type());
+    /// ```
+    pub fn recipient_type(&self) -> String {
+        self.recipient_type.clone()
+    }
+
+
+    /// Get the address of the recipient that exists under
+    /// `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let recipient = PodcastValueRecipientBuilder::new()
+    ///     .address("02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52")
+    ///     .finalize();
+    /// assert_eq!("02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52".to_owned(), recipient.address());
+    /// ```
+    pub fn address(&self) -> String {
+        self.address.clone()
+    }
+
+
+    /// Get the share of the payments that exists under `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let recipient = PodcastValueRecipientBuilder::new()
+    ///     .split(90)
+    ///     .finalize();
+    /// assert_eq!(90, recipient.split());
+    /// ```
+    pub fn split(&self) -> i64 {
+        self.split
+    }
+
+
+    /// Get whether the share is a fee under `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let recipient = PodcastValueRecipientBuilder::new()
+    ///     .fee(true)
+    ///     .finalize();
+    /// assert!(recipient.fee());
+    /// ```
+    pub fn fee(&self) -> bool {
+        self.fee
+    }
+}
@@ -0,0 +1,171 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for podcast value recipient by using the methods under
+//! `PodcastValueRecipientBuilder`.
+
+use extension::podcast::{PodcastValueRecipient, PodcastValueRecipientBuilder};
+
+impl PodcastValueRecipientBuilder {
+    /// Construct a new `PodcastValueRecipientBuilder` and return default
+    /// values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let recipient_builder = PodcastValueRecipientBuilder::new();
+    /// ```
+    pub fn new() -> PodcastValueRecipientBuilder {
+        PodcastValueRecipientBuilder::default()
+    }
+
+
+    /// Set the optional name of the recipient that exists under
+    /// `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let mut recipient_builder = PodcastValueRecipientBuilder::new();
+    /// recipient_builder.name(Some("Jane Doe".to_owned()));
+    /// ```
+    pub fn name(&mut self, name: Option<String>) -> &mut PodcastValueRecipientBuilder {
+        self.name = name;
+        self
+    }
+
+
+    /// Set the optional key of a custom record sent with the payment that
+    /// exists under `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let mut recipient_builder = PodcastValueRecipientBuilder::new();
+    /// recipient_builder.custom_key(Some("112111100".to_owned()));
+    /// ```
+    pub fn custom_key(&mut self, custom_key: Option<String>) -> &mut PodcastValueRecipientBuilder {
+        self.custom_key = custom_key;
+        self
+    }
+
+
+    /// Set the optional value of a custom record sent with the payment that
+    /// exists under `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let mut recipient_builder = PodcastValueRecipientBuilder::new();
+    /// recipient_builder.custom_value(Some("wal_hfPXCiAHbnTr".to_owned()));
+    /// ```
+    pub fn custom_value(&mut self, custom_value: Option<String>) -> &mut PodcastValueRecipientBuilder {
+        self.custom_value = custom_value;
+        self
+    }
+
+
+    /// Set the type of the address that exists under `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let mut recipient_builder = PodcastValueRecipientBuilder::new();
+    /// recipient_builder.recipient_type("node");
+    /// ```
+    pub fn recipient_type(&mut self, recipient_type: &str) -> &mut PodcastValueRecipientBuilder {
+        self.recipient_type = recipient_type.to_owned();
+        self
+    }
+
+
+    /// Set the address of the recipient that exists under
+    /// `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let mut recipient_builder = PodcastValueRecipientBuilder::new();
+    /// recipient_builder.address("02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52");
+    /// ```
+    pub fn address(&mut self, address: &str) -> &mut PodcastValueRecipientBuilder {
+        self.address = address.to_owned();
+        self
+    }
+
+
+    /// Set the share of the payments that exists under `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let mut recipient_builder = PodcastValueRecipientBuilder::new();
+    /// recipient_builder.split(90);
+    /// ```
+    pub fn split(&mut self, split: i64) -> &mut PodcastValueRecipientBuilder {
+        self.split = split;
+        self
+    }
+
+
+    /// Set whether the share is a fee under `PodcastValueRecipient`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let mut recipient_builder = PodcastValueRecipientBuilder::new();
+    /// recipient_builder.fee(true);
+    /// ```
+    pub fn fee(&mut self, fee: bool) -> &mut PodcastValueRecipientBuilder {
+        self.fee = fee;
+        self
+    }
+
+
+    /// Construct the `PodcastValueRecipient` from the
+    /// `PodcastValueRecipientBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastValueRecipientBuilder;
+    ///
+    /// let recipient = PodcastValueRecipientBuilder::new()
+    ///         .name(Some("Jane Doe".to_owned()))
+    ///         .custom_key(Some("112111100".to_owned()))
+    ///         .custom_value(Some("wal_hfPXCiAHbnTr".to_owned()))
+    ///         .recipient_type("node")
+    ///         .address("02d5c1bf8b940dc9cadca86d1b0a3c37fbe39cee4c7e839e33bef9174531d27f52")
+    ///         .split(90)
+    ///         .fee(false)
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> PodcastValueRecipient {
+        PodcastValueRecipient {
+            name: self.name.clone(),
+            custom_key: self.custom_key.clone(),
+            custom_value: self.custom_value.clone(),
+            recipient_type: self.recipient_type.clone(),
+            address: self.address.clone(),
+            split: self.split,
+            fee: self.fee,
+        }
+    }
+}
@@ -0,0 +1,562 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! Implementation of `AtomReader`.
+
+use atom::{Category, CategoryBuilder, ContentBuilder, Entry, EntryBuilder, Feed, FeedBuilder,
+           Link, LinkBuilder, Person, PersonBuilder};
+use errors::FeedError;
+use extension::ATOM_NAMESPACE;
+use feedio::AtomReader;
+use feedio::xml_state::{XmlHandler, XmlState};
+use quick_xml::{Element, XmlReader, XmlWriter};
+use quick_xml::Event::{CData, End, Start, Text};
+use std::io::{BufRead, Cursor};
+use util;
+
+impl AtomReader {
+    /// Construct a new `AtomReader` and return the `Feed`.
+    ///
+    /// A feed that cannot be parsed is logged and results in an empty `Feed`,
+    /// use `AtomReader::parse` to find out why it failed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::AtomReader;
+    ///
+    /// let atom_reader = AtomReader::new("String");
+    /// ```
+    pub fn new(feed: &str) -> AtomReader {
+        let feed = match AtomReader::parse(feed) {
+            Ok(feed) => feed,
+            Err(e) => {
+                error!("{}", e);
+                Feed::default()
+            }
+        };
+        AtomReader { feed }
+    }
+
+
+    /// Parse the atom feed to the `Feed`.
+    ///
+    /// The root element must be `feed` in the `http://www.w3.org/2005/Atom`
+    /// namespace, either as the default namespace or with a prefix. Elements
+    /// from other namespaces are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::AtomReader;
+    ///
+    /// let xml = "<feed xmlns=\"http://www.w3.org/2005/Atom\">".to_owned()
+    ///     + "<title>Example Feed</title>"
+    ///     + "<link href=\"http://example.org/\"/>"
+    ///     + "<updated>2003-12-13T18:30:02Z</updated>"
+    ///     + "<author><name>John Doe</name></author>"
+    ///     + "<id>urn:uuid:60a76c80-d399-11d9-b93C-0003939e0af6</id>"
+    ///     + "<entry>"
+    ///     + "<title>Atom-Powered Robots Run Amok</title>"
+    ///     + "<link href=\"http://example.org/2003/12/13/atom03\"/>"
+    ///     + "<id>urn:uuid:1225c695-cfb8-4ebb-aaaa-80da344efa6a</id>"
+    ///     + "<updated>2003-12-13T18:30:02Z</updated>"
+    ///     + "<summary>Some text.</summary>"
+    ///     + "</entry>"
+    ///     + "</feed>";
+    /// let feed = AtomReader::parse(&xml).unwrap();
+    /// assert_eq!("Example Feed".to_owned(), feed.title());
+    /// assert_eq!("John Doe".to_owned(), feed.authors().unwrap()[0].name());
+    /// assert_eq!("http://example.org/".to_owned(), feed.links().unwrap()[0].href());
+    ///
+    /// let entry = feed.entries().unwrap().remove(0);
+    /// assert_eq!("Atom-Powered Robots Run Amok".to_owned(), entry.title());
+    /// assert_eq!(Some("Some text.".to_owned()), entry.summary());
+    /// assert!(entry.updated().is_some());
+    /// ```
+    ///
+    /// ```
+    /// use feed::feedio::AtomReader;
+    ///
+    /// let xml = "<atom:feed xmlns:atom=\"http://www.w3.org/2005/Atom\">".to_owned()
+    ///     + "<atom:entry><atom:title>Title</atom:title>"
+    ///     + "<atom:content type=\"xhtml\"><div xmlns=\"http://www.w3.org/1999/xhtml\">"
+    ///     + "<p>Some <b>bold</b> text.</p></div></atom:content>"
+    ///     + "</atom:entry></atom:feed>";
+    /// let feed = AtomReader::parse(&xml).unwrap();
+    /// let content = feed.entries().unwrap()[0].content().unwrap();
+    /// assert_eq!(Some("xhtml".to_owned()), content.content_type());
+    /// assert_eq!("<p>Some <b>bold</b> text.</p>".to_owned(), content.content());
+    /// ```
+    ///
+    /// ```
+    /// use feed::FeedError;
+    /// use feed::feedio::AtomReader;
+    ///
+    /// let xml = "<rss><channel><title>Title</title></channel></rss>";
+    /// match AtomReader::parse(xml) {
+    ///     Err(FeedError::UnknownFormat { element, .. }) => assert_eq!("rss", element),
+    ///     _ => panic!("expected an unknown format error"),
+    /// }
+    /// ```
+    ///
+    /// ```
+    /// use feed::feedio::AtomReader;
+    ///
+    /// let xml = "<feed xmlns=\"http://www.w3.org/2005/Atom\">".to_owned()
+    ///     + "<title>Fish &amp; Chips &mdash; <![CDATA[<b>fresh</b>]]></title></feed>";
+    /// let feed = AtomReader::parse(&xml).unwrap();
+    /// assert_eq!("Fish & Chips \u{2014} <b>fresh</b>".to_owned(), feed.title());
+    /// ```
+    ///
+    /// Elements are read by their namespace, whatever prefix it is declared
+    /// with, and dates are parsed leniently, a date that cannot be parsed
+    /// keeping its text instead of failing the feed.
+    ///
+    /// ```
+    /// use feed::feedio::AtomReader;
+    ///
+    /// let xml = "<feed xmlns=\"http://www.w3.org/2005/Atom\">".to_owned()
+    ///     + "<updated>Sat, 13 Dec 2003 18:30:02 GMT</updated>"
+    ///     + "<entry><a:title xmlns:a=\"http://www.w3.org/2005/Atom\">Title</a:title>"
+    ///     + "<updated>last Saturday</updated>"
+    ///     + "<ext:title xmlns:ext=\"http://example.com/ext\">Not the title</ext:title>"
+    ///     + "</entry></feed>";
+    /// let feed = AtomReader::parse(&xml).unwrap();
+    /// assert_eq!("2003-12-13T18:30:02+00:00".to_owned(), feed.updated().unwrap().to_rfc3339());
+    ///
+    /// let entry = feed.entries().unwrap().remove(0);
+    /// assert_eq!("Title".to_owned(), entry.title());
+    /// assert!(entry.updated().is_none());
+    /// assert_eq!(Some("last Saturday".to_owned()), entry.updated_raw());
+    /// ```
+    pub fn parse(feed: &str) -> Result<Feed, FeedError> {
+        AtomReader::parse_reader(feed.as_bytes())
+    }
+
+
+    /// Parse the atom feed read from a `BufRead` to the `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::AtomReader;
+    /// use std::io::Cursor;
+    ///
+    /// let xml = "<feed xmlns=\"http://www.w3.org/2005/Atom\"><title>Title</title></feed>";
+    /// let feed = AtomReader::parse_reader(Cursor::new(xml.as_bytes())).unwrap();
+    /// assert_eq!("Title".to_owned(), feed.title());
+    /// ```
+    pub fn parse_reader<B: BufRead>(reader: B) -> Result<Feed, FeedError> {
+        let mut state = ReaderState::default();
+        // Whitespace is kept for the markup of xhtml text constructs.
+        let mut reader = XmlReader::from_reader(reader);
+        while state.read_event(&mut reader)? {}
+
+        if !state.has_feed {
+            return Err(FeedError::MissingElement {
+                element: "feed".to_owned(),
+                position: reader.buffer_position(),
+                path: String::new(),
+            });
+        }
+        Ok(state.feed_builder.finalize())
+    }
+
+
+    /// Get the `Feed` after parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::AtomReader;
+    ///
+    /// let atom_reader = AtomReader::new("String");
+    /// let feed = atom_reader.feed();
+    /// ```
+    pub fn feed(self) -> Feed {
+        self.feed
+    }
+}
+
+
+// The builders filled while reading the feed.
+#[derive(Default)]
+struct ReaderState {
+    content_builder: ContentBuilder,
+    entry_builder: EntryBuilder,
+    feed_builder: FeedBuilder,
+    person_builder: PersonBuilder,
+    entries: Vec<Entry>,
+    entry_authors: Vec<Person>,
+    entry_categories: Vec<Category>,
+    entry_contributors: Vec<Person>,
+    entry_links: Vec<Link>,
+    feed_authors: Vec<Person>,
+    feed_categories: Vec<Category>,
+    feed_contributors: Vec<Person>,
+    feed_links: Vec<Link>,
+    // Whether the root element, the feed of atom, was read.
+    has_feed: bool,
+    // Markup of a xhtml text construct, with the depth of its element.
+    xhtml: Option<(usize, XmlWriter<Cursor<Vec<u8>>>)>,
+    // The local names of the elements being read, `None` for the elements of
+    // other namespaces and the elements under them.
+    names: Vec<Option<String>>,
+    xml: XmlState,
+}
+
+
+impl ReaderState {
+    // Get the local name of the element being read and the one of its parent,
+    // an empty name for an element of another namespace.
+    fn current(&self) -> (&str, &str) {
+        let name = |index: Option<usize>| {
+            index.and_then(|index| self.names[index].as_deref()).unwrap_or("")
+        };
+        let len = self.names.len();
+        (name(len.checked_sub(1)), name(len.checked_sub(2)))
+    }
+
+
+    // Check the namespace of the root element.
+    fn root(&mut self, qualified_name: &str) -> Result<(), FeedError> {
+        let (namespace, local_name) = self.xml.resolve(qualified_name);
+        if local_name != "feed" || namespace.as_deref() != Some(ATOM_NAMESPACE) {
+            return Err(FeedError::UnknownFormat {
+                element: qualified_name.to_owned(),
+                position: 0,
+                path: String::new(),
+            });
+        }
+        self.has_feed = true;
+        self.names.push(Some(local_name.to_owned()));
+        Ok(())
+    }
+
+
+    // Set the type of a text construct and start collecting its markup when
+    // the type is xhtml.
+    fn start_text_construct(&mut self, e: &Element) -> Result<(), FeedError> {
+        let text_type = util::attribute_to_option_string(e.attributes(), "type")?;
+        if text_type.as_ref().is_some_and(|t| t == "xhtml") {
+            self.xhtml = Some((self.xml.depth(), XmlWriter::new(Cursor::new(Vec::new()))));
+        }
+
+        let (name, parent) = {
+            let (name, parent) = self.current();
+            (name.to_owned(), parent.to_owned())
+        };
+        match (parent.as_str(), name.as_str()) {
+            ("feed", "rights") => {
+                self.feed_builder.rights_type(text_type);
+            }
+            ("feed", "subtitle") => {
+                self.feed_builder.subtitle_type(text_type);
+            }
+            ("feed", "title") => {
+                self.feed_builder.title_type(text_type);
+            }
+            ("entry", "rights") => {
+                self.entry_builder.rights_type(text_type);
+            }
+            ("entry", "summary") => {
+                self.entry_builder.summary_type(text_type);
+            }
+            ("entry", "title") => {
+                self.entry_builder.title_type(text_type);
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+}
+
+
+impl XmlHandler for ReaderState {
+    fn xml(&self) -> &XmlState {
+        &self.xml
+    }
+
+
+    fn xml_mut(&mut self) -> &mut XmlState {
+        &mut self.xml
+    }
+
+
+    // Read the attributes of a start tag.
+    fn start(&mut self, e: &Element, _attributes: Vec<(String, String)>) -> Result<(), FeedError> {
+        if let Some((_, ref mut writer)) = self.xhtml {
+            writer.write(Start(e.clone()))?;
+            self.names.push(None);
+            return Ok(());
+        }
+
+        let qualified_name = String::from_utf8_lossy(e.name()).into_owned();
+        if !self.has_feed {
+            return self.root(&qualified_name);
+        }
+
+        let (namespace, local_name) = self.xml.resolve(&qualified_name);
+        let is_foreign = self.names.last().is_some_and(Option::is_none) ||
+                         namespace.as_deref() != Some(ATOM_NAMESPACE);
+        self.names.push(if is_foreign { None } else { Some(local_name.to_owned()) });
+
+        let (name, parent) = {
+            let (name, parent) = self.current();
+            (name.to_owned(), parent.to_owned())
+        };
+        match name.as_str() {
+            "entry" => {
+                self.entry_builder = EntryBuilder::new();
+                self.entry_authors = Vec::new();
+                self.entry_categories = Vec::new();
+                self.entry_contributors = Vec::new();
+                self.entry_links = Vec::new();
+            }
+            "author" | "contributor" => {
+                self.person_builder = PersonBuilder::new();
+            }
+            "link" => {
+                let mut link_builder = LinkBuilder::new();
+
+                let href = util::attribute_to_str(e.attributes(), "href")?;
+                link_builder.href(&href);
+
+                let rel = util::attribute_to_option_string(e.attributes(), "rel")?;
+                link_builder.rel(rel);
+
+                let link_type = util::attribute_to_option_string(e.attributes(), "type")?;
+                link_builder.link_type(link_type);
+
+                let hreflang = util::attribute_to_option_string(e.attributes(), "hreflang")?;
+                link_builder.hreflang(hreflang);
+
+                let title = util::attribute_to_option_string(e.attributes(), "title")?;
+                link_builder.title(title);
+
+                let length = util::attribute_to_option_i64(e.attributes(), "length")?;
+                link_builder.length(length);
+
+                match parent.as_str() {
+                    "feed" => self.feed_links.push(link_builder.finalize()),
+                    "entry" => self.entry_links.push(link_builder.finalize()),
+                    _ => (),
+                };
+            }
+            "category" => {
+                let mut category_builder = CategoryBuilder::new();
+
+                let term = util::attribute_to_str(e.attributes(), "term")?;
+                category_builder.term(&term);
+
+                let scheme = util::attribute_to_option_string(e.attributes(), "scheme")?;
+                category_builder.scheme(scheme);
+
+                let label = util::attribute_to_option_string(e.attributes(), "label")?;
+                category_builder.label(label);
+
+                match parent.as_str() {
+                    "feed" => self.feed_categories.push(category_builder.finalize()),
+                    "entry" => self.entry_categories.push(category_builder.finalize()),
+                    _ => (),
+                };
+            }
+            "content" => {
+                self.content_builder = ContentBuilder::new();
+
+                let content_type = util::attribute_to_option_string(e.attributes(), "type")?;
+                self.content_builder.content_type(content_type);
+
+                let src = util::attribute_to_option_string(e.attributes(), "src")?;
+                self.content_builder.src(src);
+
+                self.start_text_construct(e)?;
+            }
+            "title" | "subtitle" | "rights" | "summary" => {
+                self.start_text_construct(e)?;
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+
+
+    // Add text or a CDATA section to the text of the element being read, or
+    // to the markup of a xhtml text construct as it is.
+    fn push_text(&mut self,
+                 position: usize,
+                 e: &Element,
+                 is_text: bool)
+                 -> Result<(), FeedError> {
+        if let Some((_, ref mut writer)) = self.xhtml {
+            writer.write(if is_text { Text(e.clone()) } else { CData(e.clone()) })?;
+            return Ok(());
+        }
+        self.xml.push_text(position, e, is_text)
+    }
+
+
+    // Set the text of the element being read, leaving out whitespace around it.
+    fn text(&mut self, text: String) -> Result<(), FeedError> {
+        let text = text.trim().to_owned();
+        if text.is_empty() {
+            return Ok(());
+        }
+        let (name, parent) = {
+            let (name, parent) = self.current();
+            (name.to_owned(), parent.to_owned())
+        };
+        match (parent.as_str(), name.as_str()) {
+            ("entry", "content") => {
+                self.content_builder.content(&text);
+            }
+            (_, "email") => {
+                self.person_builder.email(Some(text));
+            }
+            ("feed", "generator") => {
+                self.feed_builder.generator(Some(text));
+            }
+            ("feed", "icon") => {
+                self.feed_builder.icon(Some(text));
+            }
+            (parent, "id") => {
+                match parent {
+                    "feed" => {
+                        self.feed_builder.id(&text);
+                    }
+                    "entry" => {
+                        self.entry_builder.id(&text);
+                    }
+                    _ => (),
+                };
+            }
+            ("feed", "logo") => {
+                self.feed_builder.logo(Some(text));
+            }
+            (_, "name") => {
+                self.person_builder.name(&text);
+            }
+            ("entry", "published") => {
+                self.entry_builder.published(Some(text));
+            }
+            (parent, "rights") => {
+                match parent {
+                    "feed" => {
+                        self.feed_builder.rights(Some(text));
+                    }
+                    "entry" => {
+                        self.entry_builder.rights(Some(text));
+                    }
+                    _ => (),
+                };
+            }
+            ("feed", "subtitle") => {
+                self.feed_builder.subtitle(Some(text));
+            }
+            ("entry", "summary") => {
+                self.entry_builder.summary(Some(text));
+            }
+            (parent, "title") => {
+                match parent {
+                    "feed" => {
+                        self.feed_builder.title(&text);
+                    }
+                    "entry" => {
+                        self.entry_builder.title(&text);
+                    }
+                    _ => (),
+                };
+            }
+            (parent, "updated") => {
+                match parent {
+                    "feed" => {
+                        self.feed_builder.updated(Some(text));
+                    }
+                    "entry" => {
+                        self.entry_builder.updated(Some(text));
+                    }
+                    _ => (),
+                };
+            }
+            (_, "uri") => {
+                self.person_builder.uri(Some(text));
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+
+
+    // Finish the element being read.
+    fn end(&mut self, e: &Element) -> Result<(), FeedError> {
+        if let Some((depth, _)) = self.xhtml {
+            if depth < self.xml.depth() {
+                if let Some((_, ref mut writer)) = self.xhtml {
+                    writer.write(End(e.clone()))?;
+                }
+                self.names.pop();
+                return Ok(());
+            }
+            if let Some((_, writer)) = self.xhtml.take() {
+                let markup = String::from_utf8(writer.into_inner().into_inner())
+                                 .map_err(|e| FeedError::from(e.utf8_error()))?;
+                self.text(strip_xhtml_div(&markup).to_owned())?;
+            }
+        }
+
+        let (name, parent) = {
+            let (name, parent) = self.current();
+            (name.to_owned(), parent.to_owned())
+        };
+        match name.as_str() {
+            "author" => {
+                match parent.as_str() {
+                    "feed" => self.feed_authors.push(self.person_builder.finalize()),
+                    "entry" => self.entry_authors.push(self.person_builder.finalize()),
+                    _ => (),
+                };
+            }
+            "contributor" => {
+                match parent.as_str() {
+                    "feed" => self.feed_contributors.push(self.person_builder.finalize()),
+                    "entry" => self.entry_contributors.push(self.person_builder.finalize()),
+                    _ => (),
+                };
+            }
+            "content" => {
+                self.entry_builder.content(Some(self.content_builder.finalize()));
+            }
+            "entry" => {
+                self.entry_builder.authors(Some(self.entry_authors.clone()));
+                self.entry_builder.categories(Some(self.entry_categories.clone()));
+                self.entry_builder.contributors(Some(self.entry_contributors.clone()));
+                self.entry_builder.links(Some(self.entry_links.clone()));
+                self.entries.push(self.entry_builder.finalize());
+            }
+            "feed" => {
+                self.feed_builder.authors(Some(self.feed_authors.clone()));
+                self.feed_builder.categories(Some(self.feed_categories.clone()));
+                self.feed_builder.contributors(Some(self.feed_contributors.clone()));
+                self.feed_builder.links(Some(self.feed_links.clone()));
+                self.feed_builder.entries(Some(self.entries.clone()));
+            }
+            _ => (),
+        };
+        self.names.pop();
+        Ok(())
+    }
+}
+
+
+// Remove the xhtml div wrapping the markup of a xhtml text construct.
+fn strip_xhtml_div(markup: &str) -> &str {
+    let trimmed = markup.trim();
+    if trimmed.starts_with("<div") && trimmed.ends_with("</div>") {
+        if let Some(start) = trimmed.find('>') {
+            return trimmed[start + 1..trimmed.len() - "</div>".len()].trim();
+        }
+    }
+    trimmed
+}
@@ -0,0 +1,405 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! Implementation of `AtomWriter`.
+
+use atom::{Category, Content, Entry, Feed, Link, Person};
+use chrono::*;
+use errors::FeedError;
+use feedio::AtomWriter;
+use quick_xml::{Element, XmlWriter};
+use quick_xml::Event::*;
+use std::io::Write;
+use util;
+
+impl AtomWriter {
+    /// Construct a new `AtomWriter` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    /// use feed::feedio::AtomWriter;
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .id("urn:uuid:60a76c80-d399-11d9-b93C-0003939e0af6")
+    ///     .title("Example Feed")
+    ///     .finalize();
+    /// let atom_writer = AtomWriter::new(feed);
+    /// ```
+    pub fn new(feed: Feed) -> AtomWriter {
+        let mut xml = Vec::new();
+        AtomWriter::write_to(&feed, &mut xml).expect("Error writing xml to memory");
+        AtomWriter { xml }
+    }
+
+
+    /// Write the `Feed` as atom directly to a `Write`, such as a file or a
+    /// socket, without building the xml in memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::FeedBuilder;
+    /// use feed::feedio::AtomWriter;
+    ///
+    /// let feed = FeedBuilder::new()
+    ///     .id("urn:uuid:60a76c80-d399-11d9-b93C-0003939e0af6")
+    ///     .title("Example Feed")
+    ///     .finalize();
+    ///
+    /// let mut xml = Vec::new();
+    /// AtomWriter::write_to(&feed, &mut xml).unwrap();
+    /// assert_eq!(AtomWriter::new(feed).xml(), xml);
+    /// ```
+    pub fn write_to<W: Write>(feed: &Feed, writer: W) -> Result<(), FeedError> {
+        write_feed(&mut XmlWriter::new(writer), feed)
+    }
+
+
+    /// Convert the `Feed` to XML.
+    ///
+    /// The written feed can be read back with `AtomReader`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::{ContentBuilder, EntryBuilder, FeedBuilder, LinkBuilder, PersonBuilder};
+    /// use feed::feedio::{AtomReader, AtomWriter};
+    ///
+    /// let author = PersonBuilder::new()
+    ///     .name("John Doe")
+    ///     .email(Some("johndoe@example.com".to_owned()))
+    ///     .finalize();
+    /// let link = LinkBuilder::new()
+    ///     .href("http://example.org/2003/12/13/atom03")
+    ///     .rel(Some("alternate".to_owned()))
+    ///     .link_type(Some("text/html".to_owned()))
+    ///     .hreflang(Some("en".to_owned()))
+    ///     .finalize();
+    /// let content = ContentBuilder::new()
+    ///     .content("<p>Some <b>bold</b> text.</p>")
+    ///     .content_type(Some("xhtml".to_owned()))
+    ///     .finalize();
+    /// let entry = EntryBuilder::new()
+    ///     .id("urn:uuid:1225c695-cfb8-4ebb-aaaa-80da344efa6a")
+    ///     .title("Atom-Powered &lt;b&gt;Robots&lt;/b&gt; Run Amok")
+    ///     .title_type(Some("html".to_owned()))
+    ///     .updated(Some("2003-12-13T18:30:02+00:00".to_owned()))
+    ///     .authors(Some(vec![author]))
+    ///     .links(Some(vec![link]))
+    ///     .content(Some(content))
+    ///     .finalize();
+    /// let feed = FeedBuilder::new()
+    ///     .id("urn:uuid:60a76c80-d399-11d9-b93C-0003939e0af6")
+    ///     .title("Example Feed")
+    ///     .updated(Some("2003-12-13T18:30:02+00:00".to_owned()))
+    ///     .entries(Some(vec![entry]))
+    ///     .finalize();
+    ///
+    /// let xml = AtomWriter::new(feed).xml();
+    /// let feed = AtomReader::parse(&String::from_utf8(xml).unwrap()).unwrap();
+    /// assert_eq!("Example Feed".to_owned(), feed.title());
+    ///
+    /// let entry = feed.entries().unwrap().remove(0);
+    /// assert_eq!("Atom-Powered &lt;b&gt;Robots&lt;/b&gt; Run Amok".to_owned(), entry.title());
+    /// assert_eq!(Some("html".to_owned()), entry.title_type());
+    /// assert_eq!("2003-12-13T18:30:02+00:00".to_owned(), entry.updated().unwrap().to_rfc3339());
+    /// assert_eq!(Some("johndoe@example.com".to_owned()), entry.authors().unwrap()[0].email());
+    ///
+    /// let link = entry.links().unwrap().remove(0);
+    /// assert_eq!(Some("alternate".to_owned()), link.rel());
+    /// assert_eq!(Some("text/html".to_owned()), link.link_type());
+    /// assert_eq!(Some("en".to_owned()), link.hreflang());
+    ///
+    /// let content = entry.content().unwrap();
+    /// assert_eq!("<p>Some <b>bold</b> text.</p>".to_owned(), content.content());
+    /// assert_eq!(Some("xhtml".to_owned()), content.content_type());
+    /// ```
+    pub fn xml(&self) -> Vec<u8> {
+        self.xml.clone()
+    }
+}
+
+
+// Write the `Feed` as an atom document.
+fn write_feed<W: Write>(writer: &mut XmlWriter<W>, feed: &Feed) -> Result<(), FeedError> {
+    writer.write(PI(Element::new("xml version=\"1.0\" encoding=\"UTF-8\"")))?;
+
+    let feed_tag_str = "feed";
+    let mut feed_tag = Element::new(feed_tag_str);
+    feed_tag.push_attribute(b"xmlns", "http://www.w3.org/2005/Atom");
+    writer.write(Start(feed_tag))?;
+
+    write_text_element(writer, "id", &feed.id())?;
+    write_text_construct(writer, "title", &feed.title(), feed.title_type())?;
+
+    if let Some(subtitle) = feed.subtitle() {
+        write_text_construct(writer, "subtitle", &subtitle, feed.subtitle_type())?;
+    }
+
+    if let Some(updated) = date_text(feed.updated(), feed.updated_raw()) {
+        write_text_element(writer, "updated", &updated)?;
+    }
+
+    if let Some(authors) = feed.authors() {
+        for author in authors {
+            write_person(writer, "author", &author)?;
+        }
+    }
+
+    if let Some(contributors) = feed.contributors() {
+        for contributor in contributors {
+            write_person(writer, "contributor", &contributor)?;
+        }
+    }
+
+    if let Some(links) = feed.links() {
+        for link in links {
+            write_link(writer, &link)?;
+        }
+    }
+
+    if let Some(categories) = feed.categories() {
+        for category in categories {
+            write_category(writer, &category)?;
+        }
+    }
+
+    if let Some(generator) = feed.generator() {
+        write_text_element(writer, "generator", &generator)?;
+    }
+
+    if let Some(icon) = feed.icon() {
+        write_text_element(writer, "icon", &icon)?;
+    }
+
+    if let Some(logo) = feed.logo() {
+        write_text_element(writer, "logo", &logo)?;
+    }
+
+    if let Some(rights) = feed.rights() {
+        write_text_construct(writer, "rights", &rights, feed.rights_type())?;
+    }
+
+    if let Some(entries) = feed.entries() {
+        for entry in entries {
+            write_entry(writer, &entry)?;
+        }
+    }
+
+    writer.write(End(Element::new(feed_tag_str)))?;
+    Ok(())
+}
+
+
+// Write the `Entry` under the feed.
+fn write_entry<W: Write>(writer: &mut XmlWriter<W>, entry: &Entry) -> Result<(), FeedError> {
+    let entry_tag_str = "entry";
+    writer.write(Start(Element::new(entry_tag_str)))?;
+
+    write_text_element(writer, "id", &entry.id())?;
+    write_text_construct(writer, "title", &entry.title(), entry.title_type())?;
+
+    if let Some(updated) = date_text(entry.updated(), entry.updated_raw()) {
+        write_text_element(writer, "updated", &updated)?;
+    }
+
+    if let Some(published) = date_text(entry.published(), entry.published_raw()) {
+        write_text_element(writer, "published", &published)?;
+    }
+
+    if let Some(authors) = entry.authors() {
+        for author in authors {
+            write_person(writer, "author", &author)?;
+        }
+    }
+
+    if let Some(contributors) = entry.contributors() {
+        for contributor in contributors {
+            write_person(writer, "contributor", &contributor)?;
+        }
+    }
+
+    if let Some(links) = entry.links() {
+        for link in links {
+            write_link(writer, &link)?;
+        }
+    }
+
+    if let Some(categories) = entry.categories() {
+        for category in categories {
+            write_category(writer, &category)?;
+        }
+    }
+
+    if let Some(summary) = entry.summary() {
+        write_text_construct(writer, "summary", &summary, entry.summary_type())?;
+    }
+
+    if let Some(content) = entry.content() {
+        write_content(writer, &content)?;
+    }
+
+    if let Some(rights) = entry.rights() {
+        write_text_construct(writer, "rights", &rights, entry.rights_type())?;
+    }
+
+    writer.write(End(Element::new(entry_tag_str)))?;
+    Ok(())
+}
+
+
+// Write a person construct such as author or contributor.
+fn write_person<W: Write>(writer: &mut XmlWriter<W>,
+                          tag: &str,
+                          person: &Person)
+                          -> Result<(), FeedError> {
+    writer.write(Start(Element::new(tag)))?;
+
+    write_text_element(writer, "name", &person.name())?;
+
+    if let Some(uri) = person.uri() {
+        write_text_element(writer, "uri", &uri)?;
+    }
+
+    if let Some(email) = person.email() {
+        write_text_element(writer, "email", &email)?;
+    }
+
+    writer.write(End(Element::new(tag)))?;
+    Ok(())
+}
+
+
+// Write the `Link` with its attributes.
+fn write_link<W: Write>(writer: &mut XmlWriter<W>, link: &Link) -> Result<(), FeedError> {
+    let link_tag_str = "link";
+    let mut link_tag = Element::new(link_tag_str);
+    util::push_attribute(&mut link_tag, "href", link.href().as_str());
+
+    if let Some(rel) = link.rel() {
+        util::push_attribute(&mut link_tag, "rel", rel.as_str());
+    }
+
+    if let Some(link_type) = link.link_type() {
+        util::push_attribute(&mut link_tag, "type", link_type.as_str());
+    }
+
+    if let Some(hreflang) = link.hreflang() {
+        util::push_attribute(&mut link_tag, "hreflang", hreflang.as_str());
+    }
+
+    if let Some(title) = link.title() {
+        util::push_attribute(&mut link_tag, "title", title.as_str());
+    }
+
+    if let Some(length) = link.length() {
+        util::push_attribute(&mut link_tag, "length", length.to_string().as_str());
+    }
+
+    writer.write(Start(link_tag))?;
+    writer.write(End(Element::new(link_tag_str)))?;
+    Ok(())
+}
+
+
+// Write the `Category` with its attributes.
+fn write_category<W: Write>(writer: &mut XmlWriter<W>, category: &Category) -> Result<(), FeedError> {
+    let category_tag_str = "category";
+    let mut category_tag = Element::new(category_tag_str);
+    util::push_attribute(&mut category_tag, "term", category.term().as_str());
+
+    if let Some(scheme) = category.scheme() {
+        util::push_attribute(&mut category_tag, "scheme", scheme.as_str());
+    }
+
+    if let Some(label) = category.label() {
+        util::push_attribute(&mut category_tag, "label", label.as_str());
+    }
+
+    writer.write(Start(category_tag))?;
+    writer.write(End(Element::new(category_tag_str)))?;
+    Ok(())
+}
+
+
+// Write the `Content` of an entry, which is empty when it refers to a src.
+fn write_content<W: Write>(writer: &mut XmlWriter<W>, content: &Content) -> Result<(), FeedError> {
+    let content_tag_str = "content";
+    let mut content_tag = Element::new(content_tag_str);
+
+    if let Some(content_type) = content.content_type() {
+        util::push_attribute(&mut content_tag, "type", content_type.as_str());
+    }
+
+    match content.src() {
+        Some(src) => {
+            util::push_attribute(&mut content_tag, "src", src.as_str());
+            writer.write(Start(content_tag))?;
+        }
+        None => {
+            writer.write(Start(content_tag))?;
+            write_text(writer, &content.content(), content.content_type())?;
+        }
+    }
+
+    writer.write(End(Element::new(content_tag_str)))?;
+    Ok(())
+}
+
+
+// Write a text construct with its type, the markup of a xhtml text construct
+// is wrapped in a xhtml div.
+fn write_text_construct<W: Write>(writer: &mut XmlWriter<W>,
+                                  tag: &str,
+                                  text: &str,
+                                  text_type: Option<String>)
+                                  -> Result<(), FeedError> {
+    let mut text_tag = Element::new(tag);
+    if let Some(ref text_type) = text_type {
+        util::push_attribute(&mut text_tag, "type", text_type.as_str());
+    }
+    writer.write(Start(text_tag))?;
+    write_text(writer, text, text_type)?;
+    writer.write(End(Element::new(tag)))?;
+    Ok(())
+}
+
+
+// Write the text of a text construct or content.
+fn write_text<W: Write>(writer: &mut XmlWriter<W>,
+                        text: &str,
+                        text_type: Option<String>)
+                        -> Result<(), FeedError> {
+    if text_type.as_ref().is_some_and(|t| t == "xhtml") {
+        let div_tag_str = "div";
+        let mut div_tag = Element::new(div_tag_str);
+        div_tag.push_attribute(b"xmlns", "http://www.w3.org/1999/xhtml");
+        writer.write(Start(div_tag))?;
+        writer.write(Text(Element::new(text)))?;
+        writer.write(End(Element::new(div_tag_str)))?;
+    } else {
+        writer.write(Text(Element::new(util::escape(text).as_bytes())))?;
+    }
+    Ok(())
+}
+
+
+// Get the text of a date, or its raw text when it could not be parsed.
+fn date_text(date: Option<DateTime<FixedOffset>>, raw: Option<String>) -> Option<String> {
+    date.map(|date| date.to_rfc3339()).or(raw)
+}
+
+
+// Write an element containing only text.
+fn write_text_element<W: Write>(writer: &mut XmlWriter<W>,
+                                tag: &str,
+                                text: &str)
+                                -> Result<(), FeedError> {
+    writer.write(Start(Element::new(tag)))?;
+    writer.write(Text(Element::new(util::escape(text).as_bytes())))?;
+    writer.write(End(Element::new(tag)))?;
+    Ok(())
+}
@@ -4,15 +4,31 @@
 
 //! Implementation of `FeedReader`.
 
+use errors::FeedError;
+use extension::{ExtensionElement, ExtensionElementBuilder, ExtensionMap};
 use feedio::FeedReader;
-use quick_xml::{Event, XmlReader};
+use feedio::xml_state::{XmlHandler, XmlState, RDF_NAMESPACE};
+use quick_xml::{Element, XmlReader};
 use rss::{Channel, ChannelBuilder, Category, CategoryBuilder, CloudBuilder, EnclosureBuilder,
-          GuidBuilder, ImageBuilder, Item, ItemBuilder, SourceBuilder, TextInputBuilder};
+          GuidBuilder, ImageBuilder, Item, ItemBuilder, SourceBuilder, TextInputBuilder, Version};
+use std::borrow::Cow;
+use std::io::BufRead;
+use std::mem;
+use std::str;
 use util;
 
+// The namespaces of rss, whose elements are read by their local name.
+const RSS_NAMESPACES: [&str; 4] = ["http://purl.org/rss/1.0/",
+                                   "http://my.netscape.com/rdf/simple/0.9/",
+                                   "http://backend.userland.com/rss2",
+                                   RDF_NAMESPACE];
+
 impl FeedReader {
     /// Construct a new `FeedReader` and return the `Channel`.
     ///
+    /// A feed that cannot be parsed is logged and results in an empty `Channel`,
+    /// use `FeedReader::parse` to find out why it failed.
+    ///
     /// # Examples
     ///
     /// ```
@@ -21,355 +37,181 @@ impl FeedReader {
     /// let feed_reader = FeedReader::new("String");
     /// ```
     pub fn new(feed: &str) -> FeedReader {
-        let feed_string = feed.to_owned();
-        let mut category_builder = CategoryBuilder::new();
-        let mut channel_builder = ChannelBuilder::new();
-        let mut guid_builder = GuidBuilder::new();
-        let mut image_builder = ImageBuilder::new();
-        let mut item_builder = ItemBuilder::new();
-        let mut source_builder = SourceBuilder::new();
-        let mut text_input_builder = TextInputBuilder::new();
-
-        let mut channel_categories: Vec<Category> = Vec::new();
-        let mut channel_skip_days: Vec<String> = Vec::new();
-        let mut channel_skip_hours: Vec<i64> = Vec::new();
-        let mut items: Vec<Item> = Vec::new();
-        let mut item_categories: Vec<Category> = Vec::new();
-        let mut element = "channel";
-        let mut name = "";
-
-        let reader = XmlReader::from_str(&feed_string).trim_text(true);
-        for r in reader {
-            match r {
-                Ok(Event::Start(ref e)) => {
-                    match e.name() {
-                        b"image" => {
-                            element = "image";
-                        }
-                        b"textInput" => {
-                            element = "textInput";
-                        }
-                        b"item" => {
-                            item_builder = ItemBuilder::new();
-                            element = "item";
-                        }
-                        b"author" => {
-                            name = "author";
-                        }
-                        b"category" => {
-                            name = "category";
-
-                            category_builder = CategoryBuilder::new();
-
-                            let domain = util::attribute_to_option_string(e.attributes(), 0);
-                            category_builder.domain(domain);
-                        }
-                        b"cloud" => {
-                            let mut cloud_builder = CloudBuilder::new();
-
-                            let domain = util::attribute_to_str(e.attributes(), 0);
-                            cloud_builder.domain(domain);
-
-                            let port = util::attribute_to_i64(e.attributes(), 1);
-                            cloud_builder.port(port);
-
-                            let path = util::attribute_to_str(e.attributes(), 2);
-                            cloud_builder.path(path);
-
-                            let register_procedure = util::attribute_to_str(e.attributes(), 3);
-                            cloud_builder.register_procedure(register_procedure);
-
-                            let protocol = util::attribute_to_str(e.attributes(), 4);
-                            cloud_builder.protocol(protocol);
-
-                            channel_builder.cloud(Some(cloud_builder.finalize()));
-                        }
-                        b"comments" => {
-                            name = "comments";
-                        }
-                        b"copyright" => {
-                            name = "copyright";
-                        }
-                        b"day" => {
-                            name = "day";
-                        }
-                        b"description" => {
-                            name = "description";
-                        }
-                        b"docs" => {
-                            name = "docs";
-                        }
-                        b"enclosure" => {
-                            let mut enclosure_builder = EnclosureBuilder::new();
-
-                            let url = util::attribute_to_str(e.attributes(), 0);
-                            enclosure_builder.url(url);
-
-                            let length = util::attribute_to_i64(e.attributes(), 1);
-                            enclosure_builder.length(length);
-
-                            let enclosure_type = util::attribute_to_str(e.attributes(), 2);
-                            enclosure_builder.enclosure_type(enclosure_type);
-
-                            item_builder.enclosure(Some(enclosure_builder.finalize()));
-                        }
-                        b"generator" => {
-                            name = "generator";
-                        }
-                        b"guid" => {
-                            name = "guid";
-
-                            guid_builder = GuidBuilder::new();
-
-                            let permalink = util::attribute_to_option_bool(e.attributes(), 0);
-                            guid_builder.permalink(permalink);
-                        }
-                        b"height" => {
-                            name = "height";
-                        }
-                        b"hour" => {
-                            name = "hour";
-                        }
-                        b"language" => {
-                            name = "language";
-                        }
-                        b"lastBuildDate" => {
-                            name = "lastBuildDate";
-                        }
-                        b"link" => {
-                            name = "link";
-                        }
-                        b"managingEditor" => {
-                            name = "managingEditor";
-                        }
-                        b"name" => {
-                            name = "name";
-                        }
-                        b"pubDate" => {
-                            name = "pubDate";
-                        }
-                        b"rating" => {
-                            name = "rating";
-                        }
-                        b"source" => {
-                            name = "source";
-
-                            source_builder = SourceBuilder::new();
-
-                            let url = util::attribute_to_str(e.attributes(), 0);
-                            source_builder.url(url);
-                        }
-                        b"title" => {
-                            name = "title";
-                        }
-                        b"ttl" => {
-                            name = "ttl";
-                        }
-                        b"url" => {
-                            name = "url";
-                        }
-                        b"webMaster" => {
-                            name = "webMaster";
-                        }
-                        b"width" => {
-                            name = "width";
-                        }
-                        _ => (),
-                    }
-                }
-                Ok(Event::Text(e)) => {
-                    match name {
-                        "author" => {
-                            let author = util::element_to_option_string(e);
-                            item_builder.author(author);
-                        }
-                        "category" => {
-                            let category = util::element_to_string(e);
-                            category_builder.category(&category);
-                        }
-                        "comments" => {
-                            let comments = util::element_to_option_string(e);
-                            item_builder.comments(comments);
-                        }
-                        "copyright" => {
-                            let copyright = util::element_to_option_string(e);
-                            channel_builder.copyright(copyright);
-                        }
-                        "day" => {
-                            let skip_day = util::element_to_string(e);
-                            channel_skip_days.push(skip_day);
-                        }
-                        "description" => {
-                            let description = util::element_to_string(e);
-                            match element {
-                                "channel" => {
-                                    channel_builder.description(&description);
-                                }
-                                "image" => {
-                                    image_builder.description(Some(description));
-                                }
-                                "textInput" => {
-                                    text_input_builder.description(&description);
-                                }
-                                "item" => {
-                                    item_builder.description(Some(description));
-                                }
-                                _ => (),
-                            };
-                        }
-                        "docs" => {
-                            let docs = util::element_to_option_string(e);
-                            channel_builder.docs(docs);
-                        }
-                        "generator" => {
-                            let generator = util::element_to_option_string(e);
-                            channel_builder.generator(generator);
-                        }
-                        "guid" => {
-                            let guid = util::element_to_string(e);
-                            guid_builder.guid(&guid);
-                        }
-                        "height" => {
-                            let height = util::element_to_option_i64(e);
-                            image_builder.height(height);
-                        }
-                        "hour" => {
-                            let skip_hour = util::element_to_i64(e);
-                            channel_skip_hours.push(skip_hour);
-                        }
-                        "language" => {
-                            let language = util::element_to_option_string(e);
-                            channel_builder.language(language);
-                        }
-                        "lastBuildDate" => {
-                            let last_build_date = util::element_to_option_string(e);
-                            channel_builder.last_build_date(last_build_date);
-                        }
-                        "link" => {
-                            let link = util::element_to_string(e);
-                            match element {
-                                "channel" => {
-                                    channel_builder.link(&link);
-                                }
-                                "image" => {
-                                    image_builder.link(&link);
-                                }
-                                "textInput" => {
-                                    text_input_builder.link(&link);
-                                }
-                                "item" => {
-                                    item_builder.link(Some(link));
-                                }
-                                _ => (),
-                            };
-                        }
-                        "managingEditor" => {
-                            let managing_editor = util::element_to_option_string(e);
-                            channel_builder.managing_editor(managing_editor);
-                        }
-                        "name" => {
-                            let name = util::element_to_string(e);
-                            text_input_builder.name(&name);
-                        }
-                        "pubDate" => {
-                            let pub_date = util::element_to_option_string(e);
-                            match element {
-                                "channel" => {
-                                    channel_builder.pub_date(pub_date);
-                                }
-                                "item" => {
-                                    item_builder.pub_date(pub_date);
-                                }
-                                _ => (),
-                            };
-                        }
-                        "rating" => {
-                            let rating = util::element_to_option_string(e);
-                            channel_builder.rating(rating);
-                        }
-                        "source" => {
-                            let source = util::element_to_string(e);
-                            source_builder.source(&source);
-                        }
-                        "title" => {
-                            let title = util::element_to_string(e);
-                            match element {
-                                "channel" => {
-                                    channel_builder.title(&title);
-                                }
-                                "image" => {
-                                    image_builder.title(&title);
-                                }
-                                "textInput" => {
-                                    text_input_builder.title(&title);
-                                }
-                                "item" => {
-                                    item_builder.title(Some(title));
-                                }
-                                _ => (),
-                            };
-                        }
-                        "ttl" => {
-                            let ttl = util::element_to_option_i64(e);
-                            channel_builder.ttl(ttl);
-                        }
-                        "url" => {
-                            let url = util::element_to_string(e);
-                            image_builder.url(&url);
-                        }
-                        "webMaster" => {
-                            let web_master = util::element_to_option_string(e);
-                            channel_builder.web_master(web_master);
-                        }
-                        "width" => {
-                            let width = util::element_to_option_i64(e);
-                            image_builder.width(width);
-                        }
-                        _ => (),
-                    };
-                }
-                Ok(Event::End(ref e)) => {
-                    match e.name() {
-                        b"category" => {
-                            match element {
-                                "channel" => {
-                                    channel_categories.push(category_builder.finalize());
-                                }
-                                "item" => {
-                                    item_categories.push(category_builder.finalize());
-                                }
-                                _ => (),
-                            };
-                        }
-                        b"channel" => {
-                            channel_builder.categories(Some(channel_categories.clone()));
-                            channel_builder.image(Some(image_builder.finalize()));
-                            channel_builder.items(Some(items.clone()));
-                            channel_builder.skip_days(Some(channel_skip_days.clone()));
-                            channel_builder.skip_hours(Some(channel_skip_hours.clone()));
-                            channel_builder.text_input(Some(text_input_builder.finalize()));
-                        }
-                        b"item" => {
-                            item_builder.categories(Some(item_categories.clone()));
-                            items.push(item_builder.finalize());
-                        }
-                        b"guid" => {
-                            item_builder.guid(Some(guid_builder.finalize()));
-                        }
-                        b"source" => {
-                            item_builder.source(Some(source_builder.finalize()));
-                        }
-                        _ => (),
-                    };
-                }
-                Err((e, pos)) => panic!("{:?} at position {}", e, pos),
-                _ => (),
+        let channel = match FeedReader::parse(feed) {
+            Ok(channel) => channel,
+            Err(e) => {
+                error!("{}", e);
+                Channel::default()
             }
-        }
+        };
+        FeedReader { channel }
+    }
+
 
-        FeedReader { channel: channel_builder.finalize() }
+    /// Parse the xml feed to the `Channel`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::FeedReader;
+    ///
+    /// let xml = "<rss version=\"2.0\"><channel><title>Title</title><ttl>60</ttl></channel></rss>";
+    /// let channel = FeedReader::parse(xml).unwrap();
+    /// assert_eq!("Title".to_owned(), channel.title());
+    /// assert_eq!(Some(60), channel.ttl());
+    /// ```
+    ///
+    /// ```
+    /// use feed::FeedError;
+    /// use feed::feedio::FeedReader;
+    ///
+    /// let xml = "<rss><channel><item><title>Title</title></channel></rss>";
+    /// match FeedReader::parse(xml) {
+    ///     Err(FeedError::Xml { path, .. }) => assert_eq!("/rss/channel/item", path),
+    ///     _ => panic!("expected a xml error"),
+    /// }
+    /// ```
+    ///
+    /// ```
+    /// use feed::FeedError;
+    /// use feed::feedio::FeedReader;
+    ///
+    /// let xml = "<rss><channel><item><link>http://example.com</link></item></channel></rss>";
+    /// match FeedReader::parse(xml) {
+    ///     Err(FeedError::MissingElement { path, .. }) => assert_eq!("/rss/channel/item", path),
+    ///     _ => panic!("expected a missing element error"),
+    /// }
+    /// ```
+    ///
+    /// A value that cannot be read leaves out its field rather than failing
+    /// the feed, and an empty title is a title.
+    ///
+    /// ```
+    /// use feed::feedio::FeedReader;
+    ///
+    /// let xml = "<rss><channel><title>Title</title>".to_owned()
+    ///     + "<skipHours><hour>noon</hour><hour>99999999999999999999</hour><hour>6</hour></skipHours>"
+    ///     + "<item><title></title><link>http://example.com/a</link>"
+    ///     + "<guid isPermaLink=\"yes\">http://example.com/a</guid></item>"
+    ///     + "</channel></rss>";
+    /// let channel = FeedReader::parse(&xml).unwrap();
+    /// assert_eq!(Some(vec![6]), channel.skip_hours());
+    ///
+    /// let item = channel.items().unwrap().remove(0);
+    /// assert_eq!(Some(String::new()), item.title());
+    /// assert!(item.guid().unwrap().permalink());
+    /// ```
+    ///
+    /// RSS 1.0 feeds are read into the same `Channel`, the `rdf:about` of an
+    /// item becomes its guid and its pubDate and author fall back to the Dublin
+    /// Core `dc:date` and `dc:creator`.
+    ///
+    /// ```
+    /// use feed::feedio::FeedReader;
+    ///
+    /// let xml = "<rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\" ".to_owned()
+    ///     + "xmlns=\"http://purl.org/rss/1.0/\" xmlns:dc=\"http://purl.org/dc/elements/1.1/\">"
+    ///     + "<channel rdf:about=\"http://example.com/rss\">"
+    ///     + "<title>Title</title><link>http://example.com</link>"
+    ///     + "<image rdf:resource=\"http://example.com/logo.png\"/>"
+    ///     + "<items><rdf:Seq><rdf:li resource=\"http://example.com/a\"/></rdf:Seq></items>"
+    ///     + "</channel>"
+    ///     + "<image rdf:about=\"http://example.com/logo.png\">"
+    ///     + "<title>Logo</title><url>http://example.com/logo.png</url><link>http://example.com</link>"
+    ///     + "</image>"
+    ///     + "<item rdf:about=\"http://example.com/a\">"
+    ///     + "<title>Item</title><link>http://example.com/a</link>"
+    ///     + "<dc:date>2003-06-01T12:00:00+02:00</dc:date><dc:creator>John Doe</dc:creator>"
+    ///     + "</item>"
+    ///     + "</rdf:RDF>";
+    /// let channel = FeedReader::parse(&xml).unwrap();
+    /// assert_eq!("Title".to_owned(), channel.title());
+    /// assert_eq!("Logo".to_owned(), channel.image().unwrap().title());
+    ///
+    /// let item = channel.items().unwrap().remove(0);
+    /// assert_eq!("http://example.com/a".to_owned(), item.guid().unwrap().guid());
+    /// assert_eq!(Some("John Doe".to_owned()), item.author());
+    /// assert_eq!("2003-06-01T12:00:00+02:00".to_owned(), item.pub_date().unwrap().to_rfc3339());
+    /// ```
+    ///
+    /// Attributes are looked up by name, so their order does not matter,
+    /// optional attributes may be left out and unknown ones are ignored.
+    ///
+    /// ```
+    /// use feed::feedio::FeedReader;
+    ///
+    /// let xml = "<rss><channel><item><title>Title</title>".to_owned()
+    ///     + "<enclosure type=\"audio/ogg\" foo=\"bar\" length=\"42\" url=\"http://example.com/a.ogg\"/>"
+    ///     + "<guid>http://example.com/a</guid>"
+    ///     + "<category>Podcast</category>"
+    ///     + "</item></channel></rss>";
+    /// let channel = FeedReader::parse(&xml).unwrap();
+    /// let item = channel.items().unwrap().remove(0);
+    ///
+    /// let enclosure = item.enclosure().unwrap();
+    /// assert_eq!("http://example.com/a.ogg".to_owned(), enclosure.url());
+    /// assert_eq!(42, enclosure.length());
+    /// assert_eq!("audio/ogg".to_owned(), enclosure.enclosure_type());
+    ///
+    /// assert!(item.guid().unwrap().permalink());
+    /// assert!(item.categories().unwrap()[0].domain().is_none());
+    /// ```
+    ///
+    /// ```
+    /// use feed::FeedError;
+    /// use feed::feedio::FeedReader;
+    ///
+    /// let xml = "<rss><channel><item><title>Title</title><enclosure length=\"42\"/></item></channel></rss>";
+    /// match FeedReader::parse(xml) {
+    ///     Err(FeedError::MissingAttribute { attribute, path, .. }) => {
+    ///         assert_eq!("url", attribute);
+    ///         assert_eq!("/rss/channel/item/enclosure", path);
+    ///     }
+    ///     _ => panic!("expected a missing attribute error"),
+    /// }
+    /// ```
+    ///
+    /// CDATA sections are read as text, and character references and the named
+    /// entities of xml and HTML 4 are decoded.
+    ///
+    /// ```
+    /// use feed::feedio::FeedReader;
+    ///
+    /// let xml = "<rss><channel><title>Caf&eacute; &#233;t&#xE9; &amp; co&nbsp;</title>".to_owned()
+    ///     + "<description><![CDATA[<p>Tom &amp; Jerry</p>]]></description>"
+    ///     + "</channel></rss>";
+    /// let channel = FeedReader::parse(&xml).unwrap();
+    /// assert_eq!("Caf\u{E9} \u{E9}t\u{E9} & co\u{A0}".to_owned(), channel.title());
+    /// assert_eq!("<p>Tom &amp; Jerry</p>".to_owned(), channel.description());
+    /// ```
+    pub fn parse(feed: &str) -> Result<Channel, FeedError> {
+        FeedReader::parse_reader(feed.as_bytes())
     }
 
+
+    /// Parse the xml feed read from a `BufRead` to the `Channel`.
+    ///
+    /// The text of each element is checked to be utf-8 as it is read, so the
+    /// document does not need to be read in full first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::FeedReader;
+    /// use std::io::Cursor;
+    ///
+    /// let xml = Cursor::new(b"<rss><channel><title>Title</title></channel></rss>".to_vec());
+    /// let channel = FeedReader::parse_reader(xml).unwrap();
+    /// assert_eq!("Title".to_owned(), channel.title());
+    /// ```
+    pub fn parse_reader<B: BufRead>(reader: B) -> Result<Channel, FeedError> {
+        let mut state = ReaderState::default();
+        let mut reader = XmlReader::from_reader(reader).trim_text(true);
+        while state.read_event(&mut reader)? {}
+        Ok(state.finalize())
+    }
+
+
     /// Get the `Channel` after parsing.
     ///
     /// # Examples
@@ -381,6 +223,519 @@ impl FeedReader {
     /// let channel = feed_reader.channel();
     /// ```
     pub fn channel(self) -> Channel {
-        self.channel.clone()
+        self.channel
+    }
+}
+
+
+// The builders filled while reading the feed, shared with `ItemIter`.
+#[derive(Default)]
+pub(crate) struct ReaderState {
+    category_builder: CategoryBuilder,
+    channel_builder: ChannelBuilder,
+    guid_builder: GuidBuilder,
+    image_builder: ImageBuilder,
+    item_builder: ItemBuilder,
+    source_builder: SourceBuilder,
+    text_input_builder: TextInputBuilder,
+    channel_categories: Vec<Category>,
+    channel_skip_days: Vec<String>,
+    channel_skip_hours: Vec<i64>,
+    items: Vec<Item>,
+    item_categories: Vec<Category>,
+    item_has_title_or_description: bool,
+    // Whether the feed declares the DTD of Netscape RSS 0.91.
+    has_netscape_doctype: bool,
+    // Whether the image or text input being read only refers to another one,
+    // as the ones under the channel of a RSS 1.0 feed.
+    is_reference: bool,
+    // The namespace of the root element, whose elements are read as rss.
+    root_namespace: Option<String>,
+    // The names the elements being read are read by, `None` for the elements
+    // of other namespaces.
+    names: Vec<Option<String>>,
+    // The extension elements being read, the outermost first.
+    extension_elements: Vec<ExtensionState>,
+    channel_extensions: ExtensionMap,
+    item_extensions: ExtensionMap,
+    xml: XmlState,
+}
+
+
+// An extension element being read, with its text and the child elements read
+// so far.
+struct ExtensionState {
+    builder: ExtensionElementBuilder,
+    text: Option<String>,
+    children: Vec<ExtensionElement>,
+}
+
+
+impl ReaderState {
+    // Whether the start tag of an item was just read.
+    pub(crate) fn is_in_item(&self) -> bool {
+        self.names.last().is_some_and(|name| name.as_deref() == Some("item"))
+    }
+
+
+    // Take the item that was just read.
+    pub(crate) fn take_item(&mut self) -> Option<Item> {
+        self.items.pop()
+    }
+
+
+    // Construct the `Channel` from the metadata read so far, without the items.
+    pub(crate) fn channel(&mut self) -> Channel {
+        self.channel_builder.categories(Some(self.channel_categories.clone()));
+        self.channel_builder.skip_days(Some(self.channel_skip_days.clone()));
+        self.channel_builder.skip_hours(Some(self.channel_skip_hours.clone()));
+        self.channel_builder.extensions(self.channel_extensions.clone());
+        self.channel_builder.finalize()
+    }
+
+
+    // Construct the `Channel` once the whole feed is read, the items of a
+    // RSS 1.0 feed are outside of the channel.
+    fn finalize(mut self) -> Channel {
+        self.channel_builder.items(Some(mem::take(&mut self.items)));
+        self.channel()
+    }
+
+
+    // Get the name of the element being read and the name of its parent, an
+    // empty name for an element of another namespace.
+    fn current(&self) -> (&str, &str) {
+        let name = |index: Option<usize>| {
+            index.and_then(|index| self.names[index].as_deref()).unwrap_or("")
+        };
+        let len = self.names.len();
+        (name(len.checked_sub(1)), name(len.checked_sub(2)))
+    }
+
+
+    // Get the name an element is read by, its local name for an element of
+    // rss, or `None` for an element of another namespace.
+    fn rss_name(&self, namespace: Option<&str>, local_name: &str) -> Option<String> {
+        match namespace {
+            None => Some(canonical_name(local_name).to_owned()),
+            Some(namespace) if RSS_NAMESPACES.contains(&namespace) ||
+                               self.root_namespace.as_deref() == Some(namespace) => {
+                Some(canonical_name(local_name).to_owned())
+            }
+            _ => None,
+        }
+    }
+
+
+    // Finish the extension element being read, adding it to the element it is
+    // under. The extension elements of a channel or an item are kept, the ones
+    // of other elements are left out.
+    fn end_extension(&mut self, parent: &str) {
+        let extension = match self.extension_elements.pop() {
+            Some(extension) => extension,
+            None => return,
+        };
+        // Text is trimmed as it is read, the text of CDATA sections as well.
+        let text = extension.text.map(|text| text.trim().to_owned()).filter(|text| !text.is_empty());
+        let mut builder = extension.builder;
+        let element = builder.text(text).children(extension.children).finalize();
+        if let Some(outer) = self.extension_elements.last_mut() {
+            outer.children.push(element);
+            return;
+        }
+
+        let extensions = match parent {
+            "channel" => &mut self.channel_extensions,
+            "item" => &mut self.item_extensions,
+            _ => return,
+        };
+        extensions.entry(element.namespace()).or_default().push(element);
+    }
+}
+
+
+impl XmlHandler for ReaderState {
+    fn xml(&self) -> &XmlState {
+        &self.xml
+    }
+
+
+    fn xml_mut(&mut self) -> &mut XmlState {
+        &mut self.xml
+    }
+
+
+    // Read the doctype, which tells Netscape RSS 0.91 apart.
+    fn doctype(&mut self, e: &Element) -> Result<(), FeedError> {
+        self.has_netscape_doctype = e.content().windows(8).any(|w| w == b"Netscape");
+        Ok(())
+    }
+
+
+    // Read the attributes of a start tag.
+    fn start(&mut self, e: &Element, attributes: Vec<(String, String)>) -> Result<(), FeedError> {
+        let qualified_name = String::from_utf8_lossy(e.name()).into_owned();
+        let (namespace, local_name) = self.xml.resolve(&qualified_name);
+        if self.xml.depth() == 1 {
+            self.root_namespace = namespace.clone();
+        }
+
+        let name = if self.extension_elements.is_empty() {
+            self.rss_name(namespace.as_deref(), local_name)
+        } else {
+            None
+        };
+        self.names.push(name.clone());
+        let name = match name {
+            Some(name) => name,
+            None => {
+                let mut builder = ExtensionElementBuilder::new();
+                builder.name(&qualified_name)
+                       .namespace(&namespace.unwrap_or_default())
+                       .attributes(attributes);
+                self.extension_elements.push(ExtensionState {
+                    builder,
+                    text: None,
+                    children: Vec::new(),
+                });
+                return Ok(());
+            }
+        };
+
+        match name.as_str() {
+            "rss" if self.xml.depth() == 1 => {
+                let version = util::attribute_to_option_str(e.attributes(), "version")?;
+                self.channel_builder.version(match version.as_deref().map(str::trim) {
+                    Some("0.91") if self.has_netscape_doctype => Version::V0_91Netscape,
+                    Some("0.91") => Version::V0_91Userland,
+                    Some("0.92") => Version::V0_92,
+                    _ => Version::V2_0,
+                });
+            }
+            "RDF" if self.xml.depth() == 1 => {
+                let namespace = util::attribute_to_option_str(e.attributes(), "xmlns")?;
+                self.channel_builder.version(match namespace.as_deref() {
+                    Some("http://my.netscape.com/rdf/simple/0.9/") => Version::V0_90,
+                    _ => Version::V1_0,
+                });
+            }
+            "item" => {
+                self.item_builder = ItemBuilder::new();
+                self.item_categories = Vec::new();
+                self.item_extensions = ExtensionMap::new();
+                self.item_has_title_or_description = false;
+
+                if let Some(about) = util::attribute_to_option_str(e.attributes(), "rdf:about")? {
+                    let guid = GuidBuilder::new()
+                                   .guid(&about)
+                                   .permalink(Some(false))
+                                   .finalize();
+                    self.item_builder.guid(Some(guid));
+                }
+            }
+            "image" | "textInput" => {
+                let resource = util::attribute_to_option_str(e.attributes(), "rdf:resource")?;
+                self.is_reference = resource.is_some();
+            }
+            "category" => {
+                self.category_builder = CategoryBuilder::new();
+
+                let domain = util::attribute_to_option_string(e.attributes(), "domain")?;
+                self.category_builder.domain(domain);
+            }
+            "cloud" => {
+                let mut cloud_builder = CloudBuilder::new();
+
+                let domain = util::attribute_to_str(e.attributes(), "domain")?;
+                cloud_builder.domain(&domain);
+
+                let port = util::attribute_to_option_str(e.attributes(), "port")?;
+                cloud_builder.port_raw(port.map(Cow::into_owned));
+
+                if let Some(path) = util::attribute_to_option_str(e.attributes(), "path")? {
+                    cloud_builder.path(&path);
+                }
+
+                if let Some(register_procedure) =
+                       util::attribute_to_option_str(e.attributes(), "registerProcedure")? {
+                    cloud_builder.register_procedure(&register_procedure);
+                }
+
+                if let Some(protocol) = util::attribute_to_option_str(e.attributes(), "protocol")? {
+                    cloud_builder.protocol(&protocol);
+                }
+
+                self.channel_builder.cloud(Some(cloud_builder.finalize()));
+            }
+            "enclosure" => {
+                let mut enclosure_builder = EnclosureBuilder::new();
+
+                let url = util::attribute_to_str(e.attributes(), "url")?;
+                enclosure_builder.url(&url);
+
+                let length = util::attribute_to_option_str(e.attributes(), "length")?;
+                enclosure_builder.length_raw(length.map(Cow::into_owned));
+
+                if let Some(enclosure_type) = util::attribute_to_option_str(e.attributes(), "type")? {
+                    enclosure_builder.enclosure_type(&enclosure_type);
+                }
+
+                self.item_builder.enclosure(Some(enclosure_builder.finalize()));
+            }
+            "guid" => {
+                self.guid_builder = GuidBuilder::new();
+
+                // A value other than true or false is left out, as if absent.
+                let permalink = util::attribute_to_option_str(e.attributes(), "isPermaLink")?;
+                let permalink = permalink.and_then(|permalink| util::str_to_option_bool(&permalink));
+                self.guid_builder.permalink(permalink);
+            }
+            // An empty title or description of an item is read as empty text,
+            // as its text only follows when there is some.
+            "title" if self.current().1 == "item" => {
+                self.item_has_title_or_description = true;
+                self.item_builder.title(Some(String::new()));
+            }
+            "description" if self.current().1 == "item" => {
+                self.item_has_title_or_description = true;
+                self.item_builder.description(Some(String::new()));
+            }
+            "source" => {
+                self.source_builder = SourceBuilder::new();
+
+                let url = util::attribute_to_str(e.attributes(), "url")?;
+                self.source_builder.url(&url);
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+
+
+    // Read the text of the element being read.
+    fn text(&mut self, text: String) -> Result<(), FeedError> {
+        if let Some(extension) = self.extension_elements.last_mut() {
+            extension.text.get_or_insert_with(String::new).push_str(&text);
+            return Ok(());
+        }
+
+        let (name, parent) = {
+            let (name, parent) = self.current();
+            (name.to_owned(), parent.to_owned())
+        };
+        match (parent.as_str(), name.as_str()) {
+            ("item", "author") => {
+                let author = Some(text);
+                self.item_builder.author(author);
+            }
+            (_, "category") => {
+                let category = text;
+                self.category_builder.category(&category);
+            }
+            ("item", "comments") => {
+                let comments = Some(text);
+                self.item_builder.comments(comments);
+            }
+            ("channel", "copyright") => {
+                let copyright = Some(text);
+                self.channel_builder.copyright(copyright);
+            }
+            ("skipDays", "day") => {
+                let skip_day = text;
+                self.channel_skip_days.push(skip_day);
+            }
+            (parent, "description") => {
+                let description = text;
+                match parent {
+                    "channel" => {
+                        self.channel_builder.description(&description);
+                    }
+                    "image" => {
+                        self.image_builder.description(Some(description));
+                    }
+                    "textInput" => {
+                        self.text_input_builder.description(&description);
+                    }
+                    "item" => {
+                        self.item_builder.description(Some(description));
+                    }
+                    _ => (),
+                };
+            }
+            ("channel", "docs") => {
+                let docs = Some(text);
+                self.channel_builder.docs(docs);
+            }
+            ("channel", "generator") => {
+                let generator = Some(text);
+                self.channel_builder.generator(generator);
+            }
+            ("item", "guid") => {
+                let guid = text;
+                self.guid_builder.guid(&guid);
+            }
+            ("image", "height") => {
+                let height = Some(text);
+                self.image_builder.height_raw(height);
+            }
+            ("skipHours", "hour") => {
+                // An hour that is not a number is left out.
+                if let Some(skip_hour) = util::option_str_to_option_i64(Some(&text)) {
+                    self.channel_skip_hours.push(skip_hour);
+                }
+            }
+            ("channel", "language") => {
+                let language = Some(text);
+                self.channel_builder.language(language);
+            }
+            ("channel", "lastBuildDate") => {
+                let last_build_date = Some(text);
+                self.channel_builder.last_build_date(last_build_date);
+            }
+            (parent, "link") => {
+                let link = text;
+                match parent {
+                    "channel" => {
+                        self.channel_builder.link(&link);
+                    }
+                    "image" => {
+                        self.image_builder.link(&link);
+                    }
+                    "textInput" => {
+                        self.text_input_builder.link(&link);
+                    }
+                    "item" => {
+                        self.item_builder.link(Some(link));
+                    }
+                    _ => (),
+                };
+            }
+            ("channel", "managingEditor") => {
+                let managing_editor = Some(text);
+                self.channel_builder.managing_editor(managing_editor);
+            }
+            ("textInput", "name") => {
+                let name = text;
+                self.text_input_builder.name(&name);
+            }
+            (parent, "pubDate") => {
+                let pub_date = Some(text);
+                match parent {
+                    "channel" => {
+                        self.channel_builder.pub_date(pub_date);
+                    }
+                    "item" => {
+                        self.item_builder.pub_date(pub_date);
+                    }
+                    _ => (),
+                };
+            }
+            ("channel", "rating") => {
+                let rating = Some(text);
+                self.channel_builder.rating(rating);
+            }
+            ("item", "source") => {
+                let source = text;
+                self.source_builder.source(&source);
+            }
+            (parent, "title") => {
+                let title = text;
+                match parent {
+                    "channel" => {
+                        self.channel_builder.title(&title);
+                    }
+                    "image" => {
+                        self.image_builder.title(&title);
+                    }
+                    "textInput" => {
+                        self.text_input_builder.title(&title);
+                    }
+                    "item" => {
+                        self.item_builder.title(Some(title));
+                    }
+                    _ => (),
+                };
+            }
+            ("channel", "ttl") => {
+                let ttl = Some(text);
+                self.channel_builder.ttl_raw(ttl);
+            }
+            ("image", "url") => {
+                let url = text;
+                self.image_builder.url(&url);
+            }
+            ("channel", "webMaster") => {
+                let web_master = Some(text);
+                self.channel_builder.web_master(web_master);
+            }
+            ("image", "width") => {
+                let width = Some(text);
+                self.image_builder.width_raw(width);
+            }
+            _ => (),
+        };
+        Ok(())
+    }
+
+
+    // Finish the element being read.
+    fn end(&mut self, _e: &Element) -> Result<(), FeedError> {
+        let (name, parent) = {
+            let (name, parent) = self.current();
+            (name.to_owned(), parent.to_owned())
+        };
+        if self.names.last().is_some_and(Option::is_none) {
+            self.end_extension(&parent);
+        }
+
+        match name.as_str() {
+            "category" => {
+                match parent.as_str() {
+                    "channel" => {
+                        self.channel_categories.push(self.category_builder.finalize());
+                    }
+                    "item" => {
+                        self.item_categories.push(self.category_builder.finalize());
+                    }
+                    _ => (),
+                };
+            }
+            "image" if !self.is_reference => {
+                self.channel_builder.image(Some(self.image_builder.finalize()));
+            }
+            "textInput" if !self.is_reference => {
+                self.channel_builder.text_input(Some(self.text_input_builder.finalize()));
+            }
+            "item" => {
+                if !self.item_has_title_or_description {
+                    return Err(FeedError::MissingElement {
+                        element: "title or description".to_owned(),
+                        position: 0,
+                        path: String::new(),
+                    });
+                }
+                self.item_builder.categories(Some(mem::take(&mut self.item_categories)));
+                self.item_builder.extensions(mem::take(&mut self.item_extensions));
+                self.items.push(self.item_builder.finalize());
+            }
+            "guid" => {
+                self.item_builder.guid(Some(self.guid_builder.finalize()));
+            }
+            "source" => {
+                self.item_builder.source(Some(self.source_builder.finalize()));
+            }
+            _ => (),
+        };
+        self.names.pop();
+        Ok(())
+    }
+}
+
+
+// Get the RSS 2.0 name of an element whose name differs in RSS 1.0.
+fn canonical_name(name: &str) -> &str {
+    match name {
+        "textinput" => "textInput",
+        _ => name,
     }
 }
@@ -4,12 +4,20 @@
 
 //! Implementation of `FeedWriter`.
 
-use errors;
-use feedio::FeedWriter;
+use chrono::*;
+use encoding_rs::{Encoding, UTF_8};
+use errors::FeedError;
+use extension::{ExtensionElement, ExtensionMap, CONTENT_NAMESPACE, DUBLIN_CORE_NAMESPACE};
+use feedio::{FeedWriter, WriterOptions, WriterOptionsBuilder};
 use quick_xml::{Element, XmlWriter};
 use quick_xml::Event::*;
-use rss::Channel;
-use std::io::Cursor;
+use rss::{Channel, Item, Version};
+use std::io::Write;
+use util;
+use util::encoding::{self, EncodingWriter};
+
+const NETSCAPE_DOCTYPE: &str = "!DOCTYPE rss PUBLIC \"-//Netscape Communications//DTD RSS 0.91//EN\" \
+                                \"http://my.netscape.com/publish/formats/rss-0.91.dtd\"";
 
 impl FeedWriter {
     /// Construct a new `FeedWriter` and return default values.
@@ -25,555 +33,903 @@ impl FeedWriter {
     /// let feed_writer = FeedWriter::new(channel);
     /// ```
     pub fn new(channel: Channel) -> FeedWriter {
-        let mut writer = XmlWriter::new(Cursor::new(Vec::new()));
-
-        let xml_tag_str = "?xml";
-        let mut xml_tag = Element::new(xml_tag_str);
-        xml_tag.push_attribute(b"version", "1.0");
-        xml_tag.push_attribute(b"encoding", "UTF-8");
-        writer.write(Start(xml_tag)).expect(errors::tag_start_error(xml_tag_str).as_str());
-        writer.write(End(Element::new(xml_tag_str)))
-              .expect(errors::tag_end_error(xml_tag_str).as_str());
-
-        let rss_tag_str = "rss";
-        let mut rss_tag = Element::new(rss_tag_str);
-        rss_tag.push_attribute(b"version", "2.0");
-        writer.write(Start(rss_tag)).expect(errors::tag_start_error(rss_tag_str).as_str());
-
-        let channel_tag_str = "channel";
-        let channel_tag = Element::new(channel_tag_str);
-        writer.write(Start(channel_tag)).expect(errors::tag_start_error(channel_tag_str).as_str());
-
-        let title_tag_str = "title";
-        let title_tag = Element::new(title_tag_str);
-        writer.write(Start(title_tag)).expect(errors::tag_start_error(title_tag_str).as_str());
-        writer.write(Text(Element::new(channel.title().as_str())))
-              .expect(errors::tag_text_error(title_tag_str).as_str());
-        writer.write(End(Element::new(title_tag_str)))
-              .expect(errors::tag_end_error(title_tag_str).as_str());
-
-        let link_tag_str = "link";
-        let link_tag = Element::new(link_tag_str);
-        writer.write(Start(link_tag)).expect(errors::tag_start_error(link_tag_str).as_str());
-        writer.write(Text(Element::new(channel.link().as_str())))
-              .expect(errors::tag_text_error(link_tag_str).as_str());
-        writer.write(End(Element::new(link_tag_str)))
-              .expect(errors::tag_end_error(link_tag_str).as_str());
-
-        let description_tag_str = "description";
-        let description_tag = Element::new(description_tag_str);
-        writer.write(Start(description_tag))
-              .expect(errors::tag_start_error(description_tag_str).as_str());
-        writer.write(Text(Element::new(channel.description().as_str())))
-              .expect(errors::tag_text_error(description_tag_str).as_str());
-        writer.write(End(Element::new(description_tag_str)))
-              .expect(errors::tag_end_error(description_tag_str).as_str());
-
-        if channel.generator().is_some() {
-            let generator_tag_str = "generator";
-            let generator_tag = Element::new(generator_tag_str);
-            writer.write(Start(generator_tag))
-                  .expect(errors::tag_start_error(generator_tag_str).as_str());
-            writer.write(Text(Element::new(channel.generator().unwrap().as_str())))
-                  .expect(errors::tag_text_error(generator_tag_str).as_str());
-            writer.write(End(Element::new(generator_tag_str)))
-                  .expect(errors::tag_end_error(generator_tag_str).as_str());
+        FeedWriter::with_options(channel, &WriterOptions::default())
+            .expect("Error writing xml to memory")
+    }
+
+
+    /// Construct a new `FeedWriter` writing the `Channel` as the given version
+    /// of rss, leaving out the elements that version does not allow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::{FeedReader, FeedWriter};
+    /// use feed::rss::{ChannelBuilder, ItemBuilder, Version};
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Item".to_owned()))
+    ///     .author(Some("john@example.com".to_owned()))
+    ///     .finalize();
+    /// let channel = ChannelBuilder::new()
+    ///     .title("Title")
+    ///     .link("http://example.com")
+    ///     .description("Description")
+    ///     .ttl(Some(60))
+    ///     .items(Some(vec![item]))
+    ///     .finalize();
+    ///
+    /// let xml = FeedWriter::with_version(channel, Version::V0_91Netscape).xml();
+    /// let xml = String::from_utf8(xml).unwrap();
+    /// assert!(xml.contains("<!DOCTYPE rss PUBLIC \"-//Netscape Communications//DTD RSS 0.91//EN\""));
+    /// assert!(xml.contains("<rss version=\"0.91\">"));
+    /// assert!(!xml.contains("<ttl>"));
+    /// assert!(!xml.contains("<author>"));
+    ///
+    /// let channel = FeedReader::parse(&xml).unwrap();
+    /// assert_eq!(Version::V0_91Netscape, channel.version());
+    /// assert_eq!("Item".to_owned(), channel.items().unwrap()[0].title().unwrap());
+    /// ```
+    ///
+    /// ```
+    /// use feed::feedio::{FeedReader, FeedWriter};
+    /// use feed::rss::{ChannelBuilder, ItemBuilder, Version};
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Item".to_owned()))
+    ///     .link(Some("http://example.com/a".to_owned()))
+    ///     .finalize();
+    /// let channel = ChannelBuilder::new()
+    ///     .title("Title")
+    ///     .link("http://example.com")
+    ///     .description("Description")
+    ///     .items(Some(vec![item]))
+    ///     .finalize();
+    ///
+    /// let xml = FeedWriter::with_version(channel, Version::V1_0).xml();
+    /// let channel = FeedReader::parse(&String::from_utf8(xml).unwrap()).unwrap();
+    /// assert_eq!(Version::V1_0, channel.version());
+    ///
+    /// let item = channel.items().unwrap().remove(0);
+    /// assert_eq!("http://example.com/a".to_owned(), item.guid().unwrap().guid());
+    /// ```
+    pub fn with_version(channel: Channel, version: Version) -> FeedWriter {
+        let options = WriterOptionsBuilder::new().version(Some(version)).finalize();
+        FeedWriter::with_options(channel, &options).expect("Error writing xml to memory")
+    }
+
+
+    /// Write the `Channel` as the given version of rss directly to a `Write`,
+    /// such as a file or a socket, without building the xml in memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::FeedWriter;
+    /// use feed::rss::{ChannelBuilder, Version};
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .title("Title")
+    ///     .link("http://example.com")
+    ///     .description("Description")
+    ///     .finalize();
+    ///
+    /// let mut xml = Vec::new();
+    /// FeedWriter::write_to(&channel, Version::V2_0, &mut xml).unwrap();
+    /// assert_eq!(FeedWriter::new(channel).xml(), xml);
+    /// ```
+    pub fn write_to<W: Write>(channel: &Channel,
+                              version: Version,
+                              writer: W)
+                              -> Result<(), FeedError> {
+        let options = WriterOptionsBuilder::new().version(Some(version)).finalize();
+        FeedWriter::write_with_options_to(channel, &options, writer)
+    }
+
+
+    /// Construct a new `FeedWriter` writing the `Channel` as the given version
+    /// of rss in the character encoding with the label, such as `ISO-8859-1`,
+    /// `windows-1252` or `UTF-16`.
+    ///
+    /// Characters the encoding lacks are written as character references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::Feed;
+    /// use feed::feedio::FeedWriter;
+    /// use feed::rss::{ChannelBuilder, Version};
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .title("Caf\u{E9}")
+    ///     .link("http://example.com")
+    ///     .description("\u{2603}")
+    ///     .finalize();
+    ///
+    /// let xml = FeedWriter::with_encoding(channel, Version::V2_0, "ISO-8859-1").unwrap().xml();
+    /// assert!(xml.starts_with(b"<?xml version=\"1.0\" encoding=\"windows-1252\"?>"));
+    /// let contains = |text: &[u8]| xml.windows(text.len()).any(|w| w == text);
+    /// assert!(contains(b"<title>Caf\xE9</title>"));
+    /// assert!(contains(b"<description>&#9731;</description>"));
+    /// assert_eq!("Caf\u{E9}".to_owned(), Feed::parse(&xml).unwrap().title());
+    /// ```
+    ///
+    /// ```
+    /// use feed::FeedError;
+    /// use feed::feedio::FeedWriter;
+    /// use feed::rss::{Channel, Version};
+    ///
+    /// match FeedWriter::with_encoding(Channel::default(), Version::V2_0, "klingon") {
+    ///     Err(FeedError::UnknownEncoding { label }) => assert_eq!("klingon", label),
+    ///     _ => panic!("expected an unknown encoding error"),
+    /// }
+    /// ```
+    pub fn with_encoding(channel: Channel,
+                         version: Version,
+                         encoding: &str)
+                         -> Result<FeedWriter, FeedError> {
+        let options = WriterOptionsBuilder::new()
+            .version(Some(version))
+            .encoding(Some(encoding.to_owned()))
+            .finalize();
+        FeedWriter::with_options(channel, &options)
+    }
+
+
+    /// Write the `Channel` as the given version of rss in the character
+    /// encoding with the label directly to a `Write`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::Feed;
+    /// use feed::feedio::FeedWriter;
+    /// use feed::rss::{ChannelBuilder, Version};
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .title("Title")
+    ///     .link("http://example.com")
+    ///     .description("Description")
+    ///     .finalize();
+    ///
+    /// let mut xml = Vec::new();
+    /// FeedWriter::write_encoded_to(&channel, Version::V2_0, "UTF-16LE", &mut xml).unwrap();
+    /// assert!(xml.starts_with(&[0xFF, 0xFE, b'<', 0, b'?', 0]));
+    /// assert_eq!("Title".to_owned(), Feed::parse(&xml).unwrap().title());
+    /// ```
+    pub fn write_encoded_to<W: Write>(channel: &Channel,
+                                      version: Version,
+                                      encoding: &str,
+                                      writer: W)
+                                      -> Result<(), FeedError> {
+        let options = WriterOptionsBuilder::new()
+            .version(Some(version))
+            .encoding(Some(encoding.to_owned()))
+            .finalize();
+        FeedWriter::write_with_options_to(channel, &options, writer)
+    }
+
+
+    /// Construct a new `FeedWriter` writing the `Channel` with the
+    /// `WriterOptions`.
+    ///
+    /// Text with characters the encoding lacks is written as escaped text
+    /// rather than as a CDATA section, where their character references would
+    /// not be read back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::{FeedReader, FeedWriter, WriterOptionsBuilder};
+    /// use feed::rss::ChannelBuilder;
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .title("Title")
+    ///     .link("http://example.com")
+    ///     .description("<p>Tom &amp; Jerry</p>")
+    ///     .finalize();
+    ///
+    /// let options = WriterOptionsBuilder::new().cdata(true).finalize();
+    /// let xml = FeedWriter::with_options(channel.clone(), &options).unwrap().xml();
+    /// let xml = String::from_utf8(xml).unwrap();
+    /// assert!(xml.contains("<description><![CDATA[<p>Tom &amp; Jerry</p>]]></description>"));
+    /// assert_eq!(channel.description(), FeedReader::parse(&xml).unwrap().description());
+    ///
+    /// let xml = String::from_utf8(FeedWriter::new(channel).xml()).unwrap();
+    /// assert!(xml.contains("<description>&lt;p&gt;Tom &amp;amp; Jerry&lt;/p&gt;</description>"));
+    /// ```
+    ///
+    /// ```
+    /// use feed::feedio::{FeedReader, FeedWriter, WriterOptionsBuilder};
+    /// use feed::rss::ChannelBuilder;
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .title("Title")
+    ///     .link("http://example.com")
+    ///     .description("<p>Tom &amp; Jerry \u{263A}</p>")
+    ///     .finalize();
+    ///
+    /// let options = WriterOptionsBuilder::new()
+    ///     .encoding(Some("ISO-8859-1".to_owned()))
+    ///     .cdata(true)
+    ///     .finalize();
+    /// let xml = FeedWriter::with_options(channel.clone(), &options).unwrap().xml();
+    /// let xml = String::from_utf8(xml).unwrap();
+    /// assert!(xml.contains("<description>&lt;p&gt;Tom &amp;amp; Jerry &#9786;&lt;/p&gt;</description>"));
+    /// assert_eq!(channel.description(), FeedReader::parse(&xml).unwrap().description());
+    /// ```
+    pub fn with_options(channel: Channel, options: &WriterOptions) -> Result<FeedWriter, FeedError> {
+        let mut xml = Vec::new();
+        FeedWriter::write_with_options_to(&channel, options, &mut xml)?;
+        Ok(FeedWriter { xml })
+    }
+
+
+    /// Write the `Channel` with the `WriterOptions` directly to a `Write`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::{FeedReader, FeedWriter, WriterOptionsBuilder};
+    /// use feed::rss::{ChannelBuilder, Version};
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .title("Title")
+    ///     .link("http://example.com")
+    ///     .description("A ]]> in the text")
+    ///     .finalize();
+    ///
+    /// let options = WriterOptionsBuilder::new()
+    ///     .version(Some(Version::V1_0))
+    ///     .cdata(true)
+    ///     .finalize();
+    /// let mut xml = Vec::new();
+    /// FeedWriter::write_with_options_to(&channel, &options, &mut xml).unwrap();
+    ///
+    /// let channel = FeedReader::parse(&String::from_utf8(xml).unwrap()).unwrap();
+    /// assert_eq!(Version::V1_0, channel.version());
+    /// assert_eq!("A ]]> in the text".to_owned(), channel.description());
+    /// ```
+    pub fn write_with_options_to<W: Write>(channel: &Channel,
+                                           options: &WriterOptions,
+                                           writer: W)
+                                           -> Result<(), FeedError> {
+        let version = options.version().unwrap_or_else(|| channel.version());
+        match options.encoding() {
+            Some(label) => {
+                let encoding = encoding::for_label(&label)?;
+                let writer = EncodingWriter::new(writer, encoding)?;
+                write_channel(&mut XmlWriter::new(writer), channel, version, encoding, options.cdata())
+            }
+            None => write_channel(&mut XmlWriter::new(writer), channel, version, UTF_8, options.cdata()),
         }
+    }
+
+
+    /// Convert the `Channel` to XML.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::{FeedReader, FeedWriter};
+    ///
+    /// let feed_reader = FeedReader::new("String");
+    /// let channel = feed_reader.channel();
+    ///
+    /// let xml = FeedWriter::new(channel).xml();
+    /// ```
+    pub fn xml(&self) -> Vec<u8> {
+        self.xml.clone()
+    }
+}
 
-        if channel.docs().is_some() {
-            let docs_tag_str = "docs";
-            let docs_tag = Element::new(docs_tag_str);
-            writer.write(Start(docs_tag))
-                  .expect(errors::tag_start_error(docs_tag_str).as_str());
-            writer.write(Text(Element::new(channel.docs().unwrap().as_str())))
-                  .expect(errors::tag_text_error(docs_tag_str).as_str());
-            writer.write(End(Element::new(docs_tag_str)))
-                  .expect(errors::tag_end_error(docs_tag_str).as_str());
+
+// Write the `Channel` as a rss document of the given version, declared to be
+// in the encoding, its descriptions as CDATA sections when `cdata` is set.
+fn write_channel<W: Write>(writer: &mut XmlWriter<W>,
+                           channel: &Channel,
+                           version: Version,
+                           encoding: &'static Encoding,
+                           cdata: bool)
+                           -> Result<(), FeedError> {
+    let declaration = format!("xml version=\"1.0\" encoding=\"{}\"",
+                              encoding::declaration_name(encoding));
+    writer.write(PI(Element::new(&declaration)))?;
+    let cdata = Some(encoding).filter(|_| cdata);
+
+    let rss_version = match version {
+        Version::V0_90 | Version::V1_0 => return write_rdf(writer, channel, version, cdata),
+        Version::V0_91Netscape => {
+            writer.write(Start(Element::new(NETSCAPE_DOCTYPE)))?;
+            "0.91"
         }
+        Version::V0_91Userland => "0.91",
+        Version::V0_92 => "0.92",
+        Version::V2_0 => "2.0",
+    };
+    let since_0_92 = matches!(version, Version::V0_92 | Version::V2_0);
+    let since_2_0 = version == Version::V2_0;
+
+    let namespaces = extension_namespaces(channel, vec![(String::new(), String::new())]);
+
+    let rss_tag_str = "rss";
+    let mut rss_tag = Element::new(rss_tag_str);
+    rss_tag.push_attribute(b"version", rss_version);
+    if since_2_0 {
+        push_namespaces(&mut rss_tag, &namespaces);
+    }
+    writer.write(Start(rss_tag))?;
+
+    let channel_tag_str = "channel";
+    writer.write(Start(Element::new(channel_tag_str)))?;
+
+    write_text_element(writer, "title", &channel.title())?;
+    write_text_element(writer, "link", &channel.link())?;
+    write_html_element(writer, "description", &channel.description(), cdata)?;
+
+    if let Some(generator) = channel.generator().filter(|_| since_2_0) {
+        write_text_element(writer, "generator", &generator)?;
+    }
+
+    if let Some(docs) = channel.docs() {
+        write_text_element(writer, "docs", &docs)?;
+    }
+
+    if let Some(language) = channel.language() {
+        write_text_element(writer, "language", &language)?;
+    }
+
+    if let Some(copyright) = channel.copyright() {
+        write_text_element(writer, "copyright", &copyright)?;
+    }
+
+    if let Some(managing_editor) = channel.managing_editor() {
+        write_text_element(writer, "managingEditor", &managing_editor)?;
+    }
 
-        if channel.language().is_some() {
-            let language_tag_str = "language";
-            let language_tag = Element::new(language_tag_str);
-            writer.write(Start(language_tag))
-                  .expect(errors::tag_start_error(language_tag_str).as_str());
-            writer.write(Text(Element::new(channel.language().unwrap().as_str())))
-                  .expect(errors::tag_text_error(language_tag_str).as_str());
-            writer.write(End(Element::new(language_tag_str)))
-                  .expect(errors::tag_end_error(language_tag_str).as_str());
+    if let Some(web_master) = channel.web_master() {
+        write_text_element(writer, "webMaster", &web_master)?;
+    }
+
+    // A `dc:date` the pub date falls back to is written as itself by 2.0.
+    let (pub_date, pub_date_raw) = if since_2_0 {
+        channel.own_pub_date()
+    } else {
+        (channel.pub_date(), channel.pub_date_raw())
+    };
+    if let Some(pub_date) = date_text(pub_date, pub_date_raw, DateTime::to_rfc2822) {
+        write_text_element(writer, "pubDate", &pub_date)?;
+    }
+
+    let last_build_date = date_text(channel.last_build_date(),
+                                    channel.last_build_date_raw(),
+                                    DateTime::to_rfc2822);
+    if let Some(last_build_date) = last_build_date {
+        write_text_element(writer, "lastBuildDate", &last_build_date)?;
+    }
+
+    if let Some(cloud) = channel.cloud().filter(|_| since_0_92) {
+        let cloud_tag_str = "cloud";
+        let mut cloud_tag = Element::new(cloud_tag_str);
+        util::push_attribute(&mut cloud_tag, "domain", cloud.domain().as_str());
+        let port = number_text(cloud.port(), cloud.port_raw()).unwrap_or_else(|| "0".to_owned());
+        util::push_attribute(&mut cloud_tag, "port", port.as_str());
+        util::push_attribute(&mut cloud_tag, "path", cloud.path().as_str());
+        util::push_attribute(&mut cloud_tag,
+                             "registerProcedure",
+                             cloud.register_procedure().as_str());
+        util::push_attribute(&mut cloud_tag, "protocol", cloud.protocol().as_str());
+        writer.write(Start(cloud_tag))?;
+        writer.write(End(Element::new(cloud_tag_str)))?;
+    }
+
+    let ttl = channel.ttl().map(|ttl| ttl.to_string()).or_else(|| channel.ttl_raw());
+    if let Some(ttl) = ttl.filter(|_| since_2_0) {
+        write_text_element(writer, "ttl", &ttl)?;
+    }
+
+    if let Some(categories) = channel.categories().filter(|_| since_0_92) {
+        for category in categories {
+            let category_tag_str = "category";
+            let mut category_tag = Element::new(category_tag_str);
+            if let Some(domain) = category.domain() {
+                util::push_attribute(&mut category_tag, "domain", domain.as_str());
+            }
+            writer.write(Start(category_tag))?;
+            writer.write(Text(Element::new(util::escape(&category.category()).as_bytes())))?;
+            writer.write(End(Element::new(category_tag_str)))?;
         }
+    }
 
-        if channel.copyright().is_some() {
-            let copyright_tag_str = "copyright";
-            let copyright_tag = Element::new(copyright_tag_str);
-            writer.write(Start(copyright_tag))
-                  .expect(errors::tag_start_error(copyright_tag_str).as_str());
-            writer.write(Text(Element::new(channel.copyright().unwrap().as_str())))
-                  .expect(errors::tag_text_error(copyright_tag_str).as_str());
-            writer.write(End(Element::new(copyright_tag_str)))
-                  .expect(errors::tag_end_error(copyright_tag_str).as_str());
+    if let Some(image) = channel.image() {
+        let image_tag_str = "image";
+        writer.write(Start(Element::new(image_tag_str)))?;
+
+        write_text_element(writer, "link", &image.link())?;
+        write_text_element(writer, "url", &image.url())?;
+        write_text_element(writer, "title", &image.title())?;
+
+        if let Some(width) = number_text(image.width(), image.width_raw()) {
+            write_text_element(writer, "width", &width)?;
         }
 
-        if channel.managing_editor().is_some() {
-            let managing_editor_tag_str = "managingEditor";
-            let managing_editor_tag = Element::new(managing_editor_tag_str);
-            writer.write(Start(managing_editor_tag))
-                  .expect(errors::tag_start_error(managing_editor_tag_str).as_str());
-            writer.write(Text(Element::new(channel.managing_editor().unwrap().as_str())))
-                  .expect(errors::tag_text_error(managing_editor_tag_str).as_str());
-            writer.write(End(Element::new(managing_editor_tag_str)))
-                  .expect(errors::tag_end_error(managing_editor_tag_str).as_str());
+        if let Some(height) = number_text(image.height(), image.height_raw()) {
+            write_text_element(writer, "height", &height)?;
         }
 
-        if channel.web_master().is_some() {
-            let web_master_tag_str = "webMaster";
-            let web_master_tag = Element::new(web_master_tag_str);
-            writer.write(Start(web_master_tag))
-                  .expect(errors::tag_start_error(web_master_tag_str).as_str());
-            writer.write(Text(Element::new(channel.web_master().unwrap().as_str())))
-                  .expect(errors::tag_text_error(web_master_tag_str).as_str());
-            writer.write(End(Element::new(web_master_tag_str)))
-                  .expect(errors::tag_end_error(web_master_tag_str).as_str());
+        if let Some(description) = image.description() {
+            write_text_element(writer, "description", &description)?;
         }
 
-        if channel.pub_date().is_some() {
-            let pub_date_tag_str = "pubDate";
-            let pub_date_tag = Element::new(pub_date_tag_str);
-            writer.write(Start(pub_date_tag))
-                  .expect(errors::tag_start_error(pub_date_tag_str).as_str());
-            writer.write(Text(Element::new(channel.pub_date().unwrap().to_rfc2822())))
-                  .expect(errors::tag_text_error(pub_date_tag_str).as_str());
-            writer.write(End(Element::new(pub_date_tag_str)))
-                  .expect(errors::tag_end_error(pub_date_tag_str).as_str());
+        writer.write(End(Element::new(image_tag_str)))?;
+    }
+
+    if let Some(rating) = channel.rating() {
+        write_text_element(writer, "rating", &rating)?;
+    }
+
+    if let Some(text_input) = channel.text_input() {
+        let text_input_str = "textInput";
+        writer.write(Start(Element::new(text_input_str)))?;
+
+        write_text_element(writer, "title", &text_input.title())?;
+        write_text_element(writer, "description", &text_input.description())?;
+        write_text_element(writer, "name", &text_input.name())?;
+        write_text_element(writer, "link", &text_input.link())?;
+
+        writer.write(End(Element::new(text_input_str)))?;
+    }
+
+    if let Some(skip_hours) = channel.skip_hours().filter(|hours| !hours.is_empty()) {
+        let skip_hours_tag_str = "skipHours";
+        writer.write(Start(Element::new(skip_hours_tag_str)))?;
+        for hour in skip_hours {
+            write_text_element(writer, "hour", &hour.to_string())?;
         }
+        writer.write(End(Element::new(skip_hours_tag_str)))?;
+    }
 
-        if channel.last_build_date().is_some() {
-            let last_build_date_tag_str = "lastBuildDate";
-            let last_build_date_tag = Element::new(last_build_date_tag_str);
-            writer.write(Start(last_build_date_tag))
-                  .expect(errors::tag_start_error(last_build_date_tag_str).as_str());
-            writer.write(Text(Element::new(channel.last_build_date().unwrap().to_rfc2822())))
-                  .expect(errors::tag_text_error(last_build_date_tag_str).as_str());
-            writer.write(End(Element::new(last_build_date_tag_str)))
-                  .expect(errors::tag_end_error(last_build_date_tag_str).as_str());
+    if let Some(skip_days) = channel.skip_days().filter(|days| !days.is_empty()) {
+        let skip_days_tag_str = "skipDays";
+        writer.write(Start(Element::new(skip_days_tag_str)))?;
+        for day in skip_days {
+            write_text_element(writer, "day", &day)?;
         }
+        writer.write(End(Element::new(skip_days_tag_str)))?;
+    }
 
-        if channel.cloud().is_some() {
-            let cloud_tag_str = "cloud";
-            let mut cloud_tag = Element::new(cloud_tag_str);
-            cloud_tag.push_attribute(b"domain", channel.cloud().unwrap().domain().as_str());
-            cloud_tag.push_attribute(b"port",
-                                     channel.cloud().unwrap().port().to_string().as_str());
-            cloud_tag.push_attribute(b"path", channel.cloud().unwrap().path().as_str());
-            cloud_tag.push_attribute(b"registerProcedure",
-                                     channel.cloud().unwrap().register_procedure().as_str());
-            cloud_tag.push_attribute(b"protocol", channel.cloud().unwrap().protocol().as_str());
-            writer.write(Start(cloud_tag))
-                  .expect(errors::tag_start_error(cloud_tag_str).as_str());
-            writer.write(End(Element::new(cloud_tag_str)))
-                  .expect(errors::tag_end_error(cloud_tag_str).as_str());
+    if since_2_0 {
+        write_extensions(writer, &channel.extensions(), &namespaces, cdata)?;
+    }
+
+    if let Some(items) = channel.items() {
+        for item in items {
+            write_item(writer, &item, version, cdata, &namespaces)?;
         }
+    }
+
+    writer.write(End(Element::new(channel_tag_str)))?;
+    writer.write(End(Element::new(rss_tag_str)))?;
+    Ok(())
+}
+
+
+// Write the `Channel` as a RDF document of RSS 0.90 or RSS 1.0, where the
+// image, items and text input follow the channel.
+fn write_rdf<W: Write>(writer: &mut XmlWriter<W>,
+                       channel: &Channel,
+                       version: Version,
+                       cdata: Option<&'static Encoding>)
+                       -> Result<(), FeedError> {
+    let is_1_0 = version == Version::V1_0;
+    let items = channel.items().unwrap_or_default();
+
+    let mut namespaces = vec![("rdf".to_owned(), "http://www.w3.org/1999/02/22-rdf-syntax-ns#".to_owned())];
+    if is_1_0 {
+        namespaces.push((String::new(), "http://purl.org/rss/1.0/".to_owned()));
+        namespaces.push(("dc".to_owned(), DUBLIN_CORE_NAMESPACE.to_owned()));
+        namespaces = extension_namespaces(channel, namespaces);
+    } else {
+        namespaces.push((String::new(), "http://my.netscape.com/rdf/simple/0.9/".to_owned()));
+    }
+
+    let rdf_tag_str = "rdf:RDF";
+    let mut rdf_tag = Element::new(rdf_tag_str);
+    push_namespaces(&mut rdf_tag, &namespaces);
+    writer.write(Start(rdf_tag))?;
 
-        if channel.ttl().is_some() {
-            let ttl_tag_str = "ttl";
-            let ttl_tag = Element::new(ttl_tag_str);
-            writer.write(Start(ttl_tag))
-                  .expect(errors::tag_start_error(ttl_tag_str).as_str());
-            writer.write(Text(Element::new(channel.ttl().unwrap().to_string().as_str())))
-                  .expect(errors::tag_text_error(ttl_tag_str).as_str());
-            writer.write(End(Element::new(ttl_tag_str)))
-                  .expect(errors::tag_end_error(ttl_tag_str).as_str());
+    let channel_tag_str = "channel";
+    let mut channel_tag = Element::new(channel_tag_str);
+    if is_1_0 {
+        util::push_attribute(&mut channel_tag, "rdf:about", channel.link().as_str());
+    }
+    writer.write(Start(channel_tag))?;
+
+    write_text_element(writer, "title", &channel.title())?;
+    write_text_element(writer, "link", &channel.link())?;
+    write_html_element(writer, "description", &channel.description(), cdata)?;
+
+    if is_1_0 {
+        let extensions = channel.extensions();
+        let (pub_date, pub_date_raw) = channel.own_pub_date();
+        let pub_date = date_text(pub_date, pub_date_raw, DateTime::to_rfc3339);
+        if let Some(pub_date) = pub_date.filter(|_| util::dublin_core_text(&extensions, "date").is_none()) {
+            write_text_element(writer, "dc:date", &pub_date)?;
         }
 
-        if channel.categories().is_some() {
-            for category in channel.categories().unwrap() {
-                let category_tag_str = "category";
-                let mut category_tag = Element::new(category_tag_str);
-                if category.domain().is_some() {
-                    category_tag.push_attribute(b"domain", category.domain().unwrap().as_str());
-                }
-                writer.write(Start(category_tag))
-                      .expect(errors::tag_start_error(category_tag_str).as_str());
-                writer.write(Text(Element::new(category.category())))
-                      .expect(errors::tag_text_error(category_tag_str).as_str());
-                writer.write(End(Element::new(category_tag_str)))
-                      .expect(errors::tag_end_error(category_tag_str).as_str());
-            }
+        if let Some(image) = channel.image() {
+            let mut image_tag = Element::new("image");
+            util::push_attribute(&mut image_tag, "rdf:resource", image.url().as_str());
+            writer.write(Start(image_tag))?;
+            writer.write(End(Element::new("image")))?;
         }
 
-        if channel.image().is_some() {
-            let image_tag_str = "image";
-            let image_tag = Element::new(image_tag_str);
-            writer.write(Start(image_tag)).expect(errors::tag_start_error(image_tag_str).as_str());
-
-            let image_link_tag_str = "link";
-            let image_link_tag = Element::new(image_link_tag_str);
-            writer.write(Start(image_link_tag))
-                  .expect(errors::tag_start_error(image_link_tag_str).as_str());
-            writer.write(Text(Element::new(channel.image()
-                                                  .unwrap()
-                                                  .link()
-                                                  .as_str())))
-                  .expect(errors::tag_text_error(image_link_tag_str).as_str());
-            writer.write(End(Element::new(image_link_tag_str)))
-                  .expect(errors::tag_end_error(image_link_tag_str).as_str());
-
-            let image_url_tag_str = "url";
-            let image_url_tag = Element::new(image_url_tag_str);
-            writer.write(Start(image_url_tag))
-                  .expect(errors::tag_start_error(image_url_tag_str).as_str());
-            writer.write(Text(Element::new(channel.image()
-                                                  .unwrap()
-                                                  .url()
-                                                  .as_str())))
-                  .expect(errors::tag_text_error(image_url_tag_str).as_str());
-            writer.write(End(Element::new(image_url_tag_str)))
-                  .expect(errors::tag_end_error(image_url_tag_str).as_str());
-
-            let image_title_tag_str = "title";
-            let image_title_tag = Element::new(image_title_tag_str);
-            writer.write(Start(image_title_tag))
-                  .expect(errors::tag_start_error(image_title_tag_str).as_str());
-            writer.write(Text(Element::new(channel.image()
-                                                  .unwrap()
-                                                  .title()
-                                                  .as_str())))
-                  .expect(errors::tag_text_error(image_title_tag_str).as_str());
-            writer.write(End(Element::new(image_title_tag_str)))
-                  .expect(errors::tag_end_error(image_title_tag_str).as_str());
-
-            let image_width_tag_str = "width";
-            let image_width_tag = Element::new(image_width_tag_str);
-            writer.write(Start(image_width_tag))
-                  .expect(errors::tag_start_error(image_width_tag_str).as_str());
-            writer.write(Text(Element::new(channel.image()
-                                                  .unwrap()
-                                                  .width()
-                                                  .to_string()
-                                                  .as_str())))
-                  .expect(errors::tag_text_error(image_width_tag_str).as_str());
-            writer.write(End(Element::new(image_width_tag_str)))
-                  .expect(errors::tag_end_error(image_width_tag_str).as_str());
-
-            let image_height_tag_str = "height";
-            let image_height_tag = Element::new(image_height_tag_str);
-            writer.write(Start(image_height_tag))
-                  .expect(errors::tag_start_error(image_height_tag_str).as_str());
-            writer.write(Text(Element::new(channel.image()
-                                                  .unwrap()
-                                                  .height()
-                                                  .to_string()
-                                                  .as_str())))
-                  .expect(errors::tag_text_error(image_height_tag_str).as_str());
-            writer.write(End(Element::new(image_height_tag_str)))
-                  .expect(errors::tag_end_error(image_height_tag_str).as_str());
-
-            if channel.image().unwrap().description().is_some() {
-                let image_description_tag_str = "description";
-                let image_description_tag = Element::new(image_description_tag_str);
-                writer.write(Start(image_description_tag))
-                      .expect(errors::tag_start_error(image_description_tag_str).as_str());
-                writer.write(Text(Element::new(channel.image()
-                                                      .unwrap()
-                                                      .description()
-                                                      .unwrap()
-                                                      .as_str())))
-                      .expect(errors::tag_text_error(image_description_tag_str).as_str());
-                writer.write(End(Element::new(image_description_tag_str)))
-                      .expect(errors::tag_end_error(image_description_tag_str).as_str());
-            }
+        writer.write(Start(Element::new("items")))?;
+        writer.write(Start(Element::new("rdf:Seq")))?;
+        for item in &items {
+            let mut li_tag = Element::new("rdf:li");
+            util::push_attribute(&mut li_tag, "rdf:resource", rdf_about(item).as_str());
+            writer.write(Start(li_tag))?;
+            writer.write(End(Element::new("rdf:li")))?;
+        }
+        writer.write(End(Element::new("rdf:Seq")))?;
+        writer.write(End(Element::new("items")))?;
+
+        if let Some(text_input) = channel.text_input() {
+            let mut text_input_tag = Element::new("textinput");
+            util::push_attribute(&mut text_input_tag, "rdf:resource", text_input.link().as_str());
+            writer.write(Start(text_input_tag))?;
+            writer.write(End(Element::new("textinput")))?;
+        }
+
+        write_extensions(writer, &channel.extensions(), &namespaces, cdata)?;
+    }
+
+    writer.write(End(Element::new(channel_tag_str)))?;
 
-            writer.write(End(Element::new(image_tag_str)))
-                  .expect(errors::tag_end_error(image_tag_str).as_str());
+    if let Some(image) = channel.image() {
+        let image_tag_str = "image";
+        let mut image_tag = Element::new(image_tag_str);
+        if is_1_0 {
+            util::push_attribute(&mut image_tag, "rdf:about", image.url().as_str());
         }
+        writer.write(Start(image_tag))?;
 
-        if channel.rating().is_some() {
-            let rating_tag_str = "rating";
-            let rating_tag = Element::new(rating_tag_str);
-            writer.write(Start(rating_tag))
-                  .expect(errors::tag_start_error(rating_tag_str).as_str());
-            writer.write(Text(Element::new(channel.rating().unwrap().as_str())))
-                  .expect(errors::tag_text_error(rating_tag_str).as_str());
-            writer.write(End(Element::new(rating_tag_str)))
-                  .expect(errors::tag_end_error(rating_tag_str).as_str());
+        write_text_element(writer, "title", &image.title())?;
+        write_text_element(writer, "url", &image.url())?;
+        write_text_element(writer, "link", &image.link())?;
+
+        writer.write(End(Element::new(image_tag_str)))?;
+    }
+
+    for item in &items {
+        let item_tag_str = "item";
+        let mut item_tag = Element::new(item_tag_str);
+        if is_1_0 {
+            util::push_attribute(&mut item_tag, "rdf:about", rdf_about(item).as_str());
         }
+        writer.write(Start(item_tag))?;
 
-        if channel.text_input().is_some() {
-            let text_input_str = "textInput";
-            let text_input = Element::new(text_input_str);
-            writer.write(Start(text_input))
-                  .expect(errors::tag_start_error(text_input_str).as_str());
-
-            let title_tag_str = "title";
-            let title_tag = Element::new(title_tag_str);
-            writer.write(Start(title_tag))
-                  .expect(errors::tag_start_error(title_tag_str).as_str());
-            writer.write(Text(Element::new(channel.text_input()
-                                                  .unwrap()
-                                                  .title()
-                                                  .as_str())))
-                  .expect(errors::tag_text_error(title_tag_str).as_str());
-            writer.write(End(Element::new(title_tag_str)))
-                  .expect(errors::tag_end_error(title_tag_str).as_str());
-
-            let description_tag_str = "description";
-            let description_tag = Element::new(description_tag_str);
-            writer.write(Start(description_tag))
-                  .expect(errors::tag_start_error(description_tag_str).as_str());
-            writer.write(Text(Element::new(channel.description().as_str())))
-                  .expect(errors::tag_text_error(description_tag_str).as_str());
-            writer.write(End(Element::new(description_tag_str)))
-                  .expect(errors::tag_end_error(description_tag_str).as_str());
-
-            let name_tag_str = "name";
-            let name_tag = Element::new(name_tag_str);
-            writer.write(Start(name_tag))
-                  .expect(errors::tag_start_error(name_tag_str).as_str());
-            writer.write(Text(Element::new(channel.text_input()
-                                                  .unwrap()
-                                                  .name()
-                                                  .as_str())))
-                  .expect(errors::tag_text_error(name_tag_str).as_str());
-            writer.write(End(Element::new(name_tag_str)))
-                  .expect(errors::tag_end_error(name_tag_str).as_str());
-
-            let link_tag_str = "link";
-            let link_tag = Element::new(link_tag_str);
-            writer.write(Start(link_tag))
-                  .expect(errors::tag_start_error(link_tag_str).as_str());
-            writer.write(Text(Element::new(channel.text_input()
-                                                  .unwrap()
-                                                  .link()
-                                                  .as_str())))
-                  .expect(errors::tag_text_error(link_tag_str).as_str());
-            writer.write(End(Element::new(link_tag_str)))
-                  .expect(errors::tag_end_error(link_tag_str).as_str());
-
-            writer.write(End(Element::new(text_input_str)))
-                  .expect(errors::tag_end_error(text_input_str).as_str());
+        if let Some(title) = item.title() {
+            write_text_element(writer, "title", &title)?;
         }
 
-        if channel.skip_hours().is_some() {
-            let skip_hours_tag_str = "skipHours";
-            let skip_hours_tag = Element::new(skip_hours_tag_str);
-            writer.write(Start(skip_hours_tag))
-                  .expect(errors::tag_start_error(skip_hours_tag_str).as_str());
-            for hour in channel.skip_hours().unwrap() {
-                let hour_tag_str = "hour";
-                let hour_tag = Element::new(hour_tag_str);
-                writer.write(Start(hour_tag))
-                      .expect(errors::tag_start_error(hour_tag_str).as_str());
-                writer.write(Text(Element::new(hour.to_string().as_str())))
-                      .expect(errors::tag_text_error(hour_tag_str).as_str());
-                writer.write(End(Element::new(hour_tag_str)))
-                      .expect(errors::tag_end_error(hour_tag_str).as_str());
-            }
-            writer.write(End(Element::new(skip_hours_tag_str)))
-                  .expect(errors::tag_end_error(skip_hours_tag_str).as_str());
+        if let Some(link) = item.link() {
+            write_text_element(writer, "link", &link)?;
         }
 
-        if channel.skip_days().is_some() {
-            let skip_days_tag_str = "skipDays";
-            let skip_days_tag = Element::new(skip_days_tag_str);
-            writer.write(Start(skip_days_tag))
-                  .expect(errors::tag_start_error(skip_days_tag_str).as_str());
-            for day in channel.skip_days().unwrap() {
-                let day_tag_str = "day";
-                let day_tag = Element::new(day_tag_str);
-                writer.write(Start(day_tag))
-                      .expect(errors::tag_start_error(day_tag_str).as_str());
-                writer.write(Text(Element::new(day.as_str())))
-                      .expect(errors::tag_text_error(day_tag_str).as_str());
-                writer.write(End(Element::new(day_tag_str)))
-                      .expect(errors::tag_end_error(day_tag_str).as_str());
+        if is_1_0 {
+            if let Some(description) = item.description() {
+                write_html_element(writer, "description", &description, cdata)?;
+            }
+
+            // The Dublin Core elements of the item are written instead of the
+            // author and pub date they would repeat.
+            let extensions = item.extensions();
+            let author = item.own_author();
+            if let Some(author) = author.filter(|_| util::dublin_core_text(&extensions, "creator").is_none()) {
+                write_text_element(writer, "dc:creator", &author)?;
+            }
+
+            let (pub_date, pub_date_raw) = item.own_pub_date();
+            let pub_date = date_text(pub_date, pub_date_raw, DateTime::to_rfc3339);
+            if let Some(pub_date) = pub_date.filter(|_| util::dublin_core_text(&extensions, "date").is_none()) {
+                write_text_element(writer, "dc:date", &pub_date)?;
             }
-            writer.write(End(Element::new(skip_days_tag_str)))
-                  .expect(errors::tag_end_error(skip_days_tag_str).as_str());
+
+            write_extensions(writer, &item.extensions(), &namespaces, cdata)?;
+        }
+
+        writer.write(End(Element::new(item_tag_str)))?;
+    }
+
+    if let Some(text_input) = channel.text_input() {
+        let text_input_tag_str = "textinput";
+        let mut text_input_tag = Element::new(text_input_tag_str);
+        if is_1_0 {
+            util::push_attribute(&mut text_input_tag, "rdf:about", text_input.link().as_str());
         }
+        writer.write(Start(text_input_tag))?;
 
-        if channel.items().is_some() {
-            for item in channel.items().unwrap() {
-                let item_tag_str = "item";
-                let item_tag = Element::new(item_tag_str);
-                writer.write(Start(item_tag))
-                      .expect(errors::tag_start_error(item_tag_str).as_str());
-
-                if item.title().is_some() {
-                    let item_title_tag_str = "title";
-                    let item_title_tag = Element::new(item_title_tag_str);
-                    writer.write(Start(item_title_tag))
-                          .expect(errors::tag_start_error(item_title_tag_str).as_str());
-                    writer.write(Text(Element::new(item.title()
-                                                       .unwrap()
-                                                       .as_str())))
-                          .expect(errors::tag_text_error(item_title_tag_str).as_str());
-                    writer.write(End(Element::new(item_title_tag_str)))
-                          .expect(errors::tag_end_error(item_title_tag_str).as_str());
-                }
-
-                if item.link().is_some() {
-                    let item_link_tag_str = "link";
-                    let item_link_tag = Element::new(item_link_tag_str);
-                    writer.write(Start(item_link_tag))
-                          .expect(errors::tag_start_error(item_link_tag_str).as_str());
-                    writer.write(Text(Element::new(item.link()
-                                                       .unwrap()
-                                                       .as_str())))
-                          .expect(errors::tag_text_error(item_link_tag_str).as_str());
-                    writer.write(End(Element::new(item_link_tag_str)))
-                          .expect(errors::tag_end_error(item_link_tag_str).as_str());
-                }
-
-                if item.description().is_some() {
-                    let item_description_tag_str = "description";
-                    let item_description_tag = Element::new(item_description_tag_str);
-                    writer.write(Start(item_description_tag))
-                          .expect(errors::tag_start_error(item_description_tag_str).as_str());
-                    writer.write(Text(Element::new(item.description()
-                                                       .unwrap()
-                                                       .as_str())))
-                          .expect(errors::tag_text_error(item_description_tag_str).as_str());
-                    writer.write(End(Element::new(item_description_tag_str)))
-                          .expect(errors::tag_end_error(item_description_tag_str).as_str());
-                }
-
-                if item.author().is_some() {
-                    let item_author_tag_str = "author";
-                    let item_author_tag = Element::new(item_author_tag_str);
-                    writer.write(Start(item_author_tag))
-                          .expect(errors::tag_start_error(item_author_tag_str).as_str());
-                    writer.write(Text(Element::new(item.author()
-                                                       .unwrap()
-                                                       .as_str())))
-                          .expect(errors::tag_text_error(item_author_tag_str).as_str());
-                    writer.write(End(Element::new(item_author_tag_str)))
-                          .expect(errors::tag_end_error(item_author_tag_str).as_str());
-                }
-
-                if item.categories().is_some() {
-                    for category in item.categories().unwrap() {
-                        let item_category_tag_str = "category";
-                        let mut item_category_tag = Element::new(item_category_tag_str);
-                        if category.domain().is_some() {
-                            item_category_tag.push_attribute(b"domain",
-                                                             category.domain().unwrap().as_str());
-                        }
-                        writer.write(Start(item_category_tag))
-                              .expect(errors::tag_start_error(item_category_tag_str).as_str());
-                        writer.write(Text(Element::new(category.category())))
-                              .expect(errors::tag_text_error(item_category_tag_str).as_str());
-                        writer.write(End(Element::new(item_category_tag_str)))
-                              .expect(errors::tag_end_error(item_category_tag_str).as_str());
-                    }
-                }
-
-                if item.comments().is_some() {
-                    let item_comments_tag_str = "comments";
-                    let item_comments_tag = Element::new(item_comments_tag_str);
-                    writer.write(Start(item_comments_tag))
-                          .expect(errors::tag_start_error(item_comments_tag_str).as_str());
-                    writer.write(Text(Element::new(item.comments()
-                                                       .unwrap()
-                                                       .as_str())))
-                          .expect(errors::tag_text_error(item_comments_tag_str).as_str());
-                    writer.write(End(Element::new(item_comments_tag_str)))
-                          .expect(errors::tag_end_error(item_comments_tag_str).as_str());
-                }
-
-                if item.enclosure().is_some() {
-                    let item_enclosure_tag_str = "enclosure";
-                    let mut item_enclosure_tag = Element::new(item_enclosure_tag_str);
-                    item_enclosure_tag.push_attribute(b"url",
-                                                      item.enclosure()
-                                                          .unwrap()
-                                                          .url()
-                                                          .as_str());
-                    item_enclosure_tag.push_attribute(b"length",
-                                                      item.enclosure()
-                                                          .unwrap()
-                                                          .length()
-                                                          .to_string()
-                                                          .as_str());
-                    item_enclosure_tag.push_attribute(b"type",
-                                                      item.enclosure()
-                                                          .unwrap()
-                                                          .enclosure_type()
-                                                          .as_str());
-                    writer.write(Start(item_enclosure_tag))
-                          .expect(errors::tag_start_error(item_enclosure_tag_str).as_str());
-
-                }
-
-                if item.guid().is_some() {
-                    let item_guid_tag_str = "guid";
-                    let mut item_guid_tag = Element::new(item_guid_tag_str);
-                    item_guid_tag.push_attribute(b"isPermaLink",
-                                                 item.guid()
-                                                     .unwrap()
-                                                     .permalink()
-                                                     .to_string()
-                                                     .as_str());
-                    writer.write(Start(item_guid_tag))
-                          .expect(errors::tag_start_error(item_guid_tag_str).as_str());
-                    writer.write(Text(Element::new(item.guid().unwrap().guid().as_str())))
-                          .expect(errors::tag_text_error(item_guid_tag_str).as_str());
-                    writer.write(End(Element::new(item_guid_tag_str)))
-                          .expect(errors::tag_end_error(item_guid_tag_str).as_str());
-                }
-
-                if item.pub_date().is_some() {
-                    let item_pub_date_tag_str = "pubDate";
-                    let item_pub_date_tag = Element::new(item_pub_date_tag_str);
-                    writer.write(Start(item_pub_date_tag))
-                          .expect(errors::tag_start_error(item_pub_date_tag_str).as_str());
-                    writer.write(Text(Element::new(channel.pub_date().unwrap().to_rfc2822())))
-                          .expect(errors::tag_text_error(item_pub_date_tag_str).as_str());
-                    writer.write(End(Element::new(item_pub_date_tag_str)))
-                          .expect(errors::tag_end_error(item_pub_date_tag_str).as_str());
-                }
-
-                if item.source().is_some() {
-                    let item_source_tag_str = "source";
-                    let mut item_source_tag = Element::new(item_source_tag_str);
-                    item_source_tag.push_attribute(b"url",
-                                                   item.source()
-                                                       .unwrap()
-                                                       .url()
-                                                       .to_string()
-                                                       .as_str());
-                    writer.write(Start(item_source_tag))
-                          .expect(errors::tag_start_error(item_source_tag_str).as_str());
-                    writer.write(Text(Element::new(item.source().unwrap().source().as_str())))
-                          .expect(errors::tag_text_error(item_source_tag_str).as_str());
-                    writer.write(End(Element::new(item_source_tag_str)))
-                          .expect(errors::tag_end_error(item_source_tag_str).as_str());
-                }
-
-                writer.write(End(Element::new(item_tag_str)))
-                      .expect(errors::tag_end_error(item_tag_str).as_str());
+        write_text_element(writer, "title", &text_input.title())?;
+        write_text_element(writer, "description", &text_input.description())?;
+        write_text_element(writer, "name", &text_input.name())?;
+        write_text_element(writer, "link", &text_input.link())?;
+
+        writer.write(End(Element::new(text_input_tag_str)))?;
+    }
+
+    writer.write(End(Element::new(rdf_tag_str)))?;
+    Ok(())
+}
+
+
+// Get the uri identifying the `Item` in RSS 1.0, its guid or else its link.
+fn rdf_about(item: &Item) -> String {
+    item.guid()
+        .map(|guid| guid.guid())
+        .or_else(|| item.link())
+        .unwrap_or_default()
+}
+
+
+// Write the `Item` under the channel, in the scope of the namespaces declared
+// on the root element.
+fn write_item<W: Write>(writer: &mut XmlWriter<W>,
+                        item: &Item,
+                        version: Version,
+                        cdata: Option<&'static Encoding>,
+                        namespaces: &[(String, String)])
+                        -> Result<(), FeedError> {
+    let since_0_92 = matches!(version, Version::V0_92 | Version::V2_0);
+    let since_2_0 = version == Version::V2_0;
+
+    let item_tag_str = "item";
+    writer.write(Start(Element::new(item_tag_str)))?;
+
+    if let Some(title) = item.title() {
+        write_text_element(writer, "title", &title)?;
+    }
+
+    if let Some(link) = item.link() {
+        write_text_element(writer, "link", &link)?;
+    }
+
+    if let Some(description) = item.description() {
+        write_html_element(writer, "description", &description, cdata)?;
+    }
+
+    if let Some(author) = item.own_author().filter(|_| since_2_0) {
+        write_text_element(writer, "author", &author)?;
+    }
+
+    if let Some(categories) = item.categories().filter(|_| since_0_92) {
+        for category in categories {
+            let item_category_tag_str = "category";
+            let mut item_category_tag = Element::new(item_category_tag_str);
+            if let Some(domain) = category.domain() {
+                util::push_attribute(&mut item_category_tag, "domain", domain.as_str());
             }
+            writer.write(Start(item_category_tag))?;
+            writer.write(Text(Element::new(util::escape(&category.category()).as_bytes())))?;
+            writer.write(End(Element::new(item_category_tag_str)))?;
         }
+    }
 
-        writer.write(End(Element::new(channel_tag_str)))
-              .expect(errors::tag_end_error(channel_tag_str).as_str());
-        writer.write(End(Element::new(rss_tag_str)))
-              .expect(errors::tag_end_error(rss_tag_str).as_str());
-        FeedWriter { xml: writer.into_inner().into_inner() }
+    if let Some(comments) = item.comments().filter(|_| since_2_0) {
+        write_text_element(writer, "comments", &comments)?;
     }
 
+    if let Some(enclosure) = item.enclosure().filter(|_| since_0_92) {
+        let item_enclosure_tag_str = "enclosure";
+        let mut item_enclosure_tag = Element::new(item_enclosure_tag_str);
+        util::push_attribute(&mut item_enclosure_tag, "url", enclosure.url().as_str());
+        let length = number_text(enclosure.length(), enclosure.length_raw()).unwrap_or_else(|| "0".to_owned());
+        util::push_attribute(&mut item_enclosure_tag, "length", length.as_str());
+        util::push_attribute(&mut item_enclosure_tag, "type", enclosure.enclosure_type().as_str());
+        writer.write(Start(item_enclosure_tag))?;
+        writer.write(End(Element::new(item_enclosure_tag_str)))?;
+    }
 
-    /// Convert the `Channel` to XML.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use feed::feedio::{FeedReader, FeedWriter};
-    ///
-    /// let feed_reader = FeedReader::new("String");
-    /// let channel = feed_reader.channel();
-    ///
-    /// let xml = FeedWriter::new(channel).xml();
-    /// ```
-    pub fn xml(&self) -> Vec<u8> {
-        self.xml.clone()
+    if let Some(guid) = item.guid().filter(|_| since_2_0) {
+        let item_guid_tag_str = "guid";
+        let mut item_guid_tag = Element::new(item_guid_tag_str);
+        util::push_attribute(&mut item_guid_tag, "isPermaLink", guid.permalink().to_string().as_str());
+        writer.write(Start(item_guid_tag))?;
+        writer.write(Text(Element::new(util::escape(&guid.guid()).as_bytes())))?;
+        writer.write(End(Element::new(item_guid_tag_str)))?;
+    }
+
+    let (pub_date, pub_date_raw) = item.own_pub_date();
+    let pub_date = date_text(pub_date, pub_date_raw, DateTime::to_rfc2822);
+    if let Some(pub_date) = pub_date.filter(|_| since_2_0) {
+        write_text_element(writer, "pubDate", &pub_date)?;
+    }
+
+    if let Some(source) = item.source().filter(|_| since_0_92) {
+        let item_source_tag_str = "source";
+        let mut item_source_tag = Element::new(item_source_tag_str);
+        util::push_attribute(&mut item_source_tag, "url", source.url().as_str());
+        writer.write(Start(item_source_tag))?;
+        writer.write(Text(Element::new(util::escape(&source.source()).as_bytes())))?;
+        writer.write(End(Element::new(item_source_tag_str)))?;
+    }
+
+    if since_2_0 {
+        write_extensions(writer, &item.extensions(), namespaces, cdata)?;
+    }
+
+    writer.write(End(Element::new(item_tag_str)))?;
+    Ok(())
+}
+
+
+// Get the text of the date in the format, rfc 2822 for rss and W3CDTF, which
+// rfc 3339 dates are, for Dublin Core, or its raw text when it could not be
+// parsed.
+fn date_text(date: Option<DateTime<FixedOffset>>,
+             raw: Option<String>,
+             format: fn(&DateTime<FixedOffset>) -> String)
+             -> Option<String> {
+    date.map(|date| format(&date)).or(raw)
+}
+
+
+// Get the text of the number, or its raw text when the number is 0 and so
+// absent.
+fn number_text(number: i64, raw: Option<String>) -> Option<String> {
+    if number > 0 {
+        Some(number.to_string())
+    } else {
+        raw
+    }
+}
+
+
+// Write an element containing only text.
+fn write_text_element<W: Write>(writer: &mut XmlWriter<W>,
+                                tag: &str,
+                                text: &str)
+                                -> Result<(), FeedError> {
+    writer.write(Start(Element::new(tag)))?;
+    writer.write(Text(Element::new(util::escape(text).as_bytes())))?;
+    writer.write(End(Element::new(tag)))?;
+    Ok(())
+}
+
+
+// Write an element containing text that may be HTML, as a CDATA section when
+// `cdata` is the encoding of the document and else as escaped text.
+fn write_html_element<W: Write>(writer: &mut XmlWriter<W>,
+                                tag: &str,
+                                text: &str,
+                                cdata: Option<&'static Encoding>)
+                                -> Result<(), FeedError> {
+    if !is_cdata(cdata, text) {
+        return write_text_element(writer, tag, text);
+    }
+
+    // A `]]>` in the text ends one section and starts the next.
+    writer.write(Start(Element::new(tag)))?;
+    writer.write(CData(Element::new(text.replace("]]>", "]]]]><![CDATA[>"))))?;
+    writer.write(End(Element::new(tag)))?;
+    Ok(())
+}
+
+
+// Check whether the text is written as a CDATA section, which it is not when
+// the encoding lacks some of its characters, as their character references
+// would be read as text there.
+fn is_cdata(cdata: Option<&'static Encoding>, text: &str) -> bool {
+    cdata.is_some_and(|encoding| encoding::can_encode(encoding, text))
+}
+
+
+// Add the prefixes and namespaces of the extension elements of the `Channel`
+// and its items to the ones declared on the root element. The first namespace
+// of a prefix is declared there, an element of another namespace with the
+// same prefix declares its own.
+fn extension_namespaces(channel: &Channel, mut namespaces: Vec<(String, String)>) -> Vec<(String, String)> {
+    let items = channel.items().unwrap_or_default();
+    let maps = Some(channel.extensions()).into_iter().chain(items.iter().map(Item::extensions));
+    for extensions in maps {
+        for element in extensions.values().flatten() {
+            add_namespaces(element, &mut namespaces);
+        }
+    }
+    namespaces
+}
+
+
+// Add the prefix and namespace of the extension element and of its children.
+fn add_namespaces(element: &ExtensionElement, namespaces: &mut Vec<(String, String)>) {
+    if let Some(prefix) = element.prefix() {
+        if !namespaces.iter().any(|(declared, _)| declared == prefix) {
+            namespaces.push((prefix.to_owned(), element.namespace()));
+        }
     }
+    for child in element.children() {
+        add_namespaces(&child, namespaces);
+    }
+}
+
+
+// Add the attributes declaring the namespaces to the root element, leaving out
+// the empty default namespace.
+fn push_namespaces(tag: &mut Element, namespaces: &[(String, String)]) {
+    for (prefix, namespace) in namespaces {
+        if prefix.is_empty() && namespace.is_empty() {
+            continue;
+        }
+        util::push_attribute(tag, &xmlns(prefix), namespace);
+    }
+}
+
+
+// Get the attribute declaring the namespace of a prefix.
+fn xmlns(prefix: &str) -> String {
+    if prefix.is_empty() {
+        "xmlns".to_owned()
+    } else {
+        format!("xmlns:{}", prefix)
+    }
+}
+
+
+// Write the extension elements of a channel or an item, in the scope of the
+// namespaces declared on the root element, the full content of an item as a
+// CDATA section when `cdata` is the encoding of the document.
+fn write_extensions<W: Write>(writer: &mut XmlWriter<W>,
+                              extensions: &ExtensionMap,
+                              namespaces: &[(String, String)],
+                              cdata: Option<&'static Encoding>)
+                              -> Result<(), FeedError> {
+    let mut scope = namespaces.to_vec();
+    for element in extensions.values().flatten() {
+        write_extension_element(writer, element, &mut scope, cdata)?;
+    }
+    Ok(())
+}
+
+
+// Write an extension element and its children, declaring its namespace when
+// its prefix is bound to another one in the scope.
+fn write_extension_element<W: Write>(writer: &mut XmlWriter<W>,
+                                     element: &ExtensionElement,
+                                     scope: &mut Vec<(String, String)>,
+                                     cdata: Option<&'static Encoding>)
+                                     -> Result<(), FeedError> {
+    let len = scope.len();
+    let name = element.name();
+    let prefix = element.prefix().unwrap_or("");
+    let namespace = element.namespace();
+
+    let mut tag = Element::new(&name);
+    let bound = scope.iter()
+                     .rev()
+                     .find(|(declared, _)| declared == prefix)
+                     .map(|(_, namespace)| namespace.as_str());
+    if bound.unwrap_or("") != namespace {
+        util::push_attribute(&mut tag, &xmlns(prefix), &namespace);
+        scope.push((prefix.to_owned(), namespace.clone()));
+    }
+    for (key, value) in element.attributes() {
+        util::push_attribute(&mut tag, &key, &value);
+    }
+    writer.write(Start(tag))?;
+
+    if let Some(text) = element.text() {
+        if is_cdata(cdata, &text) && namespace == CONTENT_NAMESPACE && element.local_name() == "encoded" {
+            writer.write(CData(Element::new(text.replace("]]>", "]]]]><![CDATA[>"))))?;
+        } else {
+            writer.write(Text(Element::new(util::escape(&text).as_bytes())))?;
+        }
+    }
+    for child in element.children() {
+        write_extension_element(writer, &child, scope, cdata)?;
+    }
+
+    writer.write(End(Element::new(&name)))?;
+    scope.truncate(len);
+    Ok(())
 }
@@ -0,0 +1,118 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! Implementation of `ItemIter`.
+
+use errors::FeedError;
+use feedio::ItemIter;
+use feedio::feed_reader::ReaderState;
+use feedio::xml_state::XmlHandler;
+use quick_xml::XmlReader;
+use rss::{Channel, Item};
+use std::io::BufRead;
+
+impl<B: BufRead> ItemIter<B> {
+    /// Construct a new `ItemIter` reading the rss feed up to its first item.
+    ///
+    /// Only the item being read is kept in memory, so feeds with many
+    /// thousands of items can be processed one item at a time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::ItemIter;
+    ///
+    /// let xml = "<rss><channel><title>Title</title>".to_owned()
+    ///     + "<item><title>First</title></item>"
+    ///     + "<item><title>Second</title></item>"
+    ///     + "</channel></rss>";
+    /// let item_iter = ItemIter::new(xml.as_bytes()).unwrap();
+    /// assert_eq!("Title".to_owned(), item_iter.channel().title());
+    ///
+    /// let titles = item_iter.map(|item| item.unwrap().title().unwrap()).collect::<Vec<_>>();
+    /// assert_eq!(vec!["First".to_owned(), "Second".to_owned()], titles);
+    /// ```
+    ///
+    /// An invalid item ends the iteration with its error.
+    ///
+    /// ```
+    /// use feed::FeedError;
+    /// use feed::feedio::ItemIter;
+    ///
+    /// let xml = "<rss><channel><item><title>First</title></item>".to_owned()
+    ///     + "<item><link>http://example.com</link></item>"
+    ///     + "<item><title>Third</title></item>"
+    ///     + "</channel></rss>";
+    /// let mut item_iter = ItemIter::new(xml.as_bytes()).unwrap();
+    /// assert!(item_iter.next().unwrap().is_ok());
+    /// match item_iter.next() {
+    ///     Some(Err(FeedError::MissingElement { path, .. })) => assert_eq!("/rss/channel/item", path),
+    ///     _ => panic!("expected a missing element error"),
+    /// }
+    /// assert!(item_iter.next().is_none());
+    /// ```
+    pub fn new(reader: B) -> Result<ItemIter<B>, FeedError> {
+        let mut reader = XmlReader::from_reader(reader).trim_text(true);
+        let mut state = ReaderState::default();
+        let mut finished = false;
+        while !state.is_in_item() {
+            if !state.read_event(&mut reader)? {
+                finished = true;
+                break;
+            }
+        }
+
+        Ok(ItemIter {
+            reader,
+            channel: state.channel(),
+            state,
+            finished,
+        })
+    }
+
+
+    /// Get the `Channel` read before the first item, without any items.
+    ///
+    /// Elements of the channel that follow its items are not part of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::ItemIter;
+    ///
+    /// let xml = "<rss><channel><title>Title</title><ttl>60</ttl></channel></rss>";
+    /// let channel = ItemIter::new(xml.as_bytes()).unwrap().channel();
+    /// assert_eq!("Title".to_owned(), channel.title());
+    /// assert_eq!(Some(60), channel.ttl());
+    /// assert!(channel.items().is_none());
+    /// ```
+    pub fn channel(&self) -> Channel {
+        self.channel.clone()
+    }
+}
+
+
+impl<B: BufRead> Iterator for ItemIter<B> {
+    type Item = Result<Item, FeedError>;
+
+
+    // Read events until an item is complete, stopping after the first error.
+    fn next(&mut self) -> Option<Result<Item, FeedError>> {
+        while !self.finished {
+            match self.state.read_event(&mut self.reader) {
+                Ok(true) => {
+                    if let Some(item) = self.state.take_item() {
+                        return Some(Ok(item));
+                    }
+                }
+                Ok(false) => self.finished = true,
+                Err(e) => {
+                    self.finished = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+        None
+    }
+}
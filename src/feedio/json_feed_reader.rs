@@ -0,0 +1,323 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! Implementation of `JsonFeedReader`.
+
+use errors::FeedError;
+use feedio::JsonFeedReader;
+use rss::{Category, CategoryBuilder, Channel, ChannelBuilder, EnclosureBuilder, GuidBuilder,
+          ImageBuilder, Item, ItemBuilder};
+use serde_json::{self, Value};
+use std::io::Read;
+
+impl JsonFeedReader {
+    /// Construct a new `JsonFeedReader` and return the `Channel`.
+    ///
+    /// A feed that cannot be parsed is logged and results in an empty `Channel`,
+    /// use `JsonFeedReader::parse` to find out why it failed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::JsonFeedReader;
+    ///
+    /// let json_feed_reader = JsonFeedReader::new("String");
+    /// ```
+    pub fn new(feed: &str) -> JsonFeedReader {
+        let channel = match JsonFeedReader::parse(feed) {
+            Ok(channel) => channel,
+            Err(e) => {
+                error!("{}", e);
+                Channel::default()
+            }
+        };
+        JsonFeedReader { channel }
+    }
+
+
+    /// Parse the JSON Feed to the `Channel`.
+    ///
+    /// Versions 1.0 and 1.1 of JSON Feed are read. The `url`, `content_html`
+    /// (or `content_text`), `date_published`, first attachment, first author and
+    /// `tags` of an item become its link, description, pubDate, enclosure,
+    /// author and categories.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::JsonFeedReader;
+    ///
+    /// let json = r#"{
+    ///     "version": "https://jsonfeed.org/version/1.1",
+    ///     "title": "My Example Feed",
+    ///     "home_page_url": "https://example.org/",
+    ///     "items": [
+    ///         {
+    ///             "id": "2",
+    ///             "content_text": "This is a second item.",
+    ///             "url": "https://example.org/second-item",
+    ///             "date_published": "2010-02-07T14:04:00-05:00",
+    ///             "authors": [{ "name": "John Doe" }],
+    ///             "tags": ["example"],
+    ///             "attachments": [
+    ///                 {
+    ///                     "url": "https://example.org/second-item.m4a",
+    ///                     "mime_type": "audio/x-m4a",
+    ///                     "size_in_bytes": 89970236
+    ///                 }
+    ///             ]
+    ///         }
+    ///     ]
+    /// }"#;
+    /// let channel = JsonFeedReader::parse(json).unwrap();
+    /// assert_eq!("My Example Feed".to_owned(), channel.title());
+    /// assert_eq!("https://example.org/".to_owned(), channel.link());
+    ///
+    /// let item = channel.items().unwrap().remove(0);
+    /// assert_eq!(Some("https://example.org/second-item".to_owned()), item.link());
+    /// assert_eq!(Some("This is a second item.".to_owned()), item.description());
+    /// assert_eq!(Some("John Doe".to_owned()), item.author());
+    /// assert_eq!("example".to_owned(), item.categories().unwrap()[0].category());
+    /// assert_eq!(89970236, item.enclosure().unwrap().length());
+    /// assert_eq!("2".to_owned(), item.guid().unwrap().guid());
+    /// ```
+    ///
+    /// ```
+    /// use feed::FeedError;
+    /// use feed::feedio::JsonFeedReader;
+    ///
+    /// let json = r#"{
+    ///     "version": "https://jsonfeed.org/version/1.1",
+    ///     "title": "My Example Feed",
+    ///     "items": [{ "id": "1", "title": 42 }]
+    /// }"#;
+    /// match JsonFeedReader::parse(json) {
+    ///     Err(FeedError::Json { path, .. }) => assert_eq!("/items/0/title", path),
+    ///     _ => panic!("expected a json error"),
+    /// }
+    /// ```
+    ///
+    /// ```
+    /// use feed::feedio::JsonFeedReader;
+    ///
+    /// let json = r#"{
+    ///     "version": "https://jsonfeed.org/version/1.1",
+    ///     "title": "My Example Feed",
+    ///     "items": [{ "id": "1", "title": "Title", "date_published": "last Tuesday" }]
+    /// }"#;
+    /// let item = JsonFeedReader::parse(json).unwrap().items().unwrap().remove(0);
+    /// assert!(item.pub_date().is_none());
+    /// assert_eq!(Some("last Tuesday".to_owned()), item.pub_date_raw());
+    /// ```
+    pub fn parse(feed: &str) -> Result<Channel, FeedError> {
+        JsonFeedReader::parse_reader(feed.as_bytes())
+    }
+
+
+    /// Parse the JSON Feed read from a `Read` to the `Channel`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::JsonFeedReader;
+    /// use std::io::Cursor;
+    ///
+    /// let json = r#"{"version": "https://jsonfeed.org/version/1.1", "title": "Title"}"#;
+    /// let channel = JsonFeedReader::parse_reader(Cursor::new(json)).unwrap();
+    /// assert_eq!("Title".to_owned(), channel.title());
+    /// ```
+    pub fn parse_reader<R: Read>(reader: R) -> Result<Channel, FeedError> {
+        let feed: Value = serde_json::from_reader(reader).map_err(|e| {
+            if e.is_io() {
+                FeedError::Io { message: e.to_string() }
+            } else {
+                FeedError::Json {
+                    message: e.to_string(),
+                    path: String::new(),
+                }
+            }
+        })?;
+
+        let version = optional_str(&feed, "version", "")?;
+        if !version.is_some_and(|v| v.starts_with("https://jsonfeed.org/version/")) {
+            return Err(json_error("not a JSON Feed version", "/version"));
+        }
+
+        let mut channel_builder = ChannelBuilder::new();
+
+        let title = match optional_str(&feed, "title", "")? {
+            Some(title) => title,
+            None => return Err(json_error("missing required title", "/title")),
+        };
+        channel_builder.title(title);
+
+        let link = optional_str(&feed, "home_page_url", "")?;
+        if let Some(link) = link {
+            channel_builder.link(link);
+        }
+
+        if let Some(description) = optional_str(&feed, "description", "")? {
+            channel_builder.description(description);
+        }
+
+        let language = optional_str(&feed, "language", "")?.map(str::to_owned);
+        channel_builder.language(language);
+
+        if let Some(icon) = optional_str(&feed, "icon", "")? {
+            let image = ImageBuilder::new()
+                            .url(icon)
+                            .title(title)
+                            .link(link.unwrap_or(""))
+                            .finalize();
+            channel_builder.image(Some(image));
+        }
+
+        let mut items = Vec::new();
+        for (index, item) in optional_array(&feed, "items", "")?.iter().enumerate() {
+            items.push(read_item(item, &format!("/items/{}", index))?);
+        }
+        channel_builder.items(Some(items));
+
+        Ok(channel_builder.finalize())
+    }
+
+
+    /// Get the `Channel` after parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::JsonFeedReader;
+    ///
+    /// let json_feed_reader = JsonFeedReader::new("String");
+    /// let channel = json_feed_reader.channel();
+    /// ```
+    pub fn channel(self) -> Channel {
+        self.channel
+    }
+}
+
+
+// Read the item at `path` to the `Item`.
+fn read_item(item: &Value, path: &str) -> Result<Item, FeedError> {
+    let mut item_builder = ItemBuilder::new();
+
+    let title = optional_str(item, "title", path)?.map(str::to_owned);
+    let link = optional_str(item, "url", path)?.map(str::to_owned);
+
+    let description = match optional_str(item, "content_html", path)? {
+        Some(content) => Some(content),
+        None => {
+            match optional_str(item, "content_text", path)? {
+                Some(content) => Some(content),
+                None => optional_str(item, "summary", path)?,
+            }
+        }
+    };
+    if title.is_none() && description.is_none() {
+        return Err(json_error("item has neither a title nor a content", path));
+    }
+    item_builder.description(description.map(str::to_owned));
+
+    // The id is a string, yet some feeds use a number.
+    let id = match item.get("id") {
+        Some(Value::String(id)) => Some(id.clone()),
+        Some(Value::Number(id)) => Some(id.to_string()),
+        None | Some(Value::Null) => None,
+        Some(_) => return Err(json_error("id is not a string", &format!("{}/id", path))),
+    };
+    if let Some(id) = id {
+        let guid = GuidBuilder::new()
+                       .guid(&id)
+                       .permalink(Some(link.as_ref() == Some(&id)))
+                       .finalize();
+        item_builder.guid(Some(guid));
+    }
+
+    // A date that cannot be parsed keeps its text, as in the rss reader.
+    let date_published = optional_str(item, "date_published", path)?;
+    item_builder.pub_date(date_published.map(str::to_owned));
+
+    // JSON Feed 1.1 has a list of authors, 1.0 a single author.
+    let authors_path = format!("{}/authors/0", path);
+    let author = match optional_array(item, "authors", path)?.first() {
+        Some(author) => optional_str(author, "name", &authors_path)?,
+        None => {
+            match item.get("author") {
+                Some(author) => optional_str(author, "name", &format!("{}/author", path))?,
+                None => None,
+            }
+        }
+    };
+    item_builder.author(author.map(str::to_owned));
+
+    let mut categories: Vec<Category> = Vec::new();
+    for (index, tag) in optional_array(item, "tags", path)?.iter().enumerate() {
+        match *tag {
+            Value::String(ref tag) => categories.push(CategoryBuilder::new().category(tag).finalize()),
+            _ => return Err(json_error("tag is not a string", &format!("{}/tags/{}", path, index))),
+        }
+    }
+    item_builder.categories(Some(categories));
+
+    if let Some(attachment) = optional_array(item, "attachments", path)?.first() {
+        let attachment_path = format!("{}/attachments/0", path);
+        let mut enclosure_builder = EnclosureBuilder::new();
+
+        match optional_str(attachment, "url", &attachment_path)? {
+            Some(url) => enclosure_builder.url(url),
+            None => return Err(json_error("missing required url", &attachment_path)),
+        };
+
+        if let Some(mime_type) = optional_str(attachment, "mime_type", &attachment_path)? {
+            enclosure_builder.enclosure_type(mime_type);
+        }
+
+        match attachment.get("size_in_bytes").map(|size| (size.is_null(), size.as_i64())) {
+            None | Some((true, _)) => (),
+            Some((_, Some(length))) => {
+                enclosure_builder.length(length);
+            }
+            Some((_, None)) => {
+                return Err(json_error("size_in_bytes is not an integer",
+                                      &format!("{}/size_in_bytes", attachment_path)))
+            }
+        };
+
+        item_builder.enclosure(Some(enclosure_builder.finalize()));
+    }
+
+    item_builder.title(title);
+    item_builder.link(link);
+    Ok(item_builder.finalize())
+}
+
+
+// Get the optional string `key` of the object at `path`.
+fn optional_str<'a>(object: &'a Value, key: &str, path: &str) -> Result<Option<&'a str>, FeedError> {
+    match object.get(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(value)) => Ok(Some(value)),
+        Some(_) => Err(json_error(&format!("{} is not a string", key), &format!("{}/{}", path, key))),
+    }
+}
+
+
+// Get the optional array `key` of the object at `path`, empty when absent.
+fn optional_array<'a>(object: &'a Value, key: &str, path: &str) -> Result<&'a [Value], FeedError> {
+    match object.get(key) {
+        None | Some(Value::Null) => Ok(&[]),
+        Some(Value::Array(values)) => Ok(values),
+        Some(_) => Err(json_error(&format!("{} is not an array", key), &format!("{}/{}", path, key))),
+    }
+}
+
+
+// Construct the error for an invalid value at `path`.
+fn json_error(message: &str, path: &str) -> FeedError {
+    FeedError::Json {
+        message: message.to_owned(),
+        path: path.to_owned(),
+    }
+}
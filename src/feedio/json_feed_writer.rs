@@ -0,0 +1,158 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! Implementation of `JsonFeedWriter`.
+
+use feedio::JsonFeedWriter;
+use rss::{Channel, Item};
+use serde_json::{Map, Value};
+
+impl JsonFeedWriter {
+    /// Construct a new `JsonFeedWriter` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::JsonFeedWriter;
+    /// use feed::rss::ChannelBuilder;
+    ///
+    /// let channel = ChannelBuilder::new().title("Title").finalize();
+    /// let json_feed_writer = JsonFeedWriter::new(channel);
+    /// ```
+    pub fn new(channel: Channel) -> JsonFeedWriter {
+        JsonFeedWriter { json: Value::Object(write_channel(&channel)).to_string() }
+    }
+
+
+    /// Convert the `Channel` to JSON Feed 1.1.
+    ///
+    /// The link, description, pubDate, enclosure, author and categories of an
+    /// item are written as its `url`, `content_html`, `date_published`,
+    /// `attachments`, `authors` and `tags`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::{JsonFeedReader, JsonFeedWriter};
+    /// use feed::rss::{CategoryBuilder, ChannelBuilder, EnclosureBuilder, ItemBuilder};
+    ///
+    /// let enclosure = EnclosureBuilder::new()
+    ///     .url("https://example.org/second-item.m4a")
+    ///     .length(89970236)
+    ///     .enclosure_type("audio/x-m4a")
+    ///     .finalize();
+    /// let category = CategoryBuilder::new().category("example").finalize();
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Second item".to_owned()))
+    ///     .link(Some("https://example.org/second-item".to_owned()))
+    ///     .description(Some("<p>This is a second item.</p>".to_owned()))
+    ///     .pub_date(Some("Sun, 07 Feb 2010 14:04:00 -0500".to_owned()))
+    ///     .author(Some("John Doe".to_owned()))
+    ///     .categories(Some(vec![category]))
+    ///     .enclosure(Some(enclosure))
+    ///     .finalize();
+    /// let channel = ChannelBuilder::new()
+    ///     .title("My Example Feed")
+    ///     .link("https://example.org/")
+    ///     .items(Some(vec![item]))
+    ///     .finalize();
+    ///
+    /// let json = JsonFeedWriter::new(channel).json();
+    /// assert!(json.contains("\"version\":\"https://jsonfeed.org/version/1.1\""));
+    ///
+    /// let channel = JsonFeedReader::parse(&json).unwrap();
+    /// assert_eq!("My Example Feed".to_owned(), channel.title());
+    ///
+    /// let item = channel.items().unwrap().remove(0);
+    /// assert_eq!(Some("Second item".to_owned()), item.title());
+    /// assert_eq!(Some("<p>This is a second item.</p>".to_owned()), item.description());
+    /// assert_eq!("2010-02-07T14:04:00-05:00".to_owned(), item.pub_date().unwrap().to_rfc3339());
+    /// assert_eq!(Some("John Doe".to_owned()), item.author());
+    /// assert_eq!("audio/x-m4a".to_owned(), item.enclosure().unwrap().enclosure_type());
+    /// ```
+    pub fn json(&self) -> String {
+        self.json.clone()
+    }
+}
+
+
+// Write the `Channel` as a JSON Feed object.
+fn write_channel(channel: &Channel) -> Map<String, Value> {
+    let mut feed = Map::new();
+    feed.insert("version".to_owned(), Value::from("https://jsonfeed.org/version/1.1"));
+    feed.insert("title".to_owned(), Value::from(channel.title()));
+
+    if !channel.link().is_empty() {
+        feed.insert("home_page_url".to_owned(), Value::from(channel.link()));
+    }
+
+    if !channel.description().is_empty() {
+        feed.insert("description".to_owned(), Value::from(channel.description()));
+    }
+
+    if let Some(language) = channel.language() {
+        feed.insert("language".to_owned(), Value::from(language));
+    }
+
+    if let Some(image) = channel.image() {
+        feed.insert("icon".to_owned(), Value::from(image.url()));
+    }
+
+    let items = channel.items().unwrap_or_default();
+    let items = items.iter().map(|item| Value::Object(write_item(item))).collect::<Vec<_>>();
+    feed.insert("items".to_owned(), Value::Array(items));
+    feed
+}
+
+
+// Write the `Item` as a JSON Feed item, its id is the guid, link or title.
+fn write_item(item: &Item) -> Map<String, Value> {
+    let mut object = Map::new();
+
+    let id = item.guid()
+                 .map(|guid| guid.guid())
+                 .or_else(|| item.link())
+                 .or_else(|| item.title())
+                 .unwrap_or_default();
+    object.insert("id".to_owned(), Value::from(id));
+
+    if let Some(link) = item.link() {
+        object.insert("url".to_owned(), Value::from(link));
+    }
+
+    if let Some(title) = item.title() {
+        object.insert("title".to_owned(), Value::from(title));
+    }
+
+    if let Some(description) = item.description() {
+        object.insert("content_html".to_owned(), Value::from(description));
+    }
+
+    if let Some(pub_date) = item.pub_date() {
+        object.insert("date_published".to_owned(), Value::from(pub_date.to_rfc3339()));
+    }
+
+    if let Some(author) = item.author() {
+        let mut person = Map::new();
+        person.insert("name".to_owned(), Value::from(author));
+        object.insert("authors".to_owned(), Value::Array(vec![Value::Object(person)]));
+    }
+
+    if let Some(categories) = item.categories().filter(|categories| !categories.is_empty()) {
+        let tags = categories.iter().map(|category| Value::from(category.category())).collect();
+        object.insert("tags".to_owned(), Value::Array(tags));
+    }
+
+    if let Some(enclosure) = item.enclosure() {
+        let mut attachment = Map::new();
+        attachment.insert("url".to_owned(), Value::from(enclosure.url()));
+        attachment.insert("mime_type".to_owned(), Value::from(enclosure.enclosure_type()));
+        if enclosure.length() > 0 {
+            attachment.insert("size_in_bytes".to_owned(), Value::from(enclosure.length()));
+        }
+        object.insert("attachments".to_owned(), Value::Array(vec![Value::Object(attachment)]));
+    }
+
+    object
+}
@@ -4,10 +4,35 @@
 
 //! The feed can be converted to xml.
 
+pub mod atom_reader;
+pub mod atom_writer;
 pub mod feed_reader;
 pub mod feed_writer;
+pub mod item_iter;
+pub mod json_feed_reader;
+pub mod json_feed_writer;
+pub mod writer_options;
+pub mod writer_options_builder;
+mod xml_state;
+
+use atom::Feed;
+use feedio::feed_reader::ReaderState;
+use quick_xml::XmlReader;
+use rss::{Channel, Version};
+use std::io::BufRead;
+
+/// This `AtomReader` struct parses the atom feed to the `Feed`.
+pub struct AtomReader {
+    feed: Feed,
+}
+
+
+/// This `AtomWriter` struct creates the atom xml from the `Feed`.
+#[derive(Default)]
+pub struct AtomWriter {
+    xml: Vec<u8>,
+}
 
-use rss::Channel;
 
 /// This `FeedReader` struct parses the xml feed to the `Channel`.
 pub struct FeedReader {
@@ -20,3 +45,44 @@ pub struct FeedReader {
 pub struct FeedWriter {
     xml: Vec<u8>,
 }
+
+
+/// This `ItemIter` struct reads the items of a rss feed one at a time.
+pub struct ItemIter<B: BufRead> {
+    reader: XmlReader<B>,
+    state: ReaderState,
+    channel: Channel,
+    finished: bool,
+}
+
+
+/// This `JsonFeedReader` struct parses the JSON Feed to the `Channel`.
+pub struct JsonFeedReader {
+    channel: Channel,
+}
+
+
+/// This `JsonFeedWriter` struct creates the JSON Feed from the `Channel`.
+#[derive(Default)]
+pub struct JsonFeedWriter {
+    json: String,
+}
+
+
+/// This `WriterOptions` struct contains how the `FeedWriter` writes the
+/// `Channel`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WriterOptions {
+    version: Option<Version>,
+    encoding: Option<String>,
+    cdata: bool,
+}
+
+
+/// This `WriterOptionsBuilder` struct creates the `WriterOptions`.
+#[derive(Default)]
+pub struct WriterOptionsBuilder {
+    version: Option<Version>,
+    encoding: Option<String>,
+    cdata: bool,
+}
@@ -0,0 +1,86 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under writer options can be retrieved by using the methods under
+//! `WriterOptions`.
+
+use feedio::WriterOptions;
+use rss::Version;
+
+impl WriterOptions {
+    /// Get the version of rss that exists under `WriterOptions`, the version
+    /// of the `Channel` being written when it is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::WriterOptionsBuilder;
+    /// use feed::rss::Version;
+    ///
+    /// let options = WriterOptionsBuilder::new()
+    ///     .version(Some(Version::V0_92))
+    ///     .finalize();
+    /// assert_eq!(Some(Version::V0_92), options.version());
+    /// ```
+    ///
+    /// ```
+    /// use feed::feedio::WriterOptionsBuilder;
+    ///
+    /// let options = WriterOptionsBuilder::new().finalize();
+    /// assert!(options.version().is_none());
+    /// ```
+    pub fn version(&self) -> Option<Version> {
+        self.version
+    }
+
+
+    /// Get the label of the character encoding that exists under
+    /// `WriterOptions`, utf-8 being written when it is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::WriterOptionsBuilder;
+    ///
+    /// let options = WriterOptionsBuilder::new()
+    ///     .encoding(Some("ISO-8859-1".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(Some("ISO-8859-1".to_owned()), options.encoding());
+    /// ```
+    ///
+    /// ```
+    /// use feed::feedio::WriterOptionsBuilder;
+    ///
+    /// let options = WriterOptionsBuilder::new().finalize();
+    /// assert!(options.encoding().is_none());
+    /// ```
+    pub fn encoding(&self) -> Option<String> {
+        self.encoding.clone()
+    }
+
+
+    /// Get whether the fields that may contain HTML are written as CDATA
+    /// sections under `WriterOptions`, rather than as escaped text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::WriterOptionsBuilder;
+    ///
+    /// let options = WriterOptionsBuilder::new()
+    ///     .cdata(true)
+    ///     .finalize();
+    /// assert!(options.cdata());
+    /// ```
+    ///
+    /// ```
+    /// use feed::feedio::WriterOptionsBuilder;
+    ///
+    /// let options = WriterOptionsBuilder::new().finalize();
+    /// assert!(!options.cdata());
+    /// ```
+    pub fn cdata(&self) -> bool {
+        self.cdata
+    }
+}
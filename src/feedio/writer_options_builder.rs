@@ -0,0 +1,98 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for writer options by using the methods under
+//! `WriterOptionsBuilder`.
+
+use feedio::{WriterOptions, WriterOptionsBuilder};
+use rss::Version;
+
+impl WriterOptionsBuilder {
+    /// Construct a new `WriterOptionsBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::WriterOptionsBuilder;
+    ///
+    /// let options_builder = WriterOptionsBuilder::new();
+    /// ```
+    pub fn new() -> WriterOptionsBuilder {
+        WriterOptionsBuilder::default()
+    }
+
+
+    /// Set the version of rss that exists under `WriterOptions`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::WriterOptionsBuilder;
+    /// use feed::rss::Version;
+    ///
+    /// let mut options_builder = WriterOptionsBuilder::new();
+    /// options_builder.version(Some(Version::V1_0));
+    /// ```
+    pub fn version(&mut self, version: Option<Version>) -> &mut WriterOptionsBuilder {
+        self.version = version;
+        self
+    }
+
+
+    /// Set the label of the character encoding that exists under
+    /// `WriterOptions`, such as `ISO-8859-1`, `windows-1252` or `UTF-16`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::WriterOptionsBuilder;
+    ///
+    /// let mut options_builder = WriterOptionsBuilder::new();
+    /// options_builder.encoding(Some("UTF-16".to_owned()));
+    /// ```
+    pub fn encoding(&mut self, encoding: Option<String>) -> &mut WriterOptionsBuilder {
+        self.encoding = encoding;
+        self
+    }
+
+
+    /// Set whether the fields that may contain HTML are written as CDATA
+    /// sections under `WriterOptions`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::WriterOptionsBuilder;
+    ///
+    /// let mut options_builder = WriterOptionsBuilder::new();
+    /// options_builder.cdata(true);
+    /// ```
+    pub fn cdata(&mut self, cdata: bool) -> &mut WriterOptionsBuilder {
+        self.cdata = cdata;
+        self
+    }
+
+
+    /// Construct the `WriterOptions` from the `WriterOptionsBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::WriterOptionsBuilder;
+    /// use feed::rss::Version;
+    ///
+    /// let options = WriterOptionsBuilder::new()
+    ///         .version(Some(Version::V2_0))
+    ///         .encoding(Some("UTF-8".to_owned()))
+    ///         .cdata(true)
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> WriterOptions {
+        WriterOptions {
+            version: self.version,
+            encoding: self.encoding.clone(),
+            cdata: self.cdata,
+        }
+    }
+}
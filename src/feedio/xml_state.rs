@@ -0,0 +1,191 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The state of a xml document being read, shared by `FeedReader`, `ItemIter`
+//! and `AtomReader`.
+
+use errors::FeedError;
+use extension::{ATOM_NAMESPACE, CONTENT_NAMESPACE, DUBLIN_CORE_NAMESPACE, ITUNES_NAMESPACE,
+                MEDIA_NAMESPACE, PODCAST_NAMESPACE};
+use quick_xml::{Element, Event, XmlReader};
+use std::borrow::Cow;
+use std::io::BufRead;
+use std::str;
+use util;
+
+pub(crate) const RDF_NAMESPACE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+
+// The namespaces of prefixes that feeds often use without declaring them.
+const WELL_KNOWN_PREFIXES: [(&str, &str); 7] = [("atom", ATOM_NAMESPACE),
+                                                ("content", CONTENT_NAMESPACE),
+                                                ("dc", DUBLIN_CORE_NAMESPACE),
+                                                ("itunes", ITUNES_NAMESPACE),
+                                                ("media", MEDIA_NAMESPACE),
+                                                ("podcast", PODCAST_NAMESPACE),
+                                                ("rdf", RDF_NAMESPACE)];
+
+// The path, the namespaces in scope and the text of the element being read.
+#[derive(Default)]
+pub(crate) struct XmlState {
+    // The text and CDATA sections of the element being read, with the
+    // position they start at.
+    text: Option<(usize, String)>,
+    // The prefixes and namespaces declared by each element being read.
+    namespaces: Vec<Vec<(String, String)>>,
+    // The qualified names of the elements being read, the outermost first.
+    path: Vec<String>,
+}
+
+
+impl XmlState {
+    // Get the number of elements being read, 1 for the root element.
+    pub(crate) fn depth(&self) -> usize {
+        self.path.len()
+    }
+
+
+    // Get the namespace of a prefix, the empty prefix being the default
+    // namespace. An undeclared prefix is its own namespace, unless it is one
+    // that feeds often forget to declare.
+    pub(crate) fn namespace(&self, prefix: &str) -> Option<String> {
+        let declared = self.namespaces
+                           .iter()
+                           .rev()
+                           .flat_map(|declarations| declarations.iter().rev())
+                           .find(|(declared, _)| declared == prefix);
+        match declared {
+            Some((_, namespace)) if namespace.is_empty() => None,
+            Some((_, namespace)) => Some(namespace.clone()),
+            None if prefix.is_empty() => None,
+            None => {
+                let namespace = WELL_KNOWN_PREFIXES.iter()
+                                                   .find(|&&(known, _)| known == prefix)
+                                                   .map_or(prefix, |&(_, namespace)| namespace);
+                Some(namespace.to_owned())
+            }
+        }
+    }
+
+
+    // Get the namespace and the local name of the qualified name of an element.
+    pub(crate) fn resolve<'a>(&self, qualified_name: &'a str) -> (Option<String>, &'a str) {
+        let (prefix, local_name) = qualified_name.split_once(':').unwrap_or(("", qualified_name));
+        (self.namespace(prefix), local_name)
+    }
+
+
+    // Add text, decoding its entities, or a CDATA section to the text of the
+    // element being read.
+    pub(crate) fn push_text(&mut self,
+                            position: usize,
+                            e: &Element,
+                            is_text: bool)
+                            -> Result<(), FeedError> {
+        let content = str::from_utf8(e.content())?;
+        let content = if is_text { util::unescape(content) } else { Cow::Borrowed(content) };
+        self.text.get_or_insert_with(|| (position, String::new())).1.push_str(&content);
+        Ok(())
+    }
+
+
+    // Enter an element, reading the namespaces it declares and returning its
+    // other attributes.
+    fn start(&mut self, e: &Element) -> Result<Vec<(String, String)>, FeedError> {
+        self.path.push(String::from_utf8_lossy(e.name()).into_owned());
+
+        let (declarations, attributes) = util::attributes_to_vec(e.attributes())?
+            .into_iter()
+            .partition::<Vec<_>, _>(|(key, _)| key == "xmlns" || key.starts_with("xmlns:"));
+        let declarations = declarations.into_iter()
+                                       .map(|(key, namespace)| {
+                                           (key.trim_start_matches("xmlns").trim_start_matches(':').to_owned(),
+                                            namespace)
+                                       })
+                                       .collect();
+        self.namespaces.push(declarations);
+        Ok(attributes)
+    }
+
+
+    // Leave the element being read.
+    fn end(&mut self) {
+        self.namespaces.pop();
+        self.path.pop();
+    }
+}
+
+
+// A reader of the elements of a xml document, whose events are read through
+// its `XmlState`.
+pub(crate) trait XmlHandler {
+    // Get the state of the document being read.
+    fn xml(&self) -> &XmlState;
+
+
+    // Get the state of the document being read to change it.
+    fn xml_mut(&mut self) -> &mut XmlState;
+
+
+    // Read the document type declaration.
+    fn doctype(&mut self, _e: &Element) -> Result<(), FeedError> {
+        Ok(())
+    }
+
+
+    // Read a start tag, its namespace declarations being read already and
+    // left out of its attributes.
+    fn start(&mut self, e: &Element, attributes: Vec<(String, String)>) -> Result<(), FeedError>;
+
+
+    // Add text, decoding its entities, or a CDATA section to the text of the
+    // element being read.
+    fn push_text(&mut self,
+                 position: usize,
+                 e: &Element,
+                 is_text: bool)
+                 -> Result<(), FeedError> {
+        self.xml_mut().push_text(position, e, is_text)
+    }
+
+
+    // Read the text of the element being read.
+    fn text(&mut self, text: String) -> Result<(), FeedError>;
+
+
+    // Finish the element being read, which is left right after.
+    fn end(&mut self, e: &Element) -> Result<(), FeedError>;
+
+
+    // Read the next event of the document, returning false at the end of it.
+    // Errors get the position and path they occurred at.
+    fn read_event<B: BufRead>(&mut self, reader: &mut XmlReader<B>) -> Result<bool, FeedError> {
+        let position = reader.buffer_position();
+        let event = match reader.next() {
+            None => return Ok(false),
+            Some(Ok(event)) => event,
+            Some(Err((e, pos))) => return Err(FeedError::from(e).at(pos, &self.xml().path)),
+        };
+        if let Event::Start(_) | Event::End(_) = event {
+            if let Some((position, text)) = self.xml_mut().text.take() {
+                self.text(text).map_err(|err| err.at(position, &self.xml().path))?;
+            }
+        }
+
+        let result = match event {
+            Event::Start(ref e) if e.name() == b"!DOCTYPE" => self.doctype(e),
+            Event::Start(ref e) => {
+                self.xml_mut().start(e).and_then(|attributes| self.start(e, attributes))
+            }
+            Event::Text(ref e) => self.push_text(position, e, true),
+            Event::CData(ref e) => self.push_text(position, e, false),
+            Event::End(ref e) => self.end(e),
+            _ => Ok(()),
+        };
+        result.map_err(|err| err.at(position, &self.xml().path))?;
+        if let Event::End(_) = event {
+            self.xml_mut().end();
+        }
+        Ok(true)
+    }
+}
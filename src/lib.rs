@@ -21,11 +21,14 @@
 //! extern crate feed;
 //! ```
 //!
+//! The optional `serde` feature implements `Serialize` and `Deserialize` for
+//! `Feed` and the rss and atom structs, with dates as RFC 3339 strings.
+//!
 //! ## Examples
 //!
 //! ### Reading Feeds
 //!
-//! ```
+//! ```no_run
 //! extern crate feed;
 //! extern crate url;
 //!
@@ -71,34 +74,253 @@
 
 #![deny(missing_docs)]
 
+pub mod atom;
+pub mod date;
+pub mod extension;
 pub mod feedio;
+pub mod model;
 pub mod rss;
 mod util;
 mod errors;
 
+pub use errors::FeedError;
+
 extern crate chrono;
 extern crate curl;
+extern crate encoding_rs;
+extern crate encoding_rs_io;
 extern crate quick_xml;
+#[cfg(feature = "serde")]
+extern crate serde;
+extern crate serde_json;
 extern crate url;
 
 #[macro_use]
 extern crate log;
 
+use chrono::{DateTime, FixedOffset};
 use curl::http;
-use feedio::{FeedReader, FeedWriter};
+use feedio::{AtomReader, AtomWriter, FeedReader, FeedWriter, JsonFeedReader, JsonFeedWriter};
+use model::convert;
 use rss::Channel;
-use std::str;
+use std::fs::File;
+use encoding_rs::UTF_8;
+use encoding_rs_io::DecodeReaderBytesBuilder;
+use std::io::{BufReader, Cursor, Read, Write};
+use std::path::Path;
 use url::Url;
+use util::encoding;
+
+/// This `Source` enum contains the format specific struct a `Feed` was read from.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Source {
+    /// A RSS 0.90, 0.91, 0.92, 1.0 or 2.0 feed.
+    Rss(Channel),
+    /// An Atom 1.0 feed.
+    Atom(atom::Feed),
+    /// A JSON Feed 1.0 or 1.1 feed, read into a rss `Channel` and so not
+    /// kept in full. The user_comment, feed_url, favicon, next_url, hubs,
+    /// authors and expired of the feed are left out, and of an item its
+    /// external_url, image, banner_image, date_modified, language, the
+    /// content_text or summary when it has content_html, the authors after the
+    /// first and their url and avatar, the attachments after the first and
+    /// their title and duration. Extension members starting with `_` are left
+    /// out as well. The feed is written back as rss by `Feed::write_to` and as
+    /// JSON Feed by `Feed::to_json`.
+    JsonFeed(Channel),
+}
+
+
+impl Default for Source {
+    fn default() -> Source {
+        Source::Rss(Channel::default())
+    }
+}
+
 
 /// This `Feed` struct contains all the items that exist for the feeds.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Feed {
-    channel: Channel,
+    source: Source,
 }
 
 
 impl Feed {
-    /// Get the `Channel` that exists under `Feed`.
+    /// Parse a feed of any format, sniffing it by its root element: `rss` or
+    /// `rdf:RDF` for rss, `feed` for atom, or a json object for JSON Feed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{Feed, Source};
+    ///
+    /// let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+    /// <feed xmlns="http://www.w3.org/2005/Atom">
+    ///     <title>Example Feed</title>
+    ///     <link href="http://example.org/"/>
+    ///     <updated>2003-12-13T18:30:02Z</updated>
+    ///     <author><name>John Doe</name></author>
+    ///     <id>urn:uuid:60a76c80-d399-11d9-b93C-0003939e0af6</id>
+    ///     <entry>
+    ///         <title>Atom-Powered Robots Run Amok</title>
+    ///         <link href="http://example.org/2003/12/13/atom03"/>
+    ///         <link rel="enclosure" type="audio/mpeg" length="1337"
+    ///               href="http://example.org/audio/ph34r_my_podcast.mp3"/>
+    ///         <id>urn:uuid:1225c695-cfb8-4ebb-aaaa-80da344efa6a</id>
+    ///         <updated>2003-12-13T18:30:02Z</updated>
+    ///         <summary>Some text.</summary>
+    ///     </entry>
+    /// </feed>"#;
+    /// let feed = Feed::parse(xml.as_bytes()).unwrap();
+    /// assert_eq!("Example Feed".to_owned(), feed.title());
+    /// assert_eq!("John Doe".to_owned(), feed.authors()[0].name());
+    ///
+    /// let entry = feed.entries().remove(0);
+    /// assert_eq!(Some("Atom-Powered Robots Run Amok".to_owned()), entry.title());
+    /// assert_eq!(Some("Some text.".to_owned()), entry.summary());
+    /// assert_eq!(Some(1337), entry.media()[0].length());
+    ///
+    /// match feed.source() {
+    ///     Source::Atom(feed) => assert_eq!(2, feed.entries().unwrap()[0].links().unwrap().len()),
+    ///     _ => panic!("expected an atom feed"),
+    /// }
+    /// ```
+    ///
+    /// ```
+    /// use feed::{Feed, FeedError};
+    ///
+    /// let feed = Feed::parse(b"<rss><channel><title>Title</title></channel></rss>").unwrap();
+    /// assert_eq!("Title".to_owned(), feed.title());
+    ///
+    /// let json = br#"{"version": "https://jsonfeed.org/version/1.1", "title": "Title"}"#;
+    /// assert_eq!("Title".to_owned(), Feed::parse(json).unwrap().title());
+    ///
+    /// match Feed::parse(b"<html><body/></html>") {
+    ///     Err(FeedError::UnknownFormat { element, .. }) => assert_eq!("html", element),
+    ///     _ => panic!("expected an unknown format error"),
+    /// }
+    /// ```
+    pub fn parse(feed: &[u8]) -> Result<Feed, FeedError> {
+        Feed::parse_reader(feed)
+    }
+
+
+    /// Parse a feed of any format read from a `Read`, sniffing it by the start
+    /// of the document as `Feed::parse` does.
+    ///
+    /// The document is parsed while it is read, so it does not need to be
+    /// valid utf-8 or even complete before parsing starts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::Feed;
+    /// use std::io::Cursor;
+    ///
+    /// let xml = Cursor::new("<rss><channel><title>Title</title></channel></rss>");
+    /// let feed = Feed::parse_reader(xml).unwrap();
+    /// assert_eq!("Title".to_owned(), feed.title());
+    /// ```
+    pub fn parse_reader<R: Read>(reader: R) -> Result<Feed, FeedError> {
+        Feed::parse_reader_with_charset(reader, None)
+    }
+
+
+    /// Parse a feed of any format read from a `Read`, in the character
+    /// encoding of its byte order mark, else the charset, such as the one of
+    /// the content type it was served with, else its xml declaration.
+    ///
+    /// Feeds in other encodings than utf-8, such as ISO-8859-1, Windows-1252
+    /// or UTF-16, are converted to utf-8 while they are read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::Feed;
+    ///
+    /// let xml = b"<?xml version=\"1.0\" encoding=\"ISO-8859-1\"?>\
+    ///             <rss><channel><title>Caf\xE9</title></channel></rss>";
+    /// let feed = Feed::parse_reader_with_charset(&xml[..], None).unwrap();
+    /// assert_eq!("Caf\u{E9}".to_owned(), feed.title());
+    ///
+    /// let xml = b"<rss><channel><title>\xCC\xE8\xF0</title></channel></rss>";
+    /// let feed = Feed::parse_reader_with_charset(&xml[..], Some("windows-1251")).unwrap();
+    /// assert_eq!("\u{41C}\u{438}\u{440}".to_owned(), feed.title());
+    ///
+    /// let xml = "\u{FEFF}<rss><channel><title>Title</title></channel></rss>"
+    ///     .encode_utf16()
+    ///     .flat_map(u16::to_le_bytes)
+    ///     .collect::<Vec<u8>>();
+    /// let feed = Feed::parse_reader_with_charset(&xml[..], Some("ISO-8859-1")).unwrap();
+    /// assert_eq!("Title".to_owned(), feed.title());
+    /// ```
+    pub fn parse_reader_with_charset<R: Read>(mut reader: R,
+                                              charset: Option<&str>)
+                                              -> Result<Feed, FeedError> {
+        let prefix = util::read_prefix(&mut reader)?;
+        let encoding = encoding::detect(&prefix, charset);
+        let reader = Cursor::new(prefix).chain(reader);
+        if encoding == UTF_8 {
+            Feed::parse_utf8(reader)
+        } else {
+            Feed::parse_utf8(DecodeReaderBytesBuilder::new()
+                                 .encoding(Some(encoding))
+                                 .bom_override(true)
+                                 .strip_bom(true)
+                                 .build(reader))
+        }
+    }
+
+
+    // Parse a utf-8 feed of any format, sniffing it by the start of the document.
+    fn parse_utf8<R: Read>(mut reader: R) -> Result<Feed, FeedError> {
+        let prefix = util::read_prefix(&mut reader)?;
+        let reader = BufReader::new(util::strip_bom(&prefix).chain(reader));
+        let source = if util::is_json(&prefix) {
+            Source::JsonFeed(JsonFeedReader::parse_reader(reader)?)
+        } else {
+            match util::root_element_name(&prefix) {
+                Some(name) if util::is_rss_root(name) => {
+                    Source::Rss(FeedReader::parse_reader(reader)?)
+                }
+                Some(name) if util::is_atom_root(name) => {
+                    Source::Atom(AtomReader::parse_reader(reader)?)
+                }
+                name => {
+                    return Err(FeedError::UnknownFormat {
+                        element: String::from_utf8_lossy(name.unwrap_or_default()).into_owned(),
+                        position: 0,
+                        path: String::new(),
+                    })
+                }
+            }
+        };
+        Ok(Feed { source })
+    }
+
+
+    /// Get the format specific struct the `Feed` was read from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{FeedBuilder, Source};
+    ///
+    /// let feed = FeedBuilder::new().finalize();
+    /// match feed.source() {
+    ///     Source::Rss(channel) => assert_eq!("".to_owned(), channel.title()),
+    ///     _ => panic!("expected a rss feed"),
+    /// }
+    /// ```
+    pub fn source(&self) -> Source {
+        self.source.clone()
+    }
+
+
+    /// Get the `Channel` that exists under `Feed`, an atom feed is converted
+    /// to a `Channel`.
     ///
     /// # Examples
     ///
@@ -110,11 +332,197 @@ impl Feed {
     /// let channel = feed.channel();
     /// ```
     pub fn channel(self) -> Channel {
-        self.channel
+        match self.source {
+            Source::Rss(channel) | Source::JsonFeed(channel) => channel,
+            Source::Atom(feed) => convert::atom_feed_to_channel(&feed),
+        }
+    }
+
+
+    /// Get the title of the `Feed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::FeedBuilder;
+    /// use feed::rss::ChannelBuilder;
+    ///
+    /// let channel = ChannelBuilder::new().title("Title").finalize();
+    /// let feed = FeedBuilder::new().channel(channel).finalize();
+    /// assert_eq!("Title".to_owned(), feed.title());
+    /// ```
+    pub fn title(&self) -> String {
+        match self.source {
+            Source::Rss(ref channel) | Source::JsonFeed(ref channel) => channel.title(),
+            Source::Atom(ref feed) => feed.title(),
+        }
+    }
+
+
+    /// Get the description of the `Feed`, the atom subtitle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::FeedBuilder;
+    /// use feed::rss::ChannelBuilder;
+    ///
+    /// let channel = ChannelBuilder::new().description("Description").finalize();
+    /// let feed = FeedBuilder::new().channel(channel).finalize();
+    /// assert_eq!(Some("Description".to_owned()), feed.description());
+    /// ```
+    pub fn description(&self) -> Option<String> {
+        match self.source {
+            Source::Rss(ref channel) | Source::JsonFeed(ref channel) => {
+                Some(channel.description()).filter(|description| !description.is_empty())
+            }
+            Source::Atom(ref feed) => feed.subtitle(),
+        }
+    }
+
+
+    /// Get the links of the `Feed`, the rss link is an alternate link.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::FeedBuilder;
+    /// use feed::rss::ChannelBuilder;
+    ///
+    /// let channel = ChannelBuilder::new().link("http://example.org/").finalize();
+    /// let feed = FeedBuilder::new().channel(channel).finalize();
+    /// let link = feed.links().remove(0);
+    /// assert_eq!("http://example.org/".to_owned(), link.href());
+    /// assert_eq!(Some("alternate".to_owned()), link.rel());
+    /// ```
+    pub fn links(&self) -> Vec<model::Link> {
+        match self.source {
+            Source::Rss(ref channel) | Source::JsonFeed(ref channel) => {
+                Some(channel.link())
+                    .filter(|link| !link.is_empty())
+                    .map(|link| vec![convert::alternate_link(&link)])
+                    .unwrap_or_default()
+            }
+            Source::Atom(ref feed) => {
+                feed.links().unwrap_or_default().iter().map(convert::atom_link_to_link).collect()
+            }
+        }
+    }
+
+
+    /// Get the authors of the `Feed`, the rss managingEditor is its author.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::FeedBuilder;
+    /// use feed::rss::ChannelBuilder;
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .managing_editor(Some("editor@example.org".to_owned()))
+    ///     .finalize();
+    /// let feed = FeedBuilder::new().channel(channel).finalize();
+    /// assert_eq!("editor@example.org".to_owned(), feed.authors()[0].name());
+    /// ```
+    pub fn authors(&self) -> Vec<model::Person> {
+        match self.source {
+            Source::Rss(ref channel) | Source::JsonFeed(ref channel) => {
+                channel.managing_editor()
+                       .map(|editor| vec![model::PersonBuilder::new().name(&editor).finalize()])
+                       .unwrap_or_default()
+            }
+            Source::Atom(ref feed) => convert::atom_persons_to_persons(feed.authors()),
+        }
+    }
+
+
+    /// Get the date the `Feed` was last updated, the rss lastBuildDate or pubDate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::FeedBuilder;
+    /// use feed::rss::ChannelBuilder;
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .pub_date(Some("Sun, 13 Mar 2016 20:02:02 -0700".to_owned()))
+    ///     .finalize();
+    /// let feed = FeedBuilder::new().channel(channel).finalize();
+    /// assert_eq!("Sun, 13 Mar 2016 20:02:02 -0700".to_owned(),
+    ///            feed.updated().unwrap().to_rfc2822());
+    /// ```
+    pub fn updated(&self) -> Option<DateTime<FixedOffset>> {
+        match self.source {
+            Source::Rss(ref channel) | Source::JsonFeed(ref channel) => {
+                channel.last_build_date().or_else(|| channel.pub_date())
+            }
+            Source::Atom(ref feed) => feed.updated(),
+        }
+    }
+
+
+    /// Get the entries of the `Feed`, the rss items or atom entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::FeedBuilder;
+    /// use feed::rss::{ChannelBuilder, EnclosureBuilder, ItemBuilder};
+    ///
+    /// let enclosure = EnclosureBuilder::new()
+    ///     .url("http://example.org/episode.ogg")
+    ///     .enclosure_type("audio/ogg")
+    ///     .finalize();
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Episode".to_owned()))
+    ///     .link(Some("http://example.org/episode".to_owned()))
+    ///     .enclosure(Some(enclosure))
+    ///     .finalize();
+    /// let channel = ChannelBuilder::new().items(Some(vec![item])).finalize();
+    /// let feed = FeedBuilder::new().channel(channel).finalize();
+    ///
+    /// let entry = feed.entries().remove(0);
+    /// assert_eq!(Some("Episode".to_owned()), entry.title());
+    /// assert_eq!("http://example.org/episode".to_owned(), entry.links()[0].href());
+    /// assert_eq!(Some("audio/ogg".to_owned()), entry.media()[0].media_type());
+    /// ```
+    ///
+    /// ```
+    /// use feed::Feed;
+    ///
+    /// let xml = br#"<rss version="2.0"
+    ///                    xmlns:content="http://purl.org/rss/1.0/modules/content/"
+    ///                    xmlns:dc="http://purl.org/dc/elements/1.1/">
+    ///     <channel>
+    ///         <title>Title</title>
+    ///         <item>
+    ///             <title>Item</title>
+    ///             <content:encoded>&lt;p&gt;Full&lt;/p&gt;</content:encoded>
+    ///             <dc:creator>Jane Doe</dc:creator>
+    ///             <dc:date>2016-03-13T20:02:02-07:00</dc:date>
+    ///         </item>
+    ///     </channel>
+    /// </rss>"#;
+    /// let entry = Feed::parse(xml).unwrap().entries().remove(0);
+    /// assert_eq!(Some("<p>Full</p>".to_owned()), entry.content());
+    /// assert_eq!("Jane Doe".to_owned(), entry.authors()[0].name());
+    /// assert_eq!("Sun, 13 Mar 2016 20:02:02 -0700".to_owned(),
+    ///            entry.published().unwrap().to_rfc2822());
+    /// ```
+    pub fn entries(&self) -> Vec<model::Entry> {
+        match self.source {
+            Source::Rss(ref channel) | Source::JsonFeed(ref channel) => {
+                channel.items().unwrap_or_default().iter().map(convert::rss_item_to_entry).collect()
+            }
+            Source::Atom(ref feed) => {
+                feed.entries().unwrap_or_default().iter().map(convert::atom_entry_to_entry).collect()
+            }
+        }
     }
 
 
-    /// Convert the `Feed` to XML.
+    /// Convert the `Feed` to XML, an atom feed is written as atom and any
+    /// other feed as rss.
     ///
     /// # Examples
     ///
@@ -126,14 +534,106 @@ impl Feed {
     /// let xml = feed.to_xml();
     /// ```
     pub fn to_xml(&self) -> Vec<u8> {
-        FeedWriter::new(self.channel.clone()).xml()
+        let mut xml = Vec::new();
+        self.write_to(&mut xml).expect("Error writing xml to memory");
+        xml
+    }
+
+
+    /// Write the `Feed` as XML directly to a `Write`, such as a file, a http
+    /// response body or a compressing encoder, as `to_xml` does in memory.
+    /// A JSON Feed is written as rss, `to_json` writes it as JSON Feed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{Feed, FeedBuilder};
+    /// use feed::rss::ChannelBuilder;
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .title("Title")
+    ///     .link("http://example.com")
+    ///     .description("Description")
+    ///     .finalize();
+    /// let feed = FeedBuilder::new().channel(channel).finalize();
+    ///
+    /// let mut xml = Vec::new();
+    /// feed.write_to(&mut xml).unwrap();
+    /// assert_eq!("Title".to_owned(), Feed::parse(&xml).unwrap().title());
+    /// ```
+    ///
+    /// ```
+    /// use feed::{FeedBuilder, FeedError};
+    /// use std::io::{self, Write};
+    ///
+    /// struct Closed;
+    ///
+    /// impl Write for Closed {
+    ///     fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+    ///         Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"))
+    ///     }
+    ///
+    ///     fn flush(&mut self) -> io::Result<()> {
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// match FeedBuilder::new().finalize().write_to(Closed) {
+    ///     Err(FeedError::Io { .. }) => (),
+    ///     _ => panic!("expected an io error"),
+    /// }
+    /// ```
+    pub fn write_to<W: Write>(&self, writer: W) -> Result<(), FeedError> {
+        match self.source {
+            Source::Rss(ref channel) | Source::JsonFeed(ref channel) => {
+                FeedWriter::write_to(channel, channel.version(), writer)
+            }
+            Source::Atom(ref feed) => AtomWriter::write_to(feed, writer),
+        }
+    }
+
+
+    /// Construct a `Feed` from a JSON Feed, without any network access.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::Feed;
+    ///
+    /// let json = r#"{
+    ///     "version": "https://jsonfeed.org/version/1.1",
+    ///     "title": "My Example Feed",
+    ///     "items": [{ "id": "1", "content_text": "Hello, world!" }]
+    /// }"#;
+    /// let feed = Feed::from_json(json).unwrap();
+    /// assert_eq!("My Example Feed".to_owned(), feed.channel().title());
+    /// ```
+    pub fn from_json(json: &str) -> Result<Feed, FeedError> {
+        Ok(Feed { source: Source::JsonFeed(JsonFeedReader::parse(json)?) })
+    }
+
+
+    /// Convert the `Feed` to JSON Feed 1.1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::FeedBuilder;
+    /// use feed::rss::ChannelBuilder;
+    ///
+    /// let channel = ChannelBuilder::new().title("My Example Feed").finalize();
+    /// let feed = FeedBuilder::new().channel(channel).finalize();
+    /// let json = feed.to_json();
+    /// ```
+    pub fn to_json(&self) -> String {
+        JsonFeedWriter::new(self.clone().channel()).json()
     }
 }
 
 /// This `FeedBuilder` struct creates the Feed struct from url, file, or &str.
 #[derive(Default)]
 pub struct FeedBuilder {
-    channel: Channel,
+    source: Source,
 }
 
 
@@ -164,16 +664,37 @@ impl FeedBuilder {
     /// let feed = FeedBuilder::new().channel(channel).finalize();
     /// ```
     pub fn channel(&mut self, channel: Channel) -> &mut FeedBuilder {
-        self.channel = channel;
+        self.source = Source::Rss(channel);
         self
     }
 
 
-    /// Construct a new `FeedBuilder` from a `Url`.
+    /// Construct a new `FeedBuilder` from a format specific `Source`.
     ///
     /// # Examples
     ///
     /// ```
+    /// use feed::{FeedBuilder, Source};
+    /// use feed::atom::FeedBuilder as AtomFeedBuilder;
+    ///
+    /// let atom_feed = AtomFeedBuilder::new().title("Title").finalize();
+    /// let feed = FeedBuilder::new().source(Source::Atom(atom_feed)).finalize();
+    /// assert_eq!("Title".to_owned(), feed.title());
+    /// ```
+    pub fn source(&mut self, source: Source) -> &mut FeedBuilder {
+        self.source = source;
+        self
+    }
+
+
+    /// Construct a new `FeedBuilder` from a `Url`.
+    ///
+    /// A feed that cannot be retrieved or parsed is logged and leaves the
+    /// `FeedBuilder` unchanged, use `try_read_from_url` to find out why it failed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
     /// extern crate feed;
     /// extern crate url;
     ///
@@ -206,21 +727,200 @@ impl FeedBuilder {
     /// }
     /// ```
     pub fn read_from_url(&mut self, feed_url: Url) -> &mut FeedBuilder {
-        if !feed_url.serialize().as_str().ends_with(".xml") {
-            panic!(errors::missing_xml_error());
+        if let Err(e) = self.try_read_from_url(feed_url) {
+            error!("{}", e);
         }
+        self
+    }
+
+
+    /// Construct a new `FeedBuilder` from a `Url`, returning an error when the
+    /// feed cannot be retrieved or parsed.
+    ///
+    /// The url does not need to end with `.xml`, the response is accepted when
+    /// its content type is a feed type or its root element is a feed element.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// extern crate feed;
+    /// extern crate url;
+    ///
+    /// use feed::{FeedBuilder, FeedError};
+    /// use url::Url;
+    /// fn main() {
+    ///     let url = Url::parse("http://www.jupiterbroadcasting.com/feed/").unwrap();
+    ///     match FeedBuilder::new().try_read_from_url(url) {
+    ///         Ok(builder) => println!("Title: {}", builder.finalize().channel().title()),
+    ///         Err(FeedError::Http { status }) => println!("Http status {}", status),
+    ///         Err(e) => println!("Error: {}", e),
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```
+    /// extern crate feed;
+    /// extern crate url;
+    ///
+    /// use feed::{FeedBuilder, FeedError};
+    /// use url::Url;
+    /// # use std::io::{Read, Write};
+    /// # use std::net::TcpListener;
+    /// # use std::thread;
+    /// #
+    /// # fn serve(listener: TcpListener, status: &'static str, body: &'static str) {
+    /// #     thread::spawn(move || {
+    /// #         let (mut stream, _) = listener.accept().unwrap();
+    /// #         let mut request = [0; 1024];
+    /// #         stream.read(&mut request).unwrap();
+    /// #         write!(stream, "HTTP/1.1 {}\r\nContent-Type: text/plain\r\n", status).unwrap();
+    /// #         write!(stream, "Content-Length: {}\r\n\r\n{}", body.len(), body).unwrap();
+    /// #     });
+    /// # }
+    /// fn main() {
+    /// #   let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    /// #   let port = listener.local_addr().unwrap().port();
+    /// #   serve(listener, "200 OK", "<rss><channel><title>Title</title></channel></rss>");
+    ///     let url = Url::parse(&format!("http://127.0.0.1:{}/feed", port)).unwrap();
+    ///     let feed = FeedBuilder::new().try_read_from_url(url).unwrap().finalize();
+    ///     assert_eq!("Title".to_owned(), feed.channel().title());
+    ///
+    /// #   let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    /// #   let port = listener.local_addr().unwrap().port();
+    /// #   serve(listener, "203 Non-Authoritative Information",
+    /// #         "<atom:feed xmlns:atom=\"http://www.w3.org/2005/Atom\"><atom:title>Atom</atom:title></atom:feed>");
+    ///     let url = Url::parse(&format!("http://127.0.0.1:{}/atom", port)).unwrap();
+    ///     let feed = FeedBuilder::new().try_read_from_url(url).unwrap().finalize();
+    ///     assert_eq!("Atom".to_owned(), feed.title());
+    ///
+    /// #   let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    /// #   let port = listener.local_addr().unwrap().port();
+    /// #   serve(listener, "404 Not Found", "Not Found");
+    ///     let url = Url::parse(&format!("http://127.0.0.1:{}/missing", port)).unwrap();
+    ///     match FeedBuilder::new().try_read_from_url(url) {
+    ///         Err(FeedError::Http { status }) => assert_eq!(404, status),
+    ///         _ => panic!("expected a http error"),
+    ///     }
+    /// }
+    /// ```
+    pub fn try_read_from_url(&mut self, feed_url: Url) -> Result<&mut FeedBuilder, FeedError> {
         let response = http::handle()
                            .get(feed_url.serialize())
+                           .follow_redirects(true)
                            .exec()
-                           .expect(errors::response_error());
+                           .map_err(|e| FeedError::Transport { message: e.to_string() })?;
+        if !(200..300).contains(&response.get_code()) {
+            return Err(FeedError::Http { status: response.get_code() });
+        }
+
+        let content_type = response.get_headers()
+                                   .iter()
+                                   .find(|&(name, _)| name.eq_ignore_ascii_case("content-type"))
+                                   .and_then(|(_, values)| values.last().cloned());
+        let charset = content_type.as_ref().and_then(|t| encoding::content_type_charset(t));
         let body = response.get_body();
-        let feed_str = str::from_utf8(body).expect(errors::utf8_to_str_error());
-        debug!("feed xml:{}", feed_str);
-        self.channel = FeedReader::new(feed_str).channel();
+        let (body, _) = encoding::detect(body, charset).decode_with_bom_removal(body);
+        let declared = content_type.as_ref().is_some_and(|t| util::is_feed_content_type(t));
+        let sniffed = util::is_json(body.as_bytes()) ||
+                      util::root_element_name(body.as_bytes()).is_some_and(util::is_feed_root);
+        if !declared && !sniffed {
+            return Err(FeedError::NotAFeed { content_type });
+        }
+
+        debug!("feed:{}", body);
+        self.source = Feed::parse_utf8(body.as_bytes())?.source;
+        Ok(self)
+    }
+
+
+    /// Construct a new `FeedBuilder` from a feed of any format read from a `Read`.
+    ///
+    /// A feed that cannot be read or parsed is logged and leaves the
+    /// `FeedBuilder` unchanged, use `try_read_from_reader` to find out why it failed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::FeedBuilder;
+    /// use std::io::Cursor;
+    ///
+    /// let xml = Cursor::new("<rss><channel><title>Title</title></channel></rss>");
+    /// let feed = FeedBuilder::new().read_from_reader(xml).finalize();
+    /// assert_eq!("Title".to_owned(), feed.title());
+    /// ```
+    pub fn read_from_reader<R: Read>(&mut self, reader: R) -> &mut FeedBuilder {
+        if let Err(e) = self.try_read_from_reader(reader) {
+            error!("{}", e);
+        }
+        self
+    }
+
+
+    /// Construct a new `FeedBuilder` from a feed of any format read from a
+    /// `Read`, returning an error when the feed cannot be read or parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{FeedBuilder, FeedError};
+    /// use std::io::Cursor;
+    ///
+    /// let xml = Cursor::new("<rss><channel><item><link>http://example.com</link></item></channel></rss>");
+    /// match FeedBuilder::new().try_read_from_reader(xml) {
+    ///     Err(FeedError::MissingElement { path, .. }) => assert_eq!("/rss/channel/item", path),
+    ///     _ => panic!("expected a missing element error"),
+    /// }
+    /// ```
+    pub fn try_read_from_reader<R: Read>(&mut self,
+                                         reader: R)
+                                         -> Result<&mut FeedBuilder, FeedError> {
+        self.source = Feed::parse_reader(reader)?.source;
+        Ok(self)
+    }
+
+
+    /// Construct a new `FeedBuilder` from a feed file of any format.
+    ///
+    /// A feed that cannot be read or parsed is logged and leaves the
+    /// `FeedBuilder` unchanged, use `try_read_from_path` to find out why it failed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::FeedBuilder;
+    ///
+    /// let feed = FeedBuilder::new().read_from_path("rsc/sample_rss.xml").finalize();
+    /// assert_eq!("The Linux Action Show! OGG".to_owned(), feed.title());
+    /// ```
+    pub fn read_from_path<P: AsRef<Path>>(&mut self, path: P) -> &mut FeedBuilder {
+        if let Err(e) = self.try_read_from_path(path) {
+            error!("{}", e);
+        }
         self
     }
 
 
+    /// Construct a new `FeedBuilder` from a feed file of any format, returning
+    /// an error when the file cannot be read or parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::{FeedBuilder, FeedError};
+    ///
+    /// match FeedBuilder::new().try_read_from_path("missing-feed.xml") {
+    ///     Err(FeedError::Io { .. }) => (),
+    ///     _ => panic!("expected an io error"),
+    /// }
+    /// ```
+    pub fn try_read_from_path<P: AsRef<Path>>(&mut self,
+                                              path: P)
+                                              -> Result<&mut FeedBuilder, FeedError> {
+        let file = File::open(path)?;
+        self.try_read_from_reader(file)
+    }
+
+
     /// Construct the `Feed` from the `FeedBuilder`.
     ///
     /// # Examples
@@ -231,6 +931,6 @@ impl FeedBuilder {
     /// let feed = FeedBuilder::new().finalize();
     /// ```
     pub fn finalize(&self) -> Feed {
-        Feed { channel: self.channel.clone() }
+        Feed { source: self.source.clone() }
     }
 }
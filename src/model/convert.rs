@@ -0,0 +1,204 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! Conversions between the format specific structs and the model.
+
+use atom;
+use model::{Entry, EntryBuilder, Link, LinkBuilder, MediaBuilder, Person, PersonBuilder};
+use rss::{CategoryBuilder, Channel, ChannelBuilder, EnclosureBuilder, GuidBuilder, Item,
+          ItemBuilder};
+
+// Convert the rss `Item` to the `Entry`, its full content being the one of
+// `content:encoded` and its author and published date falling back to the ones
+// of Dublin Core.
+pub fn rss_item_to_entry(item: &Item) -> Entry {
+    let links = item.link()
+                    .map(|link| vec![alternate_link(&link)])
+                    .unwrap_or_default();
+
+    let authors = item.author()
+                      .map(|author| vec![PersonBuilder::new().name(&author).finalize()])
+                      .unwrap_or_default();
+
+    let categories = item.categories()
+                         .unwrap_or_default()
+                         .iter()
+                         .map(|category| category.category())
+                         .collect();
+
+    let media = item.enclosure()
+                    .map(|enclosure| {
+                        let media_type = Some(enclosure.enclosure_type()).filter(|t| !t.is_empty());
+                        let length = Some(enclosure.length()).filter(|length| *length > 0);
+                        vec![MediaBuilder::new()
+                                 .url(&enclosure.url())
+                                 .media_type(media_type)
+                                 .length(length)
+                                 .finalize()]
+                    })
+                    .unwrap_or_default();
+
+    EntryBuilder::new()
+        .id(item.guid().map(|guid| guid.guid()))
+        .title(item.title())
+        .links(links)
+        .summary(item.description())
+        .content(item.content())
+        .authors(authors)
+        .categories(categories)
+        .published(item.pub_date())
+        .media(media)
+        .finalize()
+}
+
+
+// Convert the atom `Entry` to the `Entry`, its links with the enclosure
+// relation are its media.
+pub fn atom_entry_to_entry(entry: &atom::Entry) -> Entry {
+    let links = entry.links().unwrap_or_default();
+
+    let media = links.iter()
+                     .filter(|link| link.rel().is_some_and(|rel| rel == "enclosure"))
+                     .map(|link| {
+                         MediaBuilder::new()
+                             .url(&link.href())
+                             .media_type(link.link_type())
+                             .length(link.length())
+                             .finalize()
+                     })
+                     .collect();
+
+    let content = entry.content()
+                       .filter(|content| content.src().is_none())
+                       .map(|content| content.content());
+
+    let categories = entry.categories()
+                          .unwrap_or_default()
+                          .iter()
+                          .map(|category| category.term())
+                          .collect();
+
+    EntryBuilder::new()
+        .id(Some(entry.id()).filter(|id| !id.is_empty()))
+        .title(Some(entry.title()))
+        .links(links.iter().map(atom_link_to_link).collect())
+        .summary(entry.summary())
+        .content(content)
+        .authors(atom_persons_to_persons(entry.authors()))
+        .categories(categories)
+        .published(entry.published())
+        .updated(entry.updated())
+        .media(media)
+        .finalize()
+}
+
+
+// Convert the atom `Link` to the `Link`.
+pub fn atom_link_to_link(link: &atom::Link) -> Link {
+    LinkBuilder::new()
+        .href(&link.href())
+        .rel(link.rel())
+        .media_type(link.link_type())
+        .title(link.title())
+        .finalize()
+}
+
+
+// Convert the atom persons to the `Person`s.
+pub fn atom_persons_to_persons(persons: Option<Vec<atom::Person>>) -> Vec<Person> {
+    persons.unwrap_or_default()
+           .iter()
+           .map(|person| {
+               PersonBuilder::new()
+                   .name(&person.name())
+                   .email(person.email())
+                   .uri(person.uri())
+                   .finalize()
+           })
+           .collect()
+}
+
+
+// Construct the alternate `Link` to a rss link.
+pub fn alternate_link(href: &str) -> Link {
+    LinkBuilder::new()
+        .href(href)
+        .rel(Some("alternate".to_owned()))
+        .finalize()
+}
+
+
+// Convert the atom `Feed` to the rss `Channel`, for the code written against
+// the rss structs.
+pub fn atom_feed_to_channel(feed: &atom::Feed) -> Channel {
+    let links = feed.links().unwrap_or_default();
+    let link = links.iter()
+                    .find(|link| link.rel().is_none_or(|rel| rel == "alternate"))
+                    .or_else(|| links.first())
+                    .map(|link| link.href())
+                    .unwrap_or_default();
+
+    let items = feed.entries()
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|entry| entry_to_rss_item(&atom_entry_to_entry(entry)))
+                    .collect();
+
+    ChannelBuilder::new()
+        .title(&feed.title())
+        .link(&link)
+        .description(&feed.subtitle().unwrap_or_default())
+        .copyright(feed.rights())
+        .generator(feed.generator())
+        .last_build_date_value(feed.updated())
+        .items(Some(items))
+        .finalize()
+}
+
+
+// Convert the `Entry` to the rss `Item`, which needs a title or description.
+fn entry_to_rss_item(entry: &Entry) -> Item {
+    let links = entry.links();
+    let link = links.iter()
+                    .find(|link| link.rel().is_none_or(|rel| rel == "alternate"))
+                    .map(|link| link.href());
+
+    let description = entry.summary().or_else(|| entry.content());
+    let title = match entry.title() {
+        None if description.is_none() => Some(String::new()),
+        title => title,
+    };
+
+    let categories = entry.categories()
+                          .iter()
+                          .map(|category| CategoryBuilder::new().category(category).finalize())
+                          .collect();
+
+    let guid = entry.id().map(|id| {
+        GuidBuilder::new()
+            .guid(&id)
+            .permalink(Some(link.as_ref() == Some(&id)))
+            .finalize()
+    });
+
+    let enclosure = entry.media().first().map(|media| {
+        EnclosureBuilder::new()
+            .url(&media.url())
+            .length(media.length().unwrap_or(0))
+            .enclosure_type(&media.media_type().unwrap_or_default())
+            .finalize()
+    });
+
+    ItemBuilder::new()
+        .title(title)
+        .link(link)
+        .description(description)
+        .author(entry.authors().first().map(|author| author.name()))
+        .categories(Some(categories))
+        .guid(guid)
+        .pub_date_value(entry.published().or_else(|| entry.updated()))
+        .enclosure(enclosure)
+        .finalize()
+}
+
@@ -0,0 +1,258 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under entry can be retrieved by using the methods under `Entry`.
+
+use chrono::*;
+use model::{Entry, Link, Media, Person};
+
+impl Entry {
+    /// Get the optional id that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let id = "urn:uuid:1225c695-cfb8-4ebb-aaaa-80da344efa6a".to_owned();
+    /// let entry_obj = EntryBuilder::new()
+    ///     .id(Some(id.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(id), entry_obj.id());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let entry_obj = EntryBuilder::new()
+    ///     .id(None)
+    ///     .finalize();
+    /// assert!(entry_obj.id().is_none());
+    /// ```
+    pub fn id(&self) -> Option<String> {
+        self.id.clone()
+    }
+
+
+    /// Get the optional title that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let title = "Atom-Powered Robots Run Amok".to_owned();
+    /// let entry_obj = EntryBuilder::new()
+    ///     .title(Some(title.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(title), entry_obj.title());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let entry_obj = EntryBuilder::new()
+    ///     .title(None)
+    ///     .finalize();
+    /// assert!(entry_obj.title().is_none());
+    /// ```
+    pub fn title(&self) -> Option<String> {
+        self.title.clone()
+    }
+
+
+    /// Get the links that exist under `Entry`, empty when there are none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{EntryBuilder, LinkBuilder};
+    ///
+    /// let link_1 = LinkBuilder::new().finalize();
+    /// let link_2 = LinkBuilder::new().finalize();
+    /// let entry_obj = EntryBuilder::new()
+    ///     .links(vec![link_1, link_2])
+    ///     .finalize();
+    /// assert_eq!(2, entry_obj.links().len());
+    /// ```
+    pub fn links(&self) -> Vec<Link> {
+        self.links.clone()
+    }
+
+
+    /// Get the optional summary that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let summary = "Some text.".to_owned();
+    /// let entry_obj = EntryBuilder::new()
+    ///     .summary(Some(summary.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(summary), entry_obj.summary());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let entry_obj = EntryBuilder::new()
+    ///     .summary(None)
+    ///     .finalize();
+    /// assert!(entry_obj.summary().is_none());
+    /// ```
+    pub fn summary(&self) -> Option<String> {
+        self.summary.clone()
+    }
+
+
+    /// Get the optional content that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let content = "<p>Some text.</p>".to_owned();
+    /// let entry_obj = EntryBuilder::new()
+    ///     .content(Some(content.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(content), entry_obj.content());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let entry_obj = EntryBuilder::new()
+    ///     .content(None)
+    ///     .finalize();
+    /// assert!(entry_obj.content().is_none());
+    /// ```
+    pub fn content(&self) -> Option<String> {
+        self.content.clone()
+    }
+
+
+    /// Get the authors that exist under `Entry`, empty when there are none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{EntryBuilder, PersonBuilder};
+    ///
+    /// let person_1 = PersonBuilder::new().finalize();
+    /// let person_2 = PersonBuilder::new().finalize();
+    /// let entry_obj = EntryBuilder::new()
+    ///     .authors(vec![person_1, person_2])
+    ///     .finalize();
+    /// assert_eq!(2, entry_obj.authors().len());
+    /// ```
+    pub fn authors(&self) -> Vec<Person> {
+        self.authors.clone()
+    }
+
+
+    /// Get the categories that exist under `Entry`, empty when there are none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let categories = vec!["Technology".to_owned()];
+    /// let entry_obj = EntryBuilder::new()
+    ///     .categories(categories.clone())
+    ///     .finalize();
+    /// assert_eq!(categories, entry_obj.categories());
+    /// ```
+    pub fn categories(&self) -> Vec<String> {
+        self.categories.clone()
+    }
+
+
+    /// Get the optional published date that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate chrono;
+    /// extern crate feed;
+    ///
+    /// use chrono::DateTime;
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let published = "Sat, 13 Dec 2003 18:30:02 +0000";
+    /// let entry_obj = EntryBuilder::new()
+    ///     .published(DateTime::parse_from_rfc2822(published).ok())
+    ///     .finalize();
+    /// let local = entry_obj.published();
+    /// assert!(local.is_some());
+    /// assert_eq!(published.to_owned(), local.unwrap().to_rfc2822());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let entry_obj = EntryBuilder::new()
+    ///     .published(None)
+    ///     .finalize();
+    /// assert!(entry_obj.published().is_none());
+    /// ```
+    pub fn published(&self) -> Option<DateTime<FixedOffset>> {
+        self.published
+    }
+
+
+    /// Get the optional updated date that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate chrono;
+    /// extern crate feed;
+    ///
+    /// use chrono::DateTime;
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let updated = "Sat, 13 Dec 2003 18:30:02 +0000";
+    /// let entry_obj = EntryBuilder::new()
+    ///     .updated(DateTime::parse_from_rfc2822(updated).ok())
+    ///     .finalize();
+    /// let local = entry_obj.updated();
+    /// assert!(local.is_some());
+    /// assert_eq!(updated.to_owned(), local.unwrap().to_rfc2822());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let entry_obj = EntryBuilder::new()
+    ///     .updated(None)
+    ///     .finalize();
+    /// assert!(entry_obj.updated().is_none());
+    /// ```
+    pub fn updated(&self) -> Option<DateTime<FixedOffset>> {
+        self.updated
+    }
+
+
+    /// Get the media that exist under `Entry`, empty when there are none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{EntryBuilder, MediaBuilder};
+    ///
+    /// let media_1 = MediaBuilder::new().finalize();
+    /// let media_2 = MediaBuilder::new().finalize();
+    /// let entry_obj = EntryBuilder::new()
+    ///     .media(vec![media_1, media_2])
+    ///     .finalize();
+    /// assert_eq!(2, entry_obj.media().len());
+    /// ```
+    pub fn media(&self) -> Vec<Media> {
+        self.media.clone()
+    }
+}
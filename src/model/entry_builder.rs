@@ -0,0 +1,228 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for entry by using the methods under `EntryBuilder`.
+
+use chrono::*;
+use model::{Link, Media, Person};
+
+use model::{Entry, EntryBuilder};
+
+impl EntryBuilder {
+    /// Construct a new `EntryBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let entry_builder = EntryBuilder::new();
+    /// ```
+    pub fn new() -> EntryBuilder {
+        EntryBuilder::default()
+    }
+
+
+    /// Set the optional id that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.id(Some("urn:uuid:1225c695-cfb8-4ebb-aaaa-80da344efa6a".to_owned()));
+    /// ```
+    pub fn id(&mut self, id: Option<String>) -> &mut EntryBuilder {
+        self.id = id;
+        self
+    }
+
+
+    /// Set the optional title that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.title(Some("Atom-Powered Robots Run Amok".to_owned()));
+    /// ```
+    pub fn title(&mut self, title: Option<String>) -> &mut EntryBuilder {
+        self.title = title;
+        self
+    }
+
+
+    /// Set the links that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{EntryBuilder, LinkBuilder};
+    ///
+    /// let link = LinkBuilder::new().finalize();
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.links(vec![link]);
+    /// ```
+    pub fn links(&mut self, links: Vec<Link>) -> &mut EntryBuilder {
+        self.links = links;
+        self
+    }
+
+
+    /// Set the optional summary that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.summary(Some("Some text.".to_owned()));
+    /// ```
+    pub fn summary(&mut self, summary: Option<String>) -> &mut EntryBuilder {
+        self.summary = summary;
+        self
+    }
+
+
+    /// Set the optional content that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.content(Some("<p>Some text.</p>".to_owned()));
+    /// ```
+    pub fn content(&mut self, content: Option<String>) -> &mut EntryBuilder {
+        self.content = content;
+        self
+    }
+
+
+    /// Set the authors that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{EntryBuilder, PersonBuilder};
+    ///
+    /// let person = PersonBuilder::new().finalize();
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.authors(vec![person]);
+    /// ```
+    pub fn authors(&mut self, authors: Vec<Person>) -> &mut EntryBuilder {
+        self.authors = authors;
+        self
+    }
+
+
+    /// Set the categories that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.categories(vec!["Technology".to_owned()]);
+    /// ```
+    pub fn categories(&mut self, categories: Vec<String>) -> &mut EntryBuilder {
+        self.categories = categories;
+        self
+    }
+
+
+    /// Set the optional published date that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate chrono;
+    /// extern crate feed;
+    ///
+    /// use chrono::DateTime;
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let date = DateTime::parse_from_rfc2822("Sat, 13 Dec 2003 18:30:02 +0000").ok();
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.published(date);
+    /// ```
+    pub fn published(&mut self, published: Option<DateTime<FixedOffset>>) -> &mut EntryBuilder {
+        self.published = published;
+        self
+    }
+
+
+    /// Set the optional updated date that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate chrono;
+    /// extern crate feed;
+    ///
+    /// use chrono::DateTime;
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let date = DateTime::parse_from_rfc2822("Sat, 13 Dec 2003 18:30:02 +0000").ok();
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.updated(date);
+    /// ```
+    pub fn updated(&mut self, updated: Option<DateTime<FixedOffset>>) -> &mut EntryBuilder {
+        self.updated = updated;
+        self
+    }
+
+
+    /// Set the media that exists under `Entry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::{EntryBuilder, MediaBuilder};
+    ///
+    /// let media = MediaBuilder::new().finalize();
+    ///
+    /// let mut entry_builder = EntryBuilder::new();
+    /// entry_builder.media(vec![media]);
+    /// ```
+    pub fn media(&mut self, media: Vec<Media>) -> &mut EntryBuilder {
+        self.media = media;
+        self
+    }
+
+
+    /// Construct the `Entry` from the `EntryBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::EntryBuilder;
+    ///
+    /// let entry = EntryBuilder::new()
+    ///         .title(Some("Atom-Powered Robots Run Amok".to_owned()))
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> Entry {
+        Entry {
+            id: self.id.clone(),
+            title: self.title.clone(),
+            links: self.links.clone(),
+            summary: self.summary.clone(),
+            content: self.content.clone(),
+            authors: self.authors.clone(),
+            categories: self.categories.clone(),
+            published: self.published,
+            updated: self.updated,
+            media: self.media.clone(),
+        }
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under link can be retrieved by using the methods under `Link`.
+
+use model::Link;
+
+impl Link {
+    /// Get the href that exists under `Link`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::LinkBuilder;
+    ///
+    /// let href = "http://example.com/2003/12/13/atom03";
+    /// let link_obj = LinkBuilder::new()
+    ///     .href(href)
+    ///     .finalize();
+    /// assert_eq!(href.to_owned(), link_obj.href());
+    /// ```
+    pub fn href(&self) -> String {
+        self.href.clone()
+    }
+
+
+    /// Get the optional rel that exists under `Link`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::LinkBuilder;
+    ///
+    /// let rel = "alternate".to_owned();
+    /// let link_obj = LinkBuilder::new()
+    ///     .rel(Some(rel.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(rel), link_obj.rel());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::LinkBuilder;
+    ///
+    /// let link_obj = LinkBuilder::new()
+    ///     .rel(None)
+    ///     .finalize();
+    /// assert!(link_obj.rel().is_none());
+    /// ```
+    pub fn rel(&self) -> Option<String> {
+        self.rel.clone()
+    }
+
+
+    /// Get the optional media type that exists under `Link`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::LinkBuilder;
+    ///
+    /// let media_type = "text/html".to_owned();
+    /// let link_obj = LinkBuilder::new()
+    ///     .media_type(Some(media_type.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(media_type), link_obj.media_type());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::LinkBuilder;
+    ///
+    /// let link_obj = LinkBuilder::new()
+    ///     .media_type(None)
+    ///     .finalize();
+    /// assert!(link_obj.media_type().is_none());
+    /// ```
+    pub fn media_type(&self) -> Option<String> {
+        self.media_type.clone()
+    }
+
+
+    /// Get the optional title that exists under `Link`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::LinkBuilder;
+    ///
+    /// let title = "Atom-Powered Robots Run Amok".to_owned();
+    /// let link_obj = LinkBuilder::new()
+    ///     .title(Some(title.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(title), link_obj.title());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::LinkBuilder;
+    ///
+    /// let link_obj = LinkBuilder::new()
+    ///     .title(None)
+    ///     .finalize();
+    /// assert!(link_obj.title().is_none());
+    /// ```
+    pub fn title(&self) -> Option<String> {
+        self.title.clone()
+    }
+}
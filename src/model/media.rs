@@ -0,0 +1,80 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under media can be retrieved by using the methods under `Media`.
+
+use model::Media;
+
+impl Media {
+    /// Get the url that exists under `Media`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::MediaBuilder;
+    ///
+    /// let url = "http://example.com/episode.ogg";
+    /// let media_obj = MediaBuilder::new()
+    ///     .url(url)
+    ///     .finalize();
+    /// assert_eq!(url.to_owned(), media_obj.url());
+    /// ```
+    pub fn url(&self) -> String {
+        self.url.clone()
+    }
+
+
+    /// Get the optional media type that exists under `Media`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::MediaBuilder;
+    ///
+    /// let media_type = "audio/ogg".to_owned();
+    /// let media_obj = MediaBuilder::new()
+    ///     .media_type(Some(media_type.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(media_type), media_obj.media_type());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::MediaBuilder;
+    ///
+    /// let media_obj = MediaBuilder::new()
+    ///     .media_type(None)
+    ///     .finalize();
+    /// assert!(media_obj.media_type().is_none());
+    /// ```
+    pub fn media_type(&self) -> Option<String> {
+        self.media_type.clone()
+    }
+
+
+    /// Get the optional length that exists under `Media`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::MediaBuilder;
+    ///
+    /// let length = 1337;
+    /// let media_obj = MediaBuilder::new()
+    ///     .length(Some(length))
+    ///     .finalize();
+    /// assert_eq!(Some(length), media_obj.length());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::MediaBuilder;
+    ///
+    /// let media_obj = MediaBuilder::new()
+    ///     .length(None)
+    ///     .finalize();
+    /// assert!(media_obj.length().is_none());
+    /// ```
+    pub fn length(&self) -> Option<i64> {
+        self.length
+    }
+}
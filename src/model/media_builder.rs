@@ -0,0 +1,90 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields can be set for media by using the methods under `MediaBuilder`.
+
+use model::{Media, MediaBuilder};
+
+impl MediaBuilder {
+    /// Construct a new `MediaBuilder` and return default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::MediaBuilder;
+    ///
+    /// let media_builder = MediaBuilder::new();
+    /// ```
+    pub fn new() -> MediaBuilder {
+        MediaBuilder::default()
+    }
+
+
+    /// Set the url that exists under `Media`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::MediaBuilder;
+    ///
+    /// let mut media_builder = MediaBuilder::new();
+    /// media_builder.url("http://example.com/episode.ogg");
+    /// ```
+    pub fn url(&mut self, url: &str) -> &mut MediaBuilder {
+        self.url = url.to_owned();
+        self
+    }
+
+
+    /// Set the optional media type that exists under `Media`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::MediaBuilder;
+    ///
+    /// let mut media_builder = MediaBuilder::new();
+    /// media_builder.media_type(Some("audio/ogg".to_owned()));
+    /// ```
+    pub fn media_type(&mut self, media_type: Option<String>) -> &mut MediaBuilder {
+        self.media_type = media_type;
+        self
+    }
+
+
+    /// Set the optional length that exists under `Media`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::MediaBuilder;
+    ///
+    /// let mut media_builder = MediaBuilder::new();
+    /// media_builder.length(Some(1337));
+    /// ```
+    pub fn length(&mut self, length: Option<i64>) -> &mut MediaBuilder {
+        self.length = length;
+        self
+    }
+
+
+    /// Construct the `Media` from the `MediaBuilder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::MediaBuilder;
+    ///
+    /// let media = MediaBuilder::new()
+    ///         .url("http://example.com/episode.ogg")
+    ///         .finalize();
+    /// ```
+    pub fn finalize(&self) -> Media {
+        Media {
+            url: self.url.clone(),
+            media_type: self.media_type.clone(),
+            length: self.length,
+        }
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! All the structs for the model shared by every feed format.
+
+pub(crate) mod convert;
+pub mod entry;
+pub mod entry_builder;
+pub mod link;
+pub mod link_builder;
+pub mod media;
+pub mod media_builder;
+pub mod person;
+pub mod person_builder;
+
+use chrono::*;
+
+/// This `Entry` struct contains all the items that exist for an entry of any feed format,
+/// an rss item, atom entry or JSON Feed item.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entry {
+    id: Option<String>,
+    title: Option<String>,
+    links: Vec<Link>,
+    summary: Option<String>,
+    content: Option<String>,
+    authors: Vec<Person>,
+    categories: Vec<String>,
+    published: Option<DateTime<FixedOffset>>,
+    updated: Option<DateTime<FixedOffset>>,
+    media: Vec<Media>,
+}
+
+
+/// This `EntryBuilder` struct creates the `Entry`.
+#[derive(Default)]
+pub struct EntryBuilder {
+    id: Option<String>,
+    title: Option<String>,
+    links: Vec<Link>,
+    summary: Option<String>,
+    content: Option<String>,
+    authors: Vec<Person>,
+    categories: Vec<String>,
+    published: Option<DateTime<FixedOffset>>,
+    updated: Option<DateTime<FixedOffset>>,
+    media: Vec<Media>,
+}
+
+
+/// This `Link` struct contains all the items that exist for the link field under `Feed` and `Entry`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Link {
+    href: String,
+    rel: Option<String>,
+    media_type: Option<String>,
+    title: Option<String>,
+}
+
+
+/// This `LinkBuilder` struct creates the `Link`.
+#[derive(Default)]
+pub struct LinkBuilder {
+    href: String,
+    rel: Option<String>,
+    media_type: Option<String>,
+    title: Option<String>,
+}
+
+
+/// This `Media` struct contains all the items that exist for the media field under `Entry`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Media {
+    url: String,
+    media_type: Option<String>,
+    length: Option<i64>,
+}
+
+
+/// This `MediaBuilder` struct creates the `Media`.
+#[derive(Default)]
+pub struct MediaBuilder {
+    url: String,
+    media_type: Option<String>,
+    length: Option<i64>,
+}
+
+
+/// This `Person` struct contains all the items that exist for the person field under `Feed` and `Entry`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Person {
+    name: String,
+    email: Option<String>,
+    uri: Option<String>,
+}
+
+
+/// This `PersonBuilder` struct creates the `Person`.
+#[derive(Default)]
+pub struct PersonBuilder {
+    name: String,
+    email: Option<String>,
+    uri: Option<String>,
+}
@@ -0,0 +1,80 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The fields under person can be retrieved by using the methods under `Person`.
+
+use model::Person;
+
+impl Person {
+    /// Get the name that exists under `Person`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::PersonBuilder;
+    ///
+    /// let name = "John Doe";
+    /// let person_obj = PersonBuilder::new()
+    ///     .name(name)
+    ///     .finalize();
+    /// assert_eq!(name.to_owned(), person_obj.name());
+    /// ```
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+
+    /// Get the optional email that exists under `Person`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::PersonBuilder;
+    ///
+    /// let email = "johndoe@example.com".to_owned();
+    /// let person_obj = PersonBuilder::new()
+    ///     .email(Some(email.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(email), person_obj.email());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::PersonBuilder;
+    ///
+    /// let person_obj = PersonBuilder::new()
+    ///     .email(None)
+    ///     .finalize();
+    /// assert!(person_obj.email().is_none());
+    /// ```
+    pub fn email(&self) -> Option<String> {
+        self.email.clone()
+    }
+
+
+    /// Get the optional uri that exists under `Person`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::model::PersonBuilder;
+    ///
+    /// let uri = "http://example.com/johndoe".to_owned();
+    /// let person_obj = PersonBuilder::new()
+    ///     .uri(Some(uri.clone()))
+    ///     .finalize();
+    /// assert_eq!(Some(uri), person_obj.uri());
+    /// ```
+    ///
+    /// ```
+    /// use feed::model::PersonBuilder;
+    ///
+    /// let person_obj = PersonBuilder::new()
+    ///     .uri(None)
+    ///     .finalize();
+    /// assert!(person_obj.uri().is_none());
+    /// ```
+    pub fn uri(&self) -> Option<String> {
+        self.uri.clone()
+    }
+}
@@ -4,8 +4,16 @@
 
 //! The fields under channel can be retrieved by using the methods under `Channel`.
 
+use atom::Link;
 use chrono::*;
-use rss::{Category, Channel, Cloud, Image, Item, TextInput};
+use date;
+use extension::{Extension, ExtensionMap, ATOM_NAMESPACE};
+use extension::dublin_core::DublinCoreExtension;
+use extension::itunes::ITunesChannelExtension;
+use extension::media::MediaExtension;
+use extension::podcast::PodcastChannelExtension;
+use rss::{Category, Channel, Cloud, Image, Item, TextInput, Version};
+use util;
 
 
 impl Channel {
@@ -188,7 +196,8 @@ impl Channel {
     }
 
 
-    /// Get the optional pub date that exists under `Channel`.
+    /// Get the optional pub date that exists under `Channel`, or else the Dublin
+    /// Core `dc:date`.
     ///
     /// # Examples
     ///
@@ -214,7 +223,48 @@ impl Channel {
     /// assert!(channel.pub_date().is_none());
     /// ```
     pub fn pub_date(&self) -> Option<DateTime<FixedOffset>> {
-        self.pub_date
+        if self.pub_date.is_some() || self.pub_date_raw.is_some() {
+            self.pub_date
+        } else {
+            util::option_string_to_option_date(util::dublin_core_text(&self.extensions, "date"))
+        }
+    }
+
+
+    /// Get the text of the optional pub date that exists under `Channel` when it
+    /// could not be parsed, or else the text of `dc:date` when it could not be
+    /// parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::rss::ChannelBuilder;
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .pub_date(Some("13 Mrz 2016 20:02 Uhr".to_owned()))
+    ///     .finalize();
+    /// assert!(channel.pub_date().is_some());
+    /// assert!(channel.pub_date_raw().is_none());
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .pub_date(Some("sometime in March".to_owned()))
+    ///     .finalize();
+    /// assert!(channel.pub_date().is_none());
+    /// assert_eq!(Some("sometime in March".to_owned()), channel.pub_date_raw());
+    /// ```
+    pub fn pub_date_raw(&self) -> Option<String> {
+        if self.pub_date.is_some() || self.pub_date_raw.is_some() {
+            self.pub_date_raw.clone()
+        } else {
+            util::dublin_core_text(&self.extensions, "date").filter(|text| date::parse(text).is_none())
+        }
+    }
+
+
+    // Get the pub date and its text that were set on `Channel`, leaving out the
+    // `dc:date` they fall back to.
+    pub(crate) fn own_pub_date(&self) -> (Option<DateTime<FixedOffset>>, Option<String>) {
+        (self.pub_date, self.pub_date_raw.clone())
     }
 
 
@@ -248,6 +298,31 @@ impl Channel {
     }
 
 
+    /// Get the text of the optional last build date that exists under `Channel`
+    /// when it could not be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::rss::ChannelBuilder;
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .last_build_date(Some("13 Mrz 2016 20:02 Uhr".to_owned()))
+    ///     .finalize();
+    /// assert!(channel.last_build_date().is_some());
+    /// assert!(channel.last_build_date_raw().is_none());
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .last_build_date(Some("sometime in March".to_owned()))
+    ///     .finalize();
+    /// assert!(channel.last_build_date().is_none());
+    /// assert_eq!(Some("sometime in March".to_owned()), channel.last_build_date_raw());
+    /// ```
+    pub fn last_build_date_raw(&self) -> Option<String> {
+        self.last_build_date_raw.clone()
+    }
+
+
     /// Get the optional categories that exists under `Channel`.
     ///
     /// # Examples
@@ -411,6 +486,25 @@ impl Channel {
     }
 
 
+    /// Get the text of the ttl that exists under `Channel` as it was read, also
+    /// when it is not a number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::rss::ChannelBuilder;
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .ttl_raw(Some("unknown".to_owned()))
+    ///     .finalize();
+    /// assert!(channel.ttl().is_none());
+    /// assert_eq!(Some("unknown".to_owned()), channel.ttl_raw());
+    /// ```
+    pub fn ttl_raw(&self) -> Option<String> {
+        self.ttl_raw.clone()
+    }
+
+
     /// Get the optional image that exists under `Channel`.
     ///
     /// # Examples
@@ -639,4 +733,275 @@ impl Channel {
     pub fn items(&self) -> Option<Vec<Item>> {
         self.items.clone()
     }
+
+
+    /// Get the version of rss the `Channel` was read from or will be written as.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::rss::{ChannelBuilder, Version};
+    ///
+    /// let channel = ChannelBuilder::new()
+    ///     .version(Version::V0_91Userland)
+    ///     .finalize();
+    /// assert_eq!(Version::V0_91Userland, channel.version());
+    /// ```
+    ///
+    /// ```
+    /// use feed::rss::{ChannelBuilder, Version};
+    ///
+    /// let channel = ChannelBuilder::new().finalize();
+    /// assert_eq!(Version::V2_0, channel.version());
+    /// ```
+    pub fn version(&self) -> Version {
+        self.version
+    }
+
+
+    /// Get the extension elements that exist under `Channel`, keyed by the uri of
+    /// their namespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::FeedReader;
+    ///
+    /// let xml = "<rss xmlns:dc=\"http://purl.org/dc/elements/1.1/\"><channel>".to_owned()
+    ///     + "<title>Title</title><dc:rights>Public domain</dc:rights>"
+    ///     + "<item><title>Item</title><dc:rights>Mine</dc:rights></item>"
+    ///     + "</channel></rss>";
+    /// let channel = FeedReader::parse(&xml).unwrap();
+    /// let extensions = channel.extensions();
+    /// let rights = &extensions["http://purl.org/dc/elements/1.1/"][0];
+    /// assert_eq!("dc:rights".to_owned(), rights.name());
+    /// ```
+    pub fn extensions(&self) -> ExtensionMap {
+        self.extensions.clone()
+    }
+
+
+    /// Get the `Extension` read from the elements of its namespace under
+    /// `Channel`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::{Extension, ExtensionElement};
+    /// use feed::feedio::FeedReader;
+    ///
+    /// struct Rights(String);
+    ///
+    /// impl Extension for Rights {
+    ///     const NAMESPACE: &'static str = "http://purl.org/dc/elements/1.1/";
+    ///     const PREFIX: &'static str = "dc";
+    ///
+    ///     fn from_elements(elements: &[ExtensionElement]) -> Option<Rights> {
+    ///         let rights = elements.iter().find(|e| e.local_name() == "rights")?;
+    ///         Some(Rights(rights.text().unwrap_or_default()))
+    ///     }
+    ///
+    ///     fn to_elements(&self) -> Vec<ExtensionElement> {
+    ///         Vec::new()
+    ///     }
+    /// }
+    ///
+    /// let xml = "<rss xmlns:dc=\"http://purl.org/dc/elements/1.1/\"><channel>".to_owned()
+    ///     + "<title>Title</title><dc:rights>Public domain</dc:rights>"
+    ///     + "<item><title>Item</title><dc:rights>Mine</dc:rights></item>"
+    ///     + "</channel></rss>";
+    /// let channel = FeedReader::parse(&xml).unwrap();
+    /// assert!(channel.extension::<Rights>().is_some());
+    /// ```
+    pub fn extension<E: Extension>(&self) -> Option<E> {
+        self.extensions.get(E::NAMESPACE).and_then(|elements| E::from_elements(elements))
+    }
+
+
+    /// Get the iTunes extension that exists under `Channel`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::FeedReader;
+    ///
+    /// let xml = "<rss xmlns:itunes=\"http://www.itunes.com/dtds/podcast-1.0.dtd\">".to_owned()
+    ///     + "<channel><title>Title</title><itunes:author>Jane Doe</itunes:author>"
+    ///     + "<itunes:category text=\"Technology\"/></channel></rss>";
+    /// let channel = FeedReader::parse(&xml).unwrap();
+    /// let itunes = channel.itunes().unwrap();
+    /// assert_eq!(Some("Jane Doe".to_owned()), itunes.author());
+    /// assert_eq!("Technology", itunes.categories()[0].text());
+    /// ```
+    pub fn itunes(&self) -> Option<ITunesChannelExtension> {
+        self.extension()
+    }
+
+
+    /// Get the Podcasting 2.0 extension that exists under `Channel`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::FeedReader;
+    ///
+    /// let xml = "<rss xmlns:podcast=\"https://podcastindex.org/namespace/1.0\">".to_owned()
+    ///     + "<channel><title>Title</title><podcast:locked owner=\"jane@example.com\">yes"
+    ///     + "</podcast:locked><podcast:funding url=\"http://example.com/donate\">Support us!"
+    ///     + "</podcast:funding></channel></rss>";
+    /// let channel = FeedReader::parse(&xml).unwrap();
+    /// let podcast = channel.podcast().unwrap();
+    /// assert_eq!(Some(true), podcast.locked());
+    /// assert_eq!("http://example.com/donate", podcast.funding()[0].url());
+    /// ```
+    pub fn podcast(&self) -> Option<PodcastChannelExtension> {
+        self.extension()
+    }
+
+
+    /// Get the Media RSS extension that exists under `Channel`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::FeedReader;
+    ///
+    /// let xml = "<rss xmlns:media=\"http://search.yahoo.com/mrss/\">".to_owned()
+    ///     + "<channel><title>Title</title><media:thumbnail url=\"http://example.com/a.jpg\"/>"
+    ///     + "</channel></rss>";
+    /// let channel = FeedReader::parse(&xml).unwrap();
+    /// let media = channel.media().unwrap();
+    /// assert_eq!("http://example.com/a.jpg", media.thumbnails()[0].url());
+    /// ```
+    pub fn media(&self) -> Option<MediaExtension> {
+        self.extension()
+    }
+
+
+    /// Get the Dublin Core extension that exists under `Channel`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::FeedReader;
+    ///
+    /// let xml = "<rss xmlns:dc=\"http://purl.org/dc/elements/1.1/\"><channel>".to_owned()
+    ///     + "<title>Title</title><dc:publisher>Jane Doe</dc:publisher>"
+    ///     + "<dc:date>2016-03-13T20:02:02-07:00</dc:date></channel></rss>";
+    /// let channel = FeedReader::parse(&xml).unwrap();
+    /// let dublin_core = channel.dublin_core().unwrap();
+    /// assert_eq!(vec!["Jane Doe".to_owned()], dublin_core.publishers());
+    /// assert_eq!("2016-03-13T20:02:02-07:00", channel.pub_date().unwrap().to_rfc3339());
+    /// ```
+    pub fn dublin_core(&self) -> Option<DublinCoreExtension> {
+        self.extension()
+    }
+
+
+    /// Get the atom links that exist under `Channel`, in the order they were
+    /// read. A link without a rel is an alternate link, as in atom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::FeedReader;
+    ///
+    /// let xml = "<rss xmlns:atom=\"http://www.w3.org/2005/Atom\"><channel>".to_owned()
+    ///     + "<title>Title</title><link>http://example.com/</link>"
+    ///     + "<atom:link rel=\"self\" href=\"http://example.com/feed.xml\" type=\"application/rss+xml\"/>"
+    ///     + "<atom:link rel=\"hub\" href=\"http://example.com/hub\"/>"
+    ///     + "<atom:link rel=\"next\" href=\"http://example.com/feed/page/2.xml\"/>"
+    ///     + "</channel></rss>";
+    /// let channel = FeedReader::parse(&xml).unwrap();
+    /// assert_eq!("http://example.com/".to_owned(), channel.link());
+    ///
+    /// let links = channel.atom_links();
+    /// assert_eq!(3, links.len());
+    /// assert_eq!(Some("application/rss+xml".to_owned()), links[0].link_type());
+    /// ```
+    pub fn atom_links(&self) -> Vec<Link> {
+        self.extensions
+            .get(ATOM_NAMESPACE)
+            .map(|elements| {
+                elements.iter()
+                        .filter(|element| element.local_name() == "link")
+                        .map(util::extension_to_atom_link)
+                        .collect()
+            })
+            .unwrap_or_default()
+    }
+
+
+    /// Get the atom link to the feed itself that exists under `Channel`, the
+    /// one with the rel `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::LinkBuilder;
+    /// use feed::rss::ChannelBuilder;
+    ///
+    /// let links = vec![LinkBuilder::new().href("http://example.com/").finalize(),
+    ///                  LinkBuilder::new()
+    ///                      .href("http://example.com/feed.xml")
+    ///                      .rel(Some("self".to_owned()))
+    ///                      .finalize()];
+    /// let channel = ChannelBuilder::new().atom_links(links).finalize();
+    /// assert_eq!("http://example.com/feed.xml".to_owned(), channel.self_link().unwrap().href());
+    /// ```
+    pub fn self_link(&self) -> Option<Link> {
+        self.rel_links("self").into_iter().next()
+    }
+
+
+    /// Get the atom links to the WebSub hubs of the feed that exist under
+    /// `Channel`, the ones with the rel `hub`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::LinkBuilder;
+    /// use feed::rss::ChannelBuilder;
+    ///
+    /// let link = LinkBuilder::new()
+    ///     .href("http://example.com/hub")
+    ///     .rel(Some("hub".to_owned()))
+    ///     .finalize();
+    /// let channel = ChannelBuilder::new().atom_links(vec![link]).finalize();
+    /// assert_eq!("http://example.com/hub".to_owned(), channel.hub_links()[0].href());
+    /// ```
+    pub fn hub_links(&self) -> Vec<Link> {
+        self.rel_links("hub")
+    }
+
+
+    /// Get the atom link to the next page of a paged feed that exists under
+    /// `Channel`, the one with the rel `next`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::LinkBuilder;
+    /// use feed::rss::ChannelBuilder;
+    ///
+    /// let link = LinkBuilder::new()
+    ///     .href("http://example.com/feed/page/2.xml")
+    ///     .rel(Some("next".to_owned()))
+    ///     .finalize();
+    /// let channel = ChannelBuilder::new().atom_links(vec![link]).finalize();
+    /// assert_eq!("http://example.com/feed/page/2.xml".to_owned(), channel.next_link().unwrap().href());
+    /// ```
+    pub fn next_link(&self) -> Option<Link> {
+        self.rel_links("next").into_iter().next()
+    }
+
+
+    // Get the atom links with the rel, a link without one being an alternate
+    // link as in atom.
+    fn rel_links(&self, rel: &str) -> Vec<Link> {
+        self.atom_links()
+            .into_iter()
+            .filter(|link| link.rel().as_deref().unwrap_or("alternate") == rel)
+            .collect()
+    }
 }
@@ -4,7 +4,15 @@
 
 //! The fields can be set for channel by using the methods under `ChannelBuilder`.
 
-use rss::{Category, Channel, ChannelBuilder, Cloud, Image, Item, TextInput};
+use atom::Link;
+use chrono::*;
+use extension::{Extension, ExtensionMap, ATOM_NAMESPACE, DUBLIN_CORE_NAMESPACE, ITUNES_NAMESPACE,
+                MEDIA_NAMESPACE, PODCAST_NAMESPACE};
+use extension::dublin_core::DublinCoreExtension;
+use extension::itunes::ITunesChannelExtension;
+use extension::media::MediaExtension;
+use extension::podcast::PodcastChannelExtension;
+use rss::{Category, Channel, ChannelBuilder, Cloud, Image, Item, TextInput, Version};
 use util;
 
 impl ChannelBuilder {
@@ -158,7 +166,8 @@ impl ChannelBuilder {
     /// channel_builder.pub_date(Some("Sun, 13 Mar 2016 20:02:02 -0700".to_owned()));
     /// ```
     pub fn pub_date(&mut self, pub_date: Option<String>) -> &mut ChannelBuilder {
-        self.pub_date = util::option_string_to_option_date(pub_date);
+        self.pub_date = util::option_string_to_option_date(pub_date.clone());
+        self.pub_date_raw = pub_date.filter(|_| self.pub_date.is_none());
         self
     }
 
@@ -174,7 +183,19 @@ impl ChannelBuilder {
     /// channel_builder.last_build_date(Some("Sun, 13 Mar 2016 20:02:02 -0700".to_owned()));
     /// ```
     pub fn last_build_date(&mut self, last_build_date: Option<String>) -> &mut ChannelBuilder {
-        self.last_build_date = util::option_string_to_option_date(last_build_date);
+        self.last_build_date = util::option_string_to_option_date(last_build_date.clone());
+        self.last_build_date_raw = last_build_date.filter(|_| self.last_build_date.is_none());
+        self
+    }
+
+
+    // Set the last build date that exists under `Channel` from a date that has
+    // no text of its own, the writer formatting it.
+    pub(crate) fn last_build_date_value(&mut self,
+                                        last_build_date: Option<DateTime<FixedOffset>>)
+                                        -> &mut ChannelBuilder {
+        self.last_build_date = last_build_date;
+        self.last_build_date_raw = None;
         self
     }
 
@@ -268,6 +289,24 @@ impl ChannelBuilder {
     }
 
 
+    /// Set the ttl that exists under `Channel` from its text, which is kept even
+    /// when it is not a number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::rss::ChannelBuilder;
+    ///
+    /// let mut channel_builder = ChannelBuilder::new();
+    /// channel_builder.ttl_raw(Some("60".to_owned()));
+    /// ```
+    pub fn ttl_raw(&mut self, ttl: Option<String>) -> &mut ChannelBuilder {
+        self.ttl = util::option_str_to_option_i64(ttl.as_deref());
+        self.ttl_raw = ttl;
+        self
+    }
+
+
     /// Set the optional image that exists under `Channel`.
     ///
     /// # Examples
@@ -285,6 +324,7 @@ impl ChannelBuilder {
         self
     }
 
+
     /// Set the optional rating that exists under `Channel`.
     ///
     /// # Examples
@@ -376,6 +416,230 @@ impl ChannelBuilder {
     }
 
 
+    /// Set the version of rss that exists under `Channel`, RSS 2.0 by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::rss::{ChannelBuilder, Version};
+    ///
+    /// let mut channel_builder = ChannelBuilder::new();
+    /// channel_builder.version(Version::V0_92);
+    /// ```
+    pub fn version(&mut self, version: Version) -> &mut ChannelBuilder {
+        self.version = version;
+        self
+    }
+
+
+    /// Set the extension elements that exist under `Channel`, keyed by the uri of
+    /// their namespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::{ExtensionElementBuilder, ExtensionMap};
+    /// use feed::rss::ChannelBuilder;
+    ///
+    /// let element = ExtensionElementBuilder::new()
+    ///     .name("dc:rights")
+    ///     .namespace("http://purl.org/dc/elements/1.1/")
+    ///     .text(Some("Public domain".to_owned()))
+    ///     .finalize();
+    /// let mut extensions = ExtensionMap::new();
+    /// extensions.insert(element.namespace(), vec![element]);
+    ///
+    /// let mut channel_builder = ChannelBuilder::new();
+    /// channel_builder.extensions(extensions);
+    /// ```
+    pub fn extensions(&mut self, extensions: ExtensionMap) -> &mut ChannelBuilder {
+        self.extensions = extensions;
+        self
+    }
+
+
+    /// Set the elements of the namespace of the `Extension` under `Channel`,
+    /// replacing the ones of that namespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::{Extension, ExtensionElement};
+    /// use feed::rss::ChannelBuilder;
+    ///
+    /// struct Empty;
+    ///
+    /// impl Extension for Empty {
+    ///     const NAMESPACE: &'static str = "http://example.com/empty";
+    ///     const PREFIX: &'static str = "empty";
+    ///
+    ///     fn from_elements(_: &[ExtensionElement]) -> Option<Empty> {
+    ///         Some(Empty)
+    ///     }
+    ///
+    ///     fn to_elements(&self) -> Vec<ExtensionElement> {
+    ///         Vec::new()
+    ///     }
+    /// }
+    ///
+    /// let mut channel_builder = ChannelBuilder::new();
+    /// channel_builder.extension(&Empty);
+    /// ```
+    pub fn extension<E: Extension>(&mut self, extension: &E) -> &mut ChannelBuilder {
+        let elements = extension.to_elements();
+        if elements.is_empty() {
+            self.extensions.remove(E::NAMESPACE);
+        } else {
+            self.extensions.insert(E::NAMESPACE.to_owned(), elements);
+        }
+        self
+    }
+
+
+    /// Set the iTunes extension that exists under `Channel`, replacing the
+    /// elements of the iTunes namespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesChannelExtensionBuilder;
+    /// use feed::rss::ChannelBuilder;
+    ///
+    /// let itunes = ITunesChannelExtensionBuilder::new()
+    ///     .author(Some("Jane Doe".to_owned()))
+    ///     .finalize();
+    ///
+    /// let mut channel_builder = ChannelBuilder::new();
+    /// channel_builder.itunes(Some(itunes));
+    /// ```
+    pub fn itunes(&mut self, itunes: Option<ITunesChannelExtension>) -> &mut ChannelBuilder {
+        match itunes {
+            Some(itunes) => self.extension(&itunes),
+            None => {
+                self.extensions.remove(ITUNES_NAMESPACE);
+                self
+            }
+        }
+    }
+
+
+    /// Set the Podcasting 2.0 extension that exists under `Channel`, replacing
+    /// the elements of the Podcasting 2.0 namespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::PodcastChannelExtensionBuilder;
+    /// use feed::rss::ChannelBuilder;
+    ///
+    /// let podcast = PodcastChannelExtensionBuilder::new()
+    ///     .guid(Some("917393e3-1b1e-5cef-ace4-edaa54e1f810".to_owned()))
+    ///     .finalize();
+    ///
+    /// let mut channel_builder = ChannelBuilder::new();
+    /// channel_builder.podcast(Some(podcast));
+    /// ```
+    pub fn podcast(&mut self, podcast: Option<PodcastChannelExtension>) -> &mut ChannelBuilder {
+        match podcast {
+            Some(podcast) => self.extension(&podcast),
+            None => {
+                self.extensions.remove(PODCAST_NAMESPACE);
+                self
+            }
+        }
+    }
+
+
+    /// Set the Media RSS extension that exists under `Channel`, replacing the
+    /// elements of the Media RSS namespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaExtensionBuilder, MediaThumbnailBuilder};
+    /// use feed::rss::ChannelBuilder;
+    ///
+    /// let thumbnail = MediaThumbnailBuilder::new()
+    ///     .url("http://example.com/a.jpg")
+    ///     .finalize();
+    /// let media = MediaExtensionBuilder::new()
+    ///     .thumbnails(vec![thumbnail])
+    ///     .finalize();
+    ///
+    /// let mut channel_builder = ChannelBuilder::new();
+    /// channel_builder.media(Some(media));
+    /// ```
+    pub fn media(&mut self, media: Option<MediaExtension>) -> &mut ChannelBuilder {
+        match media {
+            Some(media) => self.extension(&media),
+            None => {
+                self.extensions.remove(MEDIA_NAMESPACE);
+                self
+            }
+        }
+    }
+
+
+    /// Set the Dublin Core extension that exists under `Channel`, replacing the
+    /// elements of the Dublin Core namespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    /// use feed::rss::ChannelBuilder;
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .rights(vec!["Public domain".to_owned()])
+    ///     .finalize();
+    ///
+    /// let mut channel_builder = ChannelBuilder::new();
+    /// channel_builder.dublin_core(Some(dublin_core));
+    /// ```
+    pub fn dublin_core(&mut self, dublin_core: Option<DublinCoreExtension>) -> &mut ChannelBuilder {
+        match dublin_core {
+            Some(dublin_core) => self.extension(&dublin_core),
+            None => {
+                self.extensions.remove(DUBLIN_CORE_NAMESPACE);
+                self
+            }
+        }
+    }
+
+
+    /// Set the atom links that exist under `Channel`, replacing the ones that
+    /// were read and keeping the other elements of atom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::atom::LinkBuilder;
+    /// use feed::rss::ChannelBuilder;
+    ///
+    /// let self_link = LinkBuilder::new()
+    ///     .href("http://example.com/feed.xml")
+    ///     .rel(Some("self".to_owned()))
+    ///     .link_type(Some("application/rss+xml".to_owned()))
+    ///     .finalize();
+    /// let hub_link = LinkBuilder::new()
+    ///     .href("http://example.com/hub")
+    ///     .rel(Some("hub".to_owned()))
+    ///     .finalize();
+    ///
+    /// let mut channel_builder = ChannelBuilder::new();
+    /// channel_builder.atom_links(vec![self_link, hub_link]);
+    /// ```
+    pub fn atom_links(&mut self, atom_links: Vec<Link>) -> &mut ChannelBuilder {
+        let mut elements = atom_links.iter().map(util::atom_link_to_extension).collect::<Vec<_>>();
+        let others = self.extensions.remove(ATOM_NAMESPACE).unwrap_or_default();
+        elements.extend(others.into_iter().filter(|element| element.local_name() != "link"));
+        if !elements.is_empty() {
+            self.extensions.insert(ATOM_NAMESPACE.to_owned(), elements);
+        }
+        self
+    }
+
+
     /// Construct the `Channel` from the `ChannelBuilder`.
     ///
     /// # Examples
@@ -421,18 +685,23 @@ impl ChannelBuilder {
             managing_editor: self.managing_editor.clone(),
             web_master: self.web_master.clone(),
             pub_date: self.pub_date,
+            pub_date_raw: self.pub_date_raw.clone(),
             last_build_date: self.last_build_date,
+            last_build_date_raw: self.last_build_date_raw.clone(),
             categories: self.categories.clone(),
             generator: self.generator.clone(),
             docs: self.docs.clone(),
             cloud: self.cloud.clone(),
             ttl: self.ttl,
+            ttl_raw: self.ttl_raw.clone(),
             image: self.image.clone(),
             rating: self.rating.clone(),
             text_input: self.text_input.clone(),
             skip_hours: self.skip_hours.clone(),
             skip_days: self.skip_days.clone(),
             items: self.items.clone(),
+            extensions: self.extensions.clone(),
+            version: self.version,
         }
     }
 }
@@ -43,6 +43,25 @@ impl Cloud {
     }
 
 
+    /// Get the text of the port that exists under `Cloud` as it was read, also
+    /// when it is not a number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::rss::CloudBuilder;
+    ///
+    /// let cloud = CloudBuilder::new()
+    ///     .port_raw(Some("unknown".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(0, cloud.port());
+    /// assert_eq!(Some("unknown".to_owned()), cloud.port_raw());
+    /// ```
+    pub fn port_raw(&self) -> Option<String> {
+        self.port_raw.clone()
+    }
+
+
     /// Get the path that exists under `Cloud`.
     ///
     /// # Examples
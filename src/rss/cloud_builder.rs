@@ -5,6 +5,7 @@
 //! The fields can be set for cloud by using the methods under `CloudBuilder`.
 
 use rss::{Cloud, CloudBuilder};
+use util;
 
 impl CloudBuilder {
     /// Construct a new `CloudBuilder` and return default values.
@@ -53,6 +54,26 @@ impl CloudBuilder {
     }
 
 
+    /// Set the port that exists under `Cloud` from its text, which is kept even
+    /// when it is not a number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::rss::CloudBuilder;
+    ///
+    /// let mut cloud_builder = CloudBuilder::new();
+    /// cloud_builder.port_raw(Some("60".to_owned()));
+    /// ```
+    pub fn port_raw(&mut self, port: Option<String>) -> &mut CloudBuilder {
+        if let Some(port) = util::option_str_to_option_i64(port.as_deref()) {
+            self.port = port;
+        }
+        self.port_raw = port;
+        self
+    }
+
+
     /// Set the path that exists under `Cloud`.
     ///
     /// # Examples
@@ -120,6 +141,7 @@ impl CloudBuilder {
         Cloud {
             domain: self.domain.clone(),
             port: self.port,
+            port_raw: self.port_raw.clone(),
             path: self.path.clone(),
             register_procedure: self.register_procedure.clone(),
             protocol: self.protocol.clone(),
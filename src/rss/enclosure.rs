@@ -44,6 +44,25 @@ impl Enclosure {
     }
 
 
+    /// Get the text of the length that exists under `Enclosure` as it was read, also
+    /// when it is not a number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::rss::EnclosureBuilder;
+    ///
+    /// let enclosure = EnclosureBuilder::new()
+    ///     .length_raw(Some("unknown".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(0, enclosure.length());
+    /// assert_eq!(Some("unknown".to_owned()), enclosure.length_raw());
+    /// ```
+    pub fn length_raw(&self) -> Option<String> {
+        self.length_raw.clone()
+    }
+
+
     /// Get the enclosure type that exists under `Enclosure`.
     ///
     /// # Examples
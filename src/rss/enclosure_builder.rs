@@ -5,6 +5,7 @@
 //! The fields can be set for enclosure by using the methods under `EnclosureBuilder`.
 
 use rss::{Enclosure, EnclosureBuilder};
+use util;
 
 impl EnclosureBuilder {
     /// Construct a new `EnclosureBuilder` and return default values.
@@ -55,6 +56,26 @@ impl EnclosureBuilder {
     }
 
 
+    /// Set the length that exists under `Enclosure` from its text, which is kept even
+    /// when it is not a number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::rss::EnclosureBuilder;
+    ///
+    /// let mut enclosure_builder = EnclosureBuilder::new();
+    /// enclosure_builder.length_raw(Some("60".to_owned()));
+    /// ```
+    pub fn length_raw(&mut self, length: Option<String>) -> &mut EnclosureBuilder {
+        if let Some(length) = util::option_str_to_option_i64(length.as_deref()) {
+            self.length = length;
+        }
+        self.length_raw = length;
+        self
+    }
+
+
     /// Set the enclosure_type that exists under `Enclosure`.
     ///
     /// # Examples
@@ -90,6 +111,7 @@ impl EnclosureBuilder {
         Enclosure {
             url: self.url.clone(),
             length: self.length,
+            length_raw: self.length_raw.clone(),
             enclosure_type: self.enclosure_type.clone(),
         }
     }
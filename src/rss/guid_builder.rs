@@ -32,11 +32,7 @@ impl GuidBuilder {
     /// guid_builder.permalink(Some(false));
     /// ```
     pub fn permalink(&mut self, permalink: Option<bool>) -> &mut GuidBuilder {
-        if permalink.is_some() {
-            self.permalink = permalink.unwrap();
-        } else {
-            self.permalink = true;
-        }
+        self.permalink = permalink.unwrap_or(true);
         self
     }
 
@@ -100,6 +100,25 @@ impl Image {
     }
 
 
+    /// Get the text of the width that exists under `Image` as it was read, also
+    /// when it is not a number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::rss::ImageBuilder;
+    ///
+    /// let image = ImageBuilder::new()
+    ///     .width_raw(Some("unknown".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(0, image.width());
+    /// assert_eq!(Some("unknown".to_owned()), image.width_raw());
+    /// ```
+    pub fn width_raw(&self) -> Option<String> {
+        self.width_raw.clone()
+    }
+
+
     /// Get the height that exists under `Image`.
     ///
     /// # Examples
@@ -139,6 +158,25 @@ impl Image {
     }
 
 
+    /// Get the text of the height that exists under `Image` as it was read, also
+    /// when it is not a number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::rss::ImageBuilder;
+    ///
+    /// let image = ImageBuilder::new()
+    ///     .height_raw(Some("unknown".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(0, image.height());
+    /// assert_eq!(Some("unknown".to_owned()), image.height_raw());
+    /// ```
+    pub fn height_raw(&self) -> Option<String> {
+        self.height_raw.clone()
+    }
+
+
     /// Get the description that exists under `Image`.
     ///
     /// # Examples
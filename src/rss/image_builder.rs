@@ -6,6 +6,7 @@
 //! and the fields can be set for image by using the methods under `ImageBuilder`.
 
 use rss::{Image, ImageBuilder};
+use util;
 
 impl ImageBuilder {
     /// Construct a new `ImageBuilder` and return default values.
@@ -81,9 +82,8 @@ impl ImageBuilder {
     /// image_builder.width(Some(88));
     /// ```
     pub fn width(&mut self, width: Option<i64>) -> &mut ImageBuilder {
-        if width.is_some() {
+        if let Some(mut size) = width {
             let max_width = 144;
-            let mut size = width.unwrap();
             if size > max_width {
                 size = max_width;
             }
@@ -95,6 +95,26 @@ impl ImageBuilder {
     }
 
 
+    /// Set the width that exists under `Image` from its text, which is kept even
+    /// when it is not a number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::rss::ImageBuilder;
+    ///
+    /// let mut image_builder = ImageBuilder::new();
+    /// image_builder.width_raw(Some("60".to_owned()));
+    /// ```
+    pub fn width_raw(&mut self, width: Option<String>) -> &mut ImageBuilder {
+        if let Some(width) = util::option_str_to_option_i64(width.as_deref()) {
+            self.width(Some(width));
+        }
+        self.width_raw = width;
+        self
+    }
+
+
     /// Set the height that exists under `Image`.
     ///
     /// # Examples
@@ -106,9 +126,8 @@ impl ImageBuilder {
     /// image_builder.height(Some(88));
     /// ```
     pub fn height(&mut self, height: Option<i64>) -> &mut ImageBuilder {
-        if height.is_some() {
+        if let Some(mut size) = height {
             let max_height = 400;
-            let mut size = height.unwrap();
             if size > max_height {
                 size = max_height;
             }
@@ -120,6 +139,26 @@ impl ImageBuilder {
     }
 
 
+    /// Set the height that exists under `Image` from its text, which is kept even
+    /// when it is not a number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::rss::ImageBuilder;
+    ///
+    /// let mut image_builder = ImageBuilder::new();
+    /// image_builder.height_raw(Some("60".to_owned()));
+    /// ```
+    pub fn height_raw(&mut self, height: Option<String>) -> &mut ImageBuilder {
+        if let Some(height) = util::option_str_to_option_i64(height.as_deref()) {
+            self.height(Some(height));
+        }
+        self.height_raw = height;
+        self
+    }
+
+
     /// Set the description that exists under `Image`.
     ///
     /// # Examples
@@ -158,7 +197,9 @@ impl ImageBuilder {
             title: self.title.clone(),
             link: self.link.clone(),
             width: self.width,
+            width_raw: self.width_raw.clone(),
             height: self.height,
+            height_raw: self.height_raw.clone(),
             description: self.description.clone(),
         }
     }
@@ -5,9 +5,16 @@
 //! The fields under item can be retrieved by using the methods under `Item`.
 
 use chrono::*;
+use date;
+use extension::{Extension, ExtensionMap, CONTENT_NAMESPACE};
+use extension::dublin_core::DublinCoreExtension;
+use extension::itunes::ITunesItemExtension;
+use extension::media::MediaExtension;
+use extension::podcast::PodcastItemExtension;
 use rss::{Category, Enclosure, Guid, Source};
 
 use rss::Item;
+use util;
 
 impl Item {
     /// Get the optional title that exists under `Item`.
@@ -105,7 +112,8 @@ impl Item {
     }
 
 
-    /// Get the optional author that exists under `Item`.
+    /// Get the optional author that exists under `Item`, or else the Dublin
+    /// Core `dc:creator`.
     ///
     /// # Examples
     ///
@@ -133,6 +141,13 @@ impl Item {
     /// assert!(item.author().is_none());
     /// ```
     pub fn author(&self) -> Option<String> {
+        self.author.clone().or_else(|| util::dublin_core_text(&self.extensions, "creator"))
+    }
+
+
+    // Get the author that was set on `Item`, leaving out the `dc:creator` it
+    // falls back to.
+    pub(crate) fn own_author(&self) -> Option<String> {
         self.author.clone()
     }
 
@@ -280,7 +295,8 @@ impl Item {
     }
 
 
-    /// Get the optional pub date that exists under `Item`.
+    /// Get the optional pub date that exists under `Item`, or else the Dublin
+    /// Core `dc:date`.
     ///
     /// # Examples
     ///
@@ -311,7 +327,50 @@ impl Item {
     /// assert!(item.pub_date().is_none());
     /// ```
     pub fn pub_date(&self) -> Option<DateTime<FixedOffset>> {
-        self.pub_date
+        if self.pub_date.is_some() || self.pub_date_raw.is_some() {
+            self.pub_date
+        } else {
+            util::option_string_to_option_date(util::dublin_core_text(&self.extensions, "date"))
+        }
+    }
+
+
+    /// Get the text of the optional pub date that exists under `Item` when it
+    /// could not be parsed, or else the text of `dc:date` when it could not be
+    /// parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::rss::ItemBuilder;
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Title".to_owned()))
+    ///     .pub_date(Some("13 Mrz 2016 20:02 Uhr".to_owned()))
+    ///     .finalize();
+    /// assert!(item.pub_date().is_some());
+    /// assert!(item.pub_date_raw().is_none());
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Title".to_owned()))
+    ///     .pub_date(Some("sometime in March".to_owned()))
+    ///     .finalize();
+    /// assert!(item.pub_date().is_none());
+    /// assert_eq!(Some("sometime in March".to_owned()), item.pub_date_raw());
+    /// ```
+    pub fn pub_date_raw(&self) -> Option<String> {
+        if self.pub_date.is_some() || self.pub_date_raw.is_some() {
+            self.pub_date_raw.clone()
+        } else {
+            util::dublin_core_text(&self.extensions, "date").filter(|text| date::parse(text).is_none())
+        }
+    }
+
+
+    // Get the pub date and its text that were set on `Item`, leaving out the
+    // `dc:date` they fall back to.
+    pub(crate) fn own_pub_date(&self) -> (Option<DateTime<FixedOffset>>, Option<String>) {
+        (self.pub_date, self.pub_date_raw.clone())
     }
 
 
@@ -346,4 +405,237 @@ impl Item {
     pub fn source(&self) -> Option<Source> {
         self.source.clone()
     }
+
+
+    /// Get a key identifying the `Item`, to find the same item again across
+    /// reads of a feed: its guid, else its link, else a hash of its title and
+    /// pubDate.
+    ///
+    /// The hash is the same in every build and release, so keys can be stored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::rss::{GuidBuilder, ItemBuilder};
+    ///
+    /// let guid = GuidBuilder::new().guid("urn:uuid:1225c695").finalize();
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Title".to_owned()))
+    ///     .link(Some("http://example.com/a".to_owned()))
+    ///     .guid(Some(guid))
+    ///     .finalize();
+    /// assert_eq!("urn:uuid:1225c695".to_owned(), item.identity_key());
+    ///
+    /// let item = ItemBuilder::new()
+    ///     .title(Some("Title".to_owned()))
+    ///     .link(Some("http://example.com/a".to_owned()))
+    ///     .finalize();
+    /// assert_eq!("http://example.com/a".to_owned(), item.identity_key());
+    /// ```
+    ///
+    /// ```
+    /// use feed::rss::ItemBuilder;
+    /// use std::collections::HashSet;
+    ///
+    /// let first = ItemBuilder::new()
+    ///     .title(Some("Title".to_owned()))
+    ///     .pub_date(Some("Sun, 13 Mar 2016 20:02:02 -0700".to_owned()))
+    ///     .finalize();
+    /// let second = ItemBuilder::new()
+    ///     .title(Some("Title".to_owned()))
+    ///     .pub_date(Some("Mon, 14 Mar 2016 20:02:02 -0700".to_owned()))
+    ///     .finalize();
+    /// assert_eq!(16, first.identity_key().len());
+    /// assert!(first.identity_key() != second.identity_key());
+    ///
+    /// let keys = vec![first.clone(), second, first]
+    ///     .iter()
+    ///     .map(|item| item.identity_key())
+    ///     .collect::<HashSet<_>>();
+    /// assert_eq!(2, keys.len());
+    /// ```
+    pub fn identity_key(&self) -> String {
+        if let Some(ref guid) = self.guid {
+            return guid.guid();
+        }
+
+        if let Some(ref link) = self.link {
+            return link.clone();
+        }
+
+        let title = self.title.clone().unwrap_or_default();
+        let pub_date = self.pub_date.map(|date| date.to_rfc3339()).unwrap_or_default();
+        let hash = util::fnv1a_64(format!("{}\n{}", title, pub_date).as_bytes());
+        format!("{:016x}", hash)
+    }
+
+
+    /// Get the extension elements that exist under `Item`, keyed by the uri of
+    /// their namespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::FeedReader;
+    ///
+    /// let xml = "<rss xmlns:dc=\"http://purl.org/dc/elements/1.1/\"><channel>".to_owned()
+    ///     + "<title>Title</title><dc:rights>Public domain</dc:rights>"
+    ///     + "<item><title>Item</title><dc:rights>Mine</dc:rights></item>"
+    ///     + "</channel></rss>";
+    /// let channel = FeedReader::parse(&xml).unwrap();
+    /// let item = channel.items().unwrap().remove(0);
+    /// let extensions = item.extensions();
+    /// let rights = &extensions["http://purl.org/dc/elements/1.1/"][0];
+    /// assert_eq!("dc:rights".to_owned(), rights.name());
+    /// ```
+    pub fn extensions(&self) -> ExtensionMap {
+        self.extensions.clone()
+    }
+
+
+    /// Get the `Extension` read from the elements of its namespace under
+    /// `Item`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::{Extension, ExtensionElement};
+    /// use feed::feedio::FeedReader;
+    ///
+    /// struct Rights(String);
+    ///
+    /// impl Extension for Rights {
+    ///     const NAMESPACE: &'static str = "http://purl.org/dc/elements/1.1/";
+    ///     const PREFIX: &'static str = "dc";
+    ///
+    ///     fn from_elements(elements: &[ExtensionElement]) -> Option<Rights> {
+    ///         let rights = elements.iter().find(|e| e.local_name() == "rights")?;
+    ///         Some(Rights(rights.text().unwrap_or_default()))
+    ///     }
+    ///
+    ///     fn to_elements(&self) -> Vec<ExtensionElement> {
+    ///         Vec::new()
+    ///     }
+    /// }
+    ///
+    /// let xml = "<rss xmlns:dc=\"http://purl.org/dc/elements/1.1/\"><channel>".to_owned()
+    ///     + "<title>Title</title><dc:rights>Public domain</dc:rights>"
+    ///     + "<item><title>Item</title><dc:rights>Mine</dc:rights></item>"
+    ///     + "</channel></rss>";
+    /// let channel = FeedReader::parse(&xml).unwrap();
+    /// let item = channel.items().unwrap().remove(0);
+    /// assert!(item.extension::<Rights>().is_some());
+    /// ```
+    pub fn extension<E: Extension>(&self) -> Option<E> {
+        self.extensions.get(E::NAMESPACE).and_then(|elements| E::from_elements(elements))
+    }
+
+
+    /// Get the iTunes extension that exists under `Item`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::FeedReader;
+    ///
+    /// let xml = "<rss xmlns:itunes=\"http://www.itunes.com/dtds/podcast-1.0.dtd\">".to_owned()
+    ///     + "<channel><title>Title</title><item><title>Item</title>"
+    ///     + "<itunes:duration>1:02:03</itunes:duration>"
+    ///     + "<itunes:explicit>no</itunes:explicit></item></channel></rss>";
+    /// let channel = FeedReader::parse(&xml).unwrap();
+    /// let itunes = channel.items().unwrap()[0].itunes().unwrap();
+    /// assert_eq!(Some(3723), itunes.duration());
+    /// assert_eq!(Some(false), itunes.explicit());
+    /// ```
+    pub fn itunes(&self) -> Option<ITunesItemExtension> {
+        self.extension()
+    }
+
+
+    /// Get the Podcasting 2.0 extension that exists under `Item`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::FeedReader;
+    ///
+    /// let xml = "<rss xmlns:podcast=\"https://podcastindex.org/namespace/1.0\">".to_owned()
+    ///     + "<channel><title>Title</title><item><title>Item</title>"
+    ///     + "<podcast:transcript url=\"http://example.com/episode.vtt\" type=\"text/vtt\"/>"
+    ///     + "<podcast:chapters url=\"http://example.com/chapters.json\" "
+    ///     + "type=\"application/json+chapters\"/></item></channel></rss>";
+    /// let channel = FeedReader::parse(&xml).unwrap();
+    /// let podcast = channel.items().unwrap()[0].podcast().unwrap();
+    /// assert_eq!("text/vtt", podcast.transcripts()[0].transcript_type());
+    /// assert_eq!("http://example.com/chapters.json", podcast.chapters().unwrap().url());
+    /// ```
+    pub fn podcast(&self) -> Option<PodcastItemExtension> {
+        self.extension()
+    }
+
+
+    /// Get the Media RSS extension that exists under `Item`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::FeedReader;
+    ///
+    /// let xml = "<rss xmlns:media=\"http://search.yahoo.com/mrss/\">".to_owned()
+    ///     + "<channel><title>Title</title><item><title>Item</title><media:group>"
+    ///     + "<media:content url=\"http://example.com/a.mp4\" width=\"640\" height=\"360\"/>"
+    ///     + "<media:content url=\"http://example.com/b.mp4\" width=\"1280\" height=\"720\"/>"
+    ///     + "</media:group></item></channel></rss>";
+    /// let channel = FeedReader::parse(&xml).unwrap();
+    /// let media = channel.items().unwrap()[0].media().unwrap();
+    /// let widths = media.groups()[0].contents().iter().map(|c| c.width()).collect::<Vec<_>>();
+    /// assert_eq!(vec![Some(640), Some(1280)], widths);
+    /// ```
+    pub fn media(&self) -> Option<MediaExtension> {
+        self.extension()
+    }
+
+
+    /// Get the Dublin Core extension that exists under `Item`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::FeedReader;
+    ///
+    /// let xml = "<rss xmlns:dc=\"http://purl.org/dc/elements/1.1/\"><channel>".to_owned()
+    ///     + "<title>Title</title><item><title>Item</title><dc:creator>Jane Doe</dc:creator>"
+    ///     + "<dc:subject>Linux</dc:subject><dc:subject>Music</dc:subject></item>"
+    ///     + "</channel></rss>";
+    /// let channel = FeedReader::parse(&xml).unwrap();
+    /// let item = channel.items().unwrap().remove(0);
+    /// let dublin_core = item.dublin_core().unwrap();
+    /// assert_eq!(vec!["Linux".to_owned(), "Music".to_owned()], dublin_core.subjects());
+    /// assert_eq!(Some("Jane Doe".to_owned()), item.author());
+    /// ```
+    pub fn dublin_core(&self) -> Option<DublinCoreExtension> {
+        self.extension()
+    }
+
+
+    /// Get the optional full content of the item, the `content:encoded` that
+    /// exists under `Item`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::feedio::FeedReader;
+    ///
+    /// let xml = "<rss xmlns:content=\"http://purl.org/rss/1.0/modules/content/\">".to_owned()
+    ///     + "<channel><title>Title</title><item><title>Item</title>"
+    ///     + "<description>Summary</description>"
+    ///     + "<content:encoded><![CDATA[<p>Full <b>post</b></p>]]></content:encoded>"
+    ///     + "</item></channel></rss>";
+    /// let channel = FeedReader::parse(&xml).unwrap();
+    /// let item = channel.items().unwrap().remove(0);
+    /// assert_eq!(Some("<p>Full <b>post</b></p>".to_owned()), item.content());
+    /// ```
+    pub fn content(&self) -> Option<String> {
+        self.extensions.get(CONTENT_NAMESPACE).and_then(|elements| util::extension_text(elements, "encoded"))
+    }
 }
@@ -4,6 +4,13 @@
 
 //! The fields can be set for item by using the methods under `ItemBuilder`.
 
+use chrono::*;
+use extension::{Extension, ExtensionMap, CONTENT_NAMESPACE, DUBLIN_CORE_NAMESPACE, ITUNES_NAMESPACE,
+                MEDIA_NAMESPACE, PODCAST_NAMESPACE};
+use extension::dublin_core::DublinCoreExtension;
+use extension::itunes::ITunesItemExtension;
+use extension::media::MediaExtension;
+use extension::podcast::PodcastItemExtension;
 use rss::{Category, Enclosure, Guid, Source};
 use util;
 use errors;
@@ -171,7 +178,19 @@ impl ItemBuilder {
     /// item_builder.pub_date(Some("Sun, 13 Mar 2016 20:02:02 -0700".to_owned()));
     /// ```
     pub fn pub_date(&mut self, pub_date: Option<String>) -> &mut ItemBuilder {
-        self.pub_date = util::option_string_to_option_date(pub_date);
+        self.pub_date = util::option_string_to_option_date(pub_date.clone());
+        self.pub_date_raw = pub_date.filter(|_| self.pub_date.is_none());
+        self
+    }
+
+
+    // Set the pub date that exists under `Item` from a date that has no text
+    // of its own, the writer formatting it.
+    pub(crate) fn pub_date_value(&mut self,
+                                 pub_date: Option<DateTime<FixedOffset>>)
+                                 -> &mut ItemBuilder {
+        self.pub_date = pub_date;
+        self.pub_date_raw = None;
         self
     }
 
@@ -194,6 +213,211 @@ impl ItemBuilder {
     }
 
 
+    /// Set the extension elements that exist under `Item`, keyed by the uri of
+    /// their namespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::{ExtensionElementBuilder, ExtensionMap};
+    /// use feed::rss::ItemBuilder;
+    ///
+    /// let element = ExtensionElementBuilder::new()
+    ///     .name("dc:rights")
+    ///     .namespace("http://purl.org/dc/elements/1.1/")
+    ///     .text(Some("Public domain".to_owned()))
+    ///     .finalize();
+    /// let mut extensions = ExtensionMap::new();
+    /// extensions.insert(element.namespace(), vec![element]);
+    ///
+    /// let mut item_builder = ItemBuilder::new();
+    /// item_builder.extensions(extensions);
+    /// ```
+    pub fn extensions(&mut self, extensions: ExtensionMap) -> &mut ItemBuilder {
+        self.extensions = extensions;
+        self
+    }
+
+
+    /// Set the elements of the namespace of the `Extension` under `Item`,
+    /// replacing the ones of that namespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::{Extension, ExtensionElement};
+    /// use feed::rss::ItemBuilder;
+    ///
+    /// struct Empty;
+    ///
+    /// impl Extension for Empty {
+    ///     const NAMESPACE: &'static str = "http://example.com/empty";
+    ///     const PREFIX: &'static str = "empty";
+    ///
+    ///     fn from_elements(_: &[ExtensionElement]) -> Option<Empty> {
+    ///         Some(Empty)
+    ///     }
+    ///
+    ///     fn to_elements(&self) -> Vec<ExtensionElement> {
+    ///         Vec::new()
+    ///     }
+    /// }
+    ///
+    /// let mut item_builder = ItemBuilder::new();
+    /// item_builder.extension(&Empty);
+    /// ```
+    pub fn extension<E: Extension>(&mut self, extension: &E) -> &mut ItemBuilder {
+        let elements = extension.to_elements();
+        if elements.is_empty() {
+            self.extensions.remove(E::NAMESPACE);
+        } else {
+            self.extensions.insert(E::NAMESPACE.to_owned(), elements);
+        }
+        self
+    }
+
+
+    /// Set the iTunes extension that exists under `Item`, replacing the
+    /// elements of the iTunes namespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::itunes::ITunesItemExtensionBuilder;
+    /// use feed::rss::ItemBuilder;
+    ///
+    /// let itunes = ITunesItemExtensionBuilder::new()
+    ///     .duration(Some(3723))
+    ///     .finalize();
+    ///
+    /// let mut item_builder = ItemBuilder::new();
+    /// item_builder.itunes(Some(itunes));
+    /// ```
+    pub fn itunes(&mut self, itunes: Option<ITunesItemExtension>) -> &mut ItemBuilder {
+        match itunes {
+            Some(itunes) => self.extension(&itunes),
+            None => {
+                self.extensions.remove(ITUNES_NAMESPACE);
+                self
+            }
+        }
+    }
+
+
+    /// Set the Podcasting 2.0 extension that exists under `Item`, replacing
+    /// the elements of the Podcasting 2.0 namespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::podcast::{PodcastItemExtensionBuilder, PodcastTranscriptBuilder};
+    /// use feed::rss::ItemBuilder;
+    ///
+    /// let transcript = PodcastTranscriptBuilder::new()
+    ///     .url("http://example.com/episode.vtt")
+    ///     .transcript_type("text/vtt")
+    ///     .finalize();
+    /// let podcast = PodcastItemExtensionBuilder::new()
+    ///     .transcripts(vec![transcript])
+    ///     .finalize();
+    ///
+    /// let mut item_builder = ItemBuilder::new();
+    /// item_builder.podcast(Some(podcast));
+    /// ```
+    pub fn podcast(&mut self, podcast: Option<PodcastItemExtension>) -> &mut ItemBuilder {
+        match podcast {
+            Some(podcast) => self.extension(&podcast),
+            None => {
+                self.extensions.remove(PODCAST_NAMESPACE);
+                self
+            }
+        }
+    }
+
+
+    /// Set the Media RSS extension that exists under `Item`, replacing the
+    /// elements of the Media RSS namespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::media::{MediaContentBuilder, MediaExtensionBuilder};
+    /// use feed::rss::ItemBuilder;
+    ///
+    /// let content = MediaContentBuilder::new()
+    ///     .url("http://example.com/a.mp4")
+    ///     .width(Some(640))
+    ///     .height(Some(360))
+    ///     .finalize();
+    /// let media = MediaExtensionBuilder::new()
+    ///     .contents(vec![content])
+    ///     .finalize();
+    ///
+    /// let mut item_builder = ItemBuilder::new();
+    /// item_builder.media(Some(media));
+    /// ```
+    pub fn media(&mut self, media: Option<MediaExtension>) -> &mut ItemBuilder {
+        match media {
+            Some(media) => self.extension(&media),
+            None => {
+                self.extensions.remove(MEDIA_NAMESPACE);
+                self
+            }
+        }
+    }
+
+
+    /// Set the Dublin Core extension that exists under `Item`, replacing the
+    /// elements of the Dublin Core namespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::extension::dublin_core::DublinCoreExtensionBuilder;
+    /// use feed::rss::ItemBuilder;
+    ///
+    /// let dublin_core = DublinCoreExtensionBuilder::new()
+    ///     .creators(vec!["Jane Doe".to_owned()])
+    ///     .finalize();
+    ///
+    /// let mut item_builder = ItemBuilder::new();
+    /// item_builder.dublin_core(Some(dublin_core));
+    /// ```
+    pub fn dublin_core(&mut self, dublin_core: Option<DublinCoreExtension>) -> &mut ItemBuilder {
+        match dublin_core {
+            Some(dublin_core) => self.extension(&dublin_core),
+            None => {
+                self.extensions.remove(DUBLIN_CORE_NAMESPACE);
+                self
+            }
+        }
+    }
+
+
+    /// Set the optional full content of the item, the `content:encoded` that
+    /// exists under `Item`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed::rss::ItemBuilder;
+    ///
+    /// let mut item_builder = ItemBuilder::new();
+    /// item_builder.content(Some("<p>Full <b>post</b></p>".to_owned()));
+    /// ```
+    pub fn content(&mut self, content: Option<String>) -> &mut ItemBuilder {
+        let mut elements = self.extensions.remove(CONTENT_NAMESPACE).unwrap_or_default();
+        elements.retain(|element| element.local_name() != "encoded");
+        if let Some(content) = content {
+            elements.insert(0, util::extension_text_element("content:encoded", CONTENT_NAMESPACE, &content));
+        }
+        if !elements.is_empty() {
+            self.extensions.insert(CONTENT_NAMESPACE.to_owned(), elements);
+        }
+        self
+    }
+
+
     /// Construct the `Item` from the `ItemBuilder`.
     ///
     /// # Examples
@@ -216,7 +440,7 @@ impl ItemBuilder {
     /// ```
     pub fn finalize(&self) -> Item {
         if self.title.is_none() && self.description.is_none() {
-            panic!(errors::item_required_field_error());
+            panic!("{}", errors::item_required_field_error());
         }
         Item {
             title: self.title.clone(),
@@ -228,7 +452,9 @@ impl ItemBuilder {
             enclosure: self.enclosure.clone(),
             guid: self.guid.clone(),
             pub_date: self.pub_date,
+            pub_date_raw: self.pub_date_raw.clone(),
             source: self.source.clone(),
+            extensions: self.extensions.clone(),
         }
     }
 }
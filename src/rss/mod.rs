@@ -24,10 +24,14 @@ pub mod text_input;
 pub mod text_input_builder;
 
 use chrono::*;
+use extension::ExtensionMap;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// This `Category` struct contains all the items that exist for the category
 /// field under 'Channel' and `Item`.
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct Category {
     category: String,
     domain: Option<String>,
@@ -43,7 +47,8 @@ pub struct CategoryBuilder {
 
 
 /// This `Channel` struct contains all the items that exist for the `feed`.
-#[derive(Clone, Default)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct Channel {
     title: String,
     link: String,
@@ -52,19 +57,27 @@ pub struct Channel {
     copyright: Option<String>,
     managing_editor: Option<String>,
     web_master: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default, with = "::util::serde_date"))]
     pub_date: Option<DateTime<FixedOffset>>,
+    pub_date_raw: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default, with = "::util::serde_date"))]
     last_build_date: Option<DateTime<FixedOffset>>,
+    last_build_date_raw: Option<String>,
     categories: Option<Vec<Category>>,
     generator: Option<String>,
     docs: Option<String>,
     cloud: Option<Cloud>,
     ttl: Option<i64>,
+    ttl_raw: Option<String>,
     image: Option<Image>,
     rating: Option<String>,
     text_input: Option<TextInput>,
     skip_hours: Option<Vec<i64>>,
     skip_days: Option<Vec<String>>,
     items: Option<Vec<Item>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    extensions: ExtensionMap,
+    version: Version,
 }
 
 
@@ -79,26 +92,33 @@ pub struct ChannelBuilder {
     managing_editor: Option<String>,
     web_master: Option<String>,
     pub_date: Option<DateTime<FixedOffset>>,
+    pub_date_raw: Option<String>,
     last_build_date: Option<DateTime<FixedOffset>>,
+    last_build_date_raw: Option<String>,
     categories: Option<Vec<Category>>,
     generator: Option<String>,
     docs: Option<String>,
     cloud: Option<Cloud>,
     ttl: Option<i64>,
+    ttl_raw: Option<String>,
     image: Option<Image>,
     rating: Option<String>,
     text_input: Option<TextInput>,
     skip_hours: Option<Vec<i64>>,
     skip_days: Option<Vec<String>>,
     items: Option<Vec<Item>>,
+    extensions: ExtensionMap,
+    version: Version,
 }
 
 
 /// This `Cloud` struct contains all the items that exist for the cloud field under 'Channel'.
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct Cloud {
     domain: String,
     port: i64,
+    port_raw: Option<String>,
     path: String,
     register_procedure: String,
     protocol: String,
@@ -110,6 +130,7 @@ pub struct Cloud {
 pub struct CloudBuilder {
     domain: String,
     port: i64,
+    port_raw: Option<String>,
     path: String,
     register_procedure: String,
     protocol: String,
@@ -117,10 +138,12 @@ pub struct CloudBuilder {
 
 
 /// This `Enclosure` struct contains all the items that exist for the enclosure field under 'Item'.
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct Enclosure {
     url: String,
     length: i64,
+    length_raw: Option<String>,
     enclosure_type: String,
 }
 
@@ -130,12 +153,14 @@ pub struct Enclosure {
 pub struct EnclosureBuilder {
     url: String,
     length: i64,
+    length_raw: Option<String>,
     enclosure_type: String,
 }
 
 
 /// This `Guid` struct contains all the items that exist for the guid field under 'Item'.
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct Guid {
     permalink: bool,
     guid: String,
@@ -151,13 +176,16 @@ pub struct GuidBuilder {
 
 
 /// This `Image` struct contains all the items that exist for the image field under 'Channel'.
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct Image {
     url: String,
     title: String,
     link: String,
     width: i64,
+    width_raw: Option<String>,
     height: i64,
+    height_raw: Option<String>,
     description: Option<String>,
 }
 
@@ -169,14 +197,17 @@ pub struct ImageBuilder {
     title: String,
     link: String,
     width: i64,
+    width_raw: Option<String>,
     height: i64,
+    height_raw: Option<String>,
     description: Option<String>,
 }
 
 
 /// This `Item` struct contains all the items that exist for the item field
 /// under 'Channel'.
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct Item {
     title: Option<String>,
     link: Option<String>,
@@ -186,8 +217,12 @@ pub struct Item {
     comments: Option<String>,
     enclosure: Option<Enclosure>,
     guid: Option<Guid>,
+    #[cfg_attr(feature = "serde", serde(default, with = "::util::serde_date"))]
     pub_date: Option<DateTime<FixedOffset>>,
+    pub_date_raw: Option<String>,
     source: Option<Source>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    extensions: ExtensionMap,
 }
 
 
@@ -203,12 +238,15 @@ pub struct ItemBuilder {
     enclosure: Option<Enclosure>,
     guid: Option<Guid>,
     pub_date: Option<DateTime<FixedOffset>>,
+    pub_date_raw: Option<String>,
     source: Option<Source>,
+    extensions: ExtensionMap,
 }
 
 
 /// This `Source` struct contains all the items that exist for the source field under 'Item'.
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct Source {
     url: String,
     source: String,
@@ -225,7 +263,8 @@ pub struct SourceBuilder {
 
 /// This `TextInput` struct contains all the items that exist for the text input
 /// field under 'Channel'.
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct TextInput {
     title: String,
     description: String,
@@ -242,3 +281,24 @@ pub struct TextInputBuilder {
     name: String,
     link: String,
 }
+
+
+/// This `Version` enum contains the dialects of rss a `Channel` can be read from
+/// or written as.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum Version {
+    /// RSS 0.90, the first RDF based version by Netscape.
+    V0_90,
+    /// RSS 0.91 as published by Netscape, with its DTD.
+    V0_91Netscape,
+    /// RSS 0.91 as published by UserLand, without a DTD.
+    V0_91Userland,
+    /// RSS 0.92.
+    V0_92,
+    /// RSS 1.0, the RDF Site Summary.
+    V1_0,
+    /// RSS 2.0.
+    #[default]
+    V2_0,
+}
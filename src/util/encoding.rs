@@ -0,0 +1,148 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! Detection of the character encoding of documents and encoding of utf-8 to
+//! other character encodings.
+
+use encoding_rs::{Encoding, UTF_16BE, UTF_16LE, UTF_8};
+use errors::FeedError;
+use std::io::{self, Write};
+use std::str;
+use util::find;
+
+// Get the encoding of a document from its byte order mark, then the charset of
+// its content type, then its xml declaration, and else utf-8.
+pub fn detect(prefix: &[u8], charset: Option<&str>) -> &'static Encoding {
+    if let Some((encoding, _)) = Encoding::for_bom(prefix) {
+        return encoding;
+    }
+
+    // utf-16 without a byte order mark, its first character being `<`.
+    if prefix.starts_with(b"<\0") {
+        return UTF_16LE;
+    } else if prefix.starts_with(b"\0<") {
+        return UTF_16BE;
+    }
+
+    if let Some(encoding) = charset.and_then(|charset| Encoding::for_label(charset.trim().as_bytes())) {
+        return encoding;
+    }
+
+    // A declaration of utf-16 that can be read byte by byte is wrong.
+    match declared_encoding(prefix).and_then(Encoding::for_label) {
+        Some(encoding) if encoding != UTF_16LE && encoding != UTF_16BE => encoding,
+        _ => UTF_8,
+    }
+}
+
+
+// Get the encoding with the label, such as `ISO-8859-1` or `UTF-16`.
+pub fn for_label(label: &str) -> Result<&'static Encoding, FeedError> {
+    Encoding::for_label(label.trim().as_bytes())
+        .ok_or_else(|| FeedError::UnknownEncoding { label: label.to_owned() })
+}
+
+
+// Get the name of the encoding written to the xml declaration, utf-16 being
+// written with a byte order mark.
+pub fn declaration_name(encoding: &'static Encoding) -> &'static str {
+    if encoding == UTF_16LE || encoding == UTF_16BE {
+        "UTF-16"
+    } else {
+        encoding.output_encoding().name()
+    }
+}
+
+
+// Get the charset parameter of a content type, e.g. `text/xml; charset=utf-8`.
+pub fn content_type_charset(content_type: &str) -> Option<&str> {
+    content_type.split(';').skip(1).find_map(|parameter| {
+        let (name, value) = parameter.split_once('=')?;
+        if name.trim().eq_ignore_ascii_case("charset") {
+            Some(value.trim().trim_matches('"'))
+        } else {
+            None
+        }
+    })
+}
+
+
+// Get the encoding named by the xml declaration at the start of a document.
+fn declared_encoding(prefix: &[u8]) -> Option<&[u8]> {
+    if !prefix.starts_with(b"<?xml") {
+        return None;
+    }
+    let declaration = &prefix[..find(prefix, b"?>")?];
+    let rest = &declaration[find(declaration, b"encoding")? + b"encoding".len()..];
+    let rest = rest.trim_ascii_start().strip_prefix(b"=")?.trim_ascii_start();
+    let quote = *rest.first().filter(|&&quote| quote == b'"' || quote == b'\'')?;
+    let len = rest[1..].iter().position(|&c| c == quote)?;
+    Some(&rest[1..1 + len])
+}
+
+
+// Encode the utf-8 text in the encoding, characters the encoding lacks
+// becoming character references.
+fn encode(encoding: &'static Encoding, text: &str) -> Vec<u8> {
+    if encoding == UTF_16LE {
+        text.encode_utf16().flat_map(u16::to_le_bytes).collect()
+    } else if encoding == UTF_16BE {
+        text.encode_utf16().flat_map(u16::to_be_bytes).collect()
+    } else {
+        encoding.encode(text).0.into_owned()
+    }
+}
+
+
+// Check whether the encoding has every character of the text, which it can
+// then write without character references.
+pub fn can_encode(encoding: &'static Encoding, text: &str) -> bool {
+    encoding == UTF_16LE || encoding == UTF_16BE || !encoding.encode(text).2
+}
+
+
+// A `Write` encoding the utf-8 written to it in another character encoding.
+pub struct EncodingWriter<W: Write> {
+    writer: W,
+    encoding: &'static Encoding,
+    pending: Vec<u8>,
+}
+
+
+impl<W: Write> EncodingWriter<W> {
+    // Construct a new `EncodingWriter`, writing the byte order mark of utf-16.
+    pub fn new(mut writer: W, encoding: &'static Encoding) -> io::Result<EncodingWriter<W>> {
+        if encoding == UTF_16LE || encoding == UTF_16BE {
+            writer.write_all(&encode(encoding, "\u{FEFF}"))?;
+        }
+        Ok(EncodingWriter {
+            writer,
+            encoding,
+            pending: Vec::new(),
+        })
+    }
+}
+
+
+impl<W: Write> Write for EncodingWriter<W> {
+    // Encode the complete characters written so far, keeping a character
+    // split between writes until the rest of it is written.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.pending.extend_from_slice(buf);
+        let len = match str::from_utf8(&self.pending) {
+            Ok(text) => text.len(),
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            Err(e) => return Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+        };
+        let text = str::from_utf8(&self.pending[..len]).unwrap_or_default();
+        self.writer.write_all(&encode(self.encoding, text))?;
+        self.pending.drain(..len);
+        Ok(buf.len())
+    }
+
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
@@ -2,83 +2,371 @@
 // Use of this source code is governed by the LGPLv3 license that can be
 // found in the LICENSE file.
 
+pub mod encoding;
+mod html_entities;
+#[cfg(feature = "serde")]
+pub mod serde_date;
+
 use chrono::*;
-use errors;
+use date;
+use errors::FeedError;
+use atom::{Link, LinkBuilder};
+use extension::{ExtensionElement, ExtensionElementBuilder, ExtensionMap, ATOM_NAMESPACE, DUBLIN_CORE_NAMESPACE};
 use quick_xml::attributes::Attributes;
 use quick_xml::Element;
-use std::{i64, str};
+use std::borrow::Cow;
+use std::io::{self, Read};
+use std::str;
 use std::str::FromStr;
 
-// Common code to convert &str to Option<String>.
-pub fn str_to_option_string(s: &str) -> Option<String> {
-    Some(s.to_owned())
+// The most bytes read to detect the format of a document.
+const PREFIX_LIMIT: usize = 64 * 1024;
+
+// Common code to convert &str to i64.
+pub fn str_to_i64(s: &str) -> Result<i64, FeedError> {
+    i64::from_str(s.trim()).map_err(|_| {
+        FeedError::InvalidInteger {
+            value: s.to_owned(),
+            position: 0,
+            path: String::new(),
+        }
+    })
+}
+
+
+// Common code to convert Option<&str> to Option<i64>, leaving out text that
+// is not a number.
+pub fn option_str_to_option_i64(s: Option<&str>) -> Option<i64> {
+    s.and_then(|s| str_to_i64(s).ok())
+}
+
+
+// Common code to convert &str to Option<bool>, leaving out text that is not a
+// boolean.
+pub fn str_to_option_bool(s: &str) -> Option<bool> {
+    bool::from_str(s.trim()).ok()
+}
+
+
+// Common code to convert the attribute `name` to &str.
+pub fn attribute_to_str<'a>(attributes: Attributes<'a>,
+                            name: &str)
+                            -> Result<Cow<'a, str>, FeedError> {
+    match attribute_to_option_str(attributes, name)? {
+        Some(attr) => Ok(attr),
+        None => {
+            Err(FeedError::MissingAttribute {
+                attribute: name.to_owned(),
+                position: 0,
+                path: String::new(),
+            })
+        }
+    }
 }
 
 
-// Common code to convert attribute to &str.
-pub fn attribute_to_str(attributes: Attributes, index: usize) -> &str {
-    let attr = attributes.map(|a| a.unwrap().1).collect::<Vec<_>>();
-    str::from_utf8(attr[index]).expect(errors::utf8_to_str_error())
+// Common code to convert the attribute `name` to Option<&str>, decoding its
+// entities.
+pub fn attribute_to_option_str<'a>(attributes: Attributes<'a>,
+                                   name: &str)
+                                   -> Result<Option<Cow<'a, str>>, FeedError> {
+    for attribute in attributes {
+        match attribute {
+            Ok((key, value)) => {
+                if key == name.as_bytes() {
+                    return Ok(Some(unescape(str::from_utf8(value)?)));
+                }
+            }
+            Err((e, _)) => return Err(FeedError::from(e)),
+        }
+    }
+    Ok(None)
 }
 
 
-// Common code to convert attribute to i64.
-pub fn attribute_to_i64(attributes: Attributes, index: usize) -> i64 {
-    let attr_str = attribute_to_str(attributes, index);
-    i64::from_str(attr_str).expect(errors::str_to_i64_error())
+// Common code to convert all the attributes to their names and values,
+// decoding the entities of the values.
+pub fn attributes_to_vec(attributes: Attributes) -> Result<Vec<(String, String)>, FeedError> {
+    let mut attrs = Vec::new();
+    for attribute in attributes {
+        match attribute {
+            Ok((key, value)) => {
+                let key = str::from_utf8(key)?.to_owned();
+                attrs.push((key, unescape(str::from_utf8(value)?).into_owned()));
+            }
+            Err((e, _)) => return Err(FeedError::from(e)),
+        }
+    }
+    Ok(attrs)
 }
 
-// Common code to convert attribute to bool.
-pub fn attribute_to_bool(attributes: Attributes, index: usize) -> bool {
-    let attr_str = attribute_to_str(attributes, index);
-    bool::from_str(attr_str).expect(errors::str_to_bool_error())
+
+// Common code to convert the attribute `name` to Option<String>.
+pub fn attribute_to_option_string(attributes: Attributes,
+                                  name: &str)
+                                  -> Result<Option<String>, FeedError> {
+    Ok(attribute_to_option_str(attributes, name)?.map(Cow::into_owned))
 }
 
 
-// Common code to convert attribute to Option<String>.
-pub fn attribute_to_option_string(attributes: Attributes, index: usize) -> Option<String> {
-    str_to_option_string(attribute_to_str(attributes, index))
+// Common code to convert the attribute `name` to Option<i64>.
+pub fn attribute_to_option_i64(attributes: Attributes,
+                               name: &str)
+                               -> Result<Option<i64>, FeedError> {
+    match attribute_to_option_str(attributes, name)? {
+        Some(attr) => Ok(Some(str_to_i64(&attr)?)),
+        None => Ok(None),
+    }
 }
 
 
-// Common code to convert attribute to Option<bool>.
-pub fn attribute_to_option_bool(attributes: Attributes, index: usize) -> Option<bool> {
-    Some(attribute_to_bool(attributes, index))
+// Common code to get the text of the first extension element with the local
+// name.
+pub fn extension_text(elements: &[ExtensionElement], local_name: &str) -> Option<String> {
+    elements.iter().find(|element| element.local_name() == local_name).and_then(ExtensionElement::text)
 }
 
 
-// Common code to convert element to String.
-pub fn element_to_string(e: Element) -> String {
-    e.into_string().expect(errors::element_to_string_error())
+// Common code to get an attribute of the first extension element with the
+// local name.
+pub fn extension_attribute(elements: &[ExtensionElement],
+                           local_name: &str,
+                           attribute: &str)
+                           -> Option<String> {
+    elements.iter()
+            .find(|element| element.local_name() == local_name)
+            .and_then(|element| element.attribute(attribute))
 }
 
 
-// Common code to convert element to Option<String>.
-pub fn element_to_option_string(e: Element) -> Option<String> {
-    Some(element_to_string(e))
+// Common code to get the text of the first Dublin Core element with the local
+// name, which some fields of rss fall back to.
+pub fn dublin_core_text(extensions: &ExtensionMap, local_name: &str) -> Option<String> {
+    extensions.get(DUBLIN_CORE_NAMESPACE).and_then(|elements| extension_text(elements, local_name))
+}
+
+
+// Common code to read an `atom:link` extension element to the atom `Link`.
+pub fn extension_to_atom_link(element: &ExtensionElement) -> Link {
+    LinkBuilder::new()
+        .href(&element.attribute("href").unwrap_or_default())
+        .rel(element.attribute("rel"))
+        .link_type(element.attribute("type"))
+        .hreflang(element.attribute("hreflang"))
+        .title(element.attribute("title"))
+        .length(option_str_to_option_i64(element.attribute("length").as_deref()))
+        .finalize()
+}
+
+
+// Common code to convert the atom `Link` to an `atom:link` extension element.
+pub fn atom_link_to_extension(link: &Link) -> ExtensionElement {
+    let attributes = [("href", Some(link.href())),
+                      ("rel", link.rel()),
+                      ("type", link.link_type()),
+                      ("hreflang", link.hreflang()),
+                      ("title", link.title()),
+                      ("length", link.length().map(|length| length.to_string()))];
+    extension_builder("atom:link", ATOM_NAMESPACE)
+        .attributes(attributes.iter()
+                              .filter_map(|(key, value)| value.clone().map(|value| ((*key).to_owned(), value)))
+                              .collect())
+        .finalize()
+}
+
+
+// Common code to construct the builder of an extension element with the name
+// and namespace.
+pub fn extension_builder(name: &str, namespace: &str) -> ExtensionElementBuilder {
+    let mut builder = ExtensionElementBuilder::new();
+    builder.name(name).namespace(namespace);
+    builder
+}
+
+
+// Common code to construct an extension element containing only text.
+pub fn extension_text_element(name: &str, namespace: &str, text: &str) -> ExtensionElement {
+    extension_builder(name, namespace).text(Some(text.to_owned())).finalize()
+}
+
+
+// Common code to add the attribute to the element, escaping its value.
+pub fn push_attribute(element: &mut Element, key: &str, value: &str) {
+    element.push_attribute(key, escape(value).as_bytes());
+}
+
+
+// Common code to escape the characters of text that are markup in xml, for
+// the text of elements and the values of attributes.
+pub fn escape(text: &str) -> Cow<'_, str> {
+    if !text.contains(['&', '<', '>', '"']) {
+        return Cow::Borrowed(text);
+    }
+    let mut escaped = String::with_capacity(text.len() + 16);
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    Cow::Owned(escaped)
 }
 
 
-// Common code to convert attribute to i64.
-pub fn element_to_i64(e: Element) -> i64 {
-    let e_string = element_to_string(e);
-    i64::from_str(&e_string).expect(errors::str_to_i64_error())
+// Common code to decode the character references and the named entities of
+// xml and HTML 4 in text, keeping any other `&` as it is.
+pub fn unescape(text: &str) -> Cow<'_, str> {
+    if !text.contains('&') {
+        return Cow::Borrowed(text);
+    }
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest[1..].find(';')
+                               .filter(|&len| len <= 32)
+                               .and_then(|len| entity(&rest[1..1 + len]).map(|c| (len, c)));
+        match decoded {
+            Some((len, c)) => {
+                unescaped.push(c);
+                rest = &rest[len + 2..];
+            }
+            None => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    unescaped.push_str(rest);
+    Cow::Owned(unescaped)
 }
 
 
-// Common code to convert attribute to Option<i64>.
-pub fn element_to_option_i64(e: Element) -> Option<i64> {
-    Some(element_to_i64(e))
+// Common code to get the character of an entity, e.g. `amp`, `eacute`, `#233`
+// or `#xE9`.
+fn entity(name: &str) -> Option<char> {
+    let code = if let Some(hex) = name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+        Some(hex).filter(|hex| hex.chars().all(|c| c.is_ascii_hexdigit()))
+                 .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+    } else if let Some(decimal) = name.strip_prefix('#') {
+        Some(decimal).filter(|decimal| decimal.chars().all(|c| c.is_ascii_digit()))
+                     .and_then(|decimal| decimal.parse().ok())
+    } else if name == "apos" {
+        return Some('\'');
+    } else {
+        return html_entities::decode(name);
+    };
+    code.and_then(char::from_u32).filter(|&c| c != '\0')
 }
 
 
 // Common code to convert Option<String> to Option<DateTime<FixedOffset>>.
 pub fn option_string_to_option_date(date_option: Option<String>) -> Option<DateTime<FixedOffset>> {
-    if date_option.is_none() {
-        return None;
+    date_option.and_then(|date| date::parse(&date))
+}
+
+
+// Common code to check whether a content type is used for xml feeds.
+pub fn is_feed_content_type(content_type: &str) -> bool {
+    let mime = content_type.split(';').next().unwrap_or("").trim().to_lowercase();
+    matches!(mime.as_str(),
+             "application/rss+xml" | "application/rdf+xml" | "application/atom+xml" |
+             "application/xml" | "text/xml" | "application/feed+json" | "application/json")
+}
+
+
+// Common code to check whether the root element of a xml document is the one
+// of a rss feed, `rss` or `rdf:RDF`.
+pub fn is_rss_root(name: &[u8]) -> bool {
+    name == b"rss" || name == b"rdf:RDF"
+}
+
+
+// Common code to check whether the root element of a xml document is the one
+// of an atom feed, `feed` with or without a prefix.
+pub fn is_atom_root(name: &[u8]) -> bool {
+    name == b"feed" || name.ends_with(b":feed")
+}
+
+
+// Common code to check whether the root element of a xml document is the one
+// of a feed of any xml format.
+pub fn is_feed_root(name: &[u8]) -> bool {
+    is_rss_root(name) || is_atom_root(name)
+}
+
+
+// Common code to check whether a document is json, its first character being `{`.
+pub fn is_json(document: &[u8]) -> bool {
+    let document = strip_bom(document);
+    document.iter().find(|b| !b" \t\r\n".contains(b)) == Some(&b'{')
+}
+
+
+// Common code to strip the utf-8 byte order mark of a document.
+pub fn strip_bom(document: &[u8]) -> &[u8] {
+    if document.starts_with(b"\xEF\xBB\xBF") { &document[3..] } else { document }
+}
+
+
+// Common code to find the name of the root element of a xml document.
+pub fn root_element_name(xml: &[u8]) -> Option<&[u8]> {
+    let mut rest = strip_bom(xml);
+    loop {
+        let start = rest.iter().position(|b| !b" \t\r\n".contains(b))?;
+        rest = &rest[start..];
+        if !rest.starts_with(b"<") {
+            return None;
+        }
+        let end = if rest.starts_with(b"<?") {
+            find(rest, b"?>").map(|i| i + 2)
+        } else if rest.starts_with(b"<!--") {
+            find(rest, b"-->").map(|i| i + 3)
+        } else if rest.starts_with(b"<!") {
+            find(rest, b">").map(|i| i + 1)
+        } else {
+            let len = rest.iter().position(|b| b" \t\r\n/>".contains(b))?;
+            return Some(&rest[1..len]);
+        };
+        rest = &rest[end?..];
     }
-    let date_string = date_option.unwrap();
-    let datetime = DateTime::parse_from_rfc2822(&date_string)
-                       .expect(errors::str_to_datetime_error());
-    Some(datetime)
+}
+
+
+// Common code to read the start of a document, until its root element or json
+// object is found, so its format can be detected before it is parsed.
+pub fn read_prefix<R: Read>(reader: &mut R) -> io::Result<Vec<u8>> {
+    let mut prefix = Vec::new();
+    let mut chunk = [0; 4096];
+    while prefix.len() < PREFIX_LIMIT && !is_json(&prefix) && root_element_name(&prefix).is_none() {
+        match reader.read(&mut chunk) {
+            Ok(0) => break,
+            Ok(len) => prefix.extend_from_slice(&chunk[..len]),
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => (),
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(prefix)
+}
+
+
+// Common code to hash bytes with 64 bit FNV-1a, which unlike the hashers of
+// std gives the same hash in every release.
+pub fn fnv1a_64(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+
+// Common code to find the position of a byte string.
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|window| window == needle)
 }
@@ -0,0 +1,34 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! Serialization of the optional dates as RFC 3339 strings.
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Deserializer, Serializer};
+use serde::de::Error;
+
+// Serialize the optional date as a RFC 3339 string.
+pub fn serialize<S>(date: &Option<DateTime<FixedOffset>>, serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer
+{
+    match *date {
+        Some(ref date) => serializer.serialize_some(&date.to_rfc3339()),
+        None => serializer.serialize_none(),
+    }
+}
+
+
+// Deserialize the optional date from a RFC 3339 string.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<DateTime<FixedOffset>>, D::Error>
+    where D: Deserializer<'de>
+{
+    match Option::<String>::deserialize(deserializer)? {
+        Some(date) => {
+            DateTime::parse_from_rfc3339(&date)
+                .map(Some)
+                .map_err(|_| D::Error::custom(format!("error converting {:?} to a date", date)))
+        }
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,336 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! Every feed of the corpus must read back the same after it is written,
+//! parse(write(parse(x))) == parse(x).
+
+extern crate feed;
+
+use feed::atom::LinkBuilder;
+use feed::extension::itunes::ITunesItemExtensionBuilder;
+use feed::feedio::{FeedReader, FeedWriter, WriterOptionsBuilder};
+use feed::rss::{Channel, ChannelBuilder, ItemBuilder};
+use std::collections::HashSet;
+use std::fs::{self, File};
+use std::io::Read;
+
+// Read the feed at `path` to the `Channel`.
+fn read_channel(path: &str) -> Channel {
+    let mut xml = String::new();
+    File::open(path).unwrap().read_to_string(&mut xml).unwrap();
+    FeedReader::parse(&xml).unwrap_or_else(|e| panic!("{}: {}", path, e))
+}
+
+
+// Write the `Channel` as its own version and read it back.
+fn round_trip(channel: &Channel) -> Channel {
+    let xml = String::from_utf8(FeedWriter::new(channel.clone()).xml()).unwrap();
+    FeedReader::parse(&xml).unwrap_or_else(|e| panic!("{}\n{}", e, xml))
+}
+
+
+fn assert_round_trip(path: &str) {
+    let channel = read_channel(path);
+    assert_eq!(channel, round_trip(&channel), "{}", path);
+}
+
+
+#[test]
+fn sample_rss() {
+    assert_round_trip("rsc/sample_rss.xml");
+}
+
+
+#[test]
+fn corpus() {
+    let mut paths = fs::read_dir("rsc/round_trip")
+                        .unwrap()
+                        .map(|entry| entry.unwrap().path())
+                        .collect::<Vec<_>>();
+    paths.sort();
+    assert!(!paths.is_empty());
+    for path in paths {
+        assert_round_trip(path.to_str().unwrap());
+    }
+}
+
+
+#[test]
+fn every_field_is_read() {
+    let channel = read_channel("rsc/round_trip/rss_2_0_full.xml");
+    assert_eq!(Some(60), channel.ttl());
+    assert_eq!(vec![0, 23], channel.skip_hours().unwrap());
+    assert_eq!(vec!["Saturday".to_owned(), "Sunday".to_owned()], channel.skip_days().unwrap());
+    assert_eq!("q".to_owned(), channel.text_input().unwrap().name());
+    assert_eq!(Some("The logo".to_owned()), channel.image().unwrap().description());
+    assert_eq!("rpc.sys.com".to_owned(), channel.cloud().unwrap().domain());
+    assert!(channel.rating().is_some());
+
+    let item = channel.items().unwrap().remove(0);
+    assert!(!item.guid().unwrap().permalink());
+    assert_eq!("http://example.org/feed.xml".to_owned(), item.source().unwrap().url());
+    assert_eq!(Some("http://example.com/first#comments".to_owned()), item.comments());
+    assert_eq!(12345, item.enclosure().unwrap().length());
+}
+
+
+#[test]
+fn empty_skip_elements_are_not_written() {
+    let channel = read_channel("rsc/round_trip/rss_0_92.xml");
+    let xml = String::from_utf8(FeedWriter::new(channel).xml()).unwrap();
+    assert!(!xml.contains("skipHours"));
+    assert!(!xml.contains("skipDays"));
+}
+
+
+#[test]
+fn items_compare_and_hash_by_value() {
+    let channel = read_channel("rsc/round_trip/rss_2_0_full.xml");
+    let mut items = channel.items().unwrap();
+    items.extend(round_trip(&channel).items().unwrap());
+
+    let unique = items.iter().collect::<HashSet<_>>();
+    assert_eq!(2, unique.len());
+
+    let keys = items.iter().map(|item| item.identity_key()).collect::<HashSet<_>>();
+    assert_eq!(2, keys.len());
+}
+
+
+#[test]
+fn dates_keep_their_text() {
+    let channel = read_channel("rsc/round_trip/tricky.xml");
+    assert!(channel.last_build_date().is_none());
+    assert_eq!(Some("sometime in March".to_owned()), channel.last_build_date_raw());
+
+    let item = channel.items().unwrap().remove(1);
+    assert_eq!("2016-03-13T20:02:00+01:00".to_owned(), item.pub_date().unwrap().to_rfc3339());
+
+    let xml = String::from_utf8(FeedWriter::new(channel).xml()).unwrap();
+    assert!(xml.contains("<lastBuildDate>sometime in March</lastBuildDate>"));
+    assert!(xml.contains("<pubDate>Sun, 13 Mar 2016 20:02:00 +0100</pubDate>"));
+}
+
+
+#[test]
+fn numbers_keep_their_text() {
+    let channel = read_channel("rsc/round_trip/tricky.xml");
+    assert!(channel.ttl().is_none());
+    assert_eq!(Some("one hour".to_owned()), channel.ttl_raw());
+    assert_eq!(0, channel.image().unwrap().width());
+    assert_eq!(Some("wide".to_owned()), channel.image().unwrap().width_raw());
+
+    let enclosure = channel.items().unwrap().remove(0).enclosure().unwrap();
+    assert_eq!(0, enclosure.length());
+    assert_eq!(Some("unknown".to_owned()), enclosure.length_raw());
+
+    let xml = String::from_utf8(FeedWriter::new(channel).xml()).unwrap();
+    assert!(xml.contains("<ttl>one hour</ttl>"));
+    assert!(xml.contains("<width>wide</width>"));
+    assert!(xml.contains("length=\"unknown\""));
+}
+
+
+#[test]
+fn entities_and_cdata_are_decoded() {
+    let channel = read_channel("rsc/round_trip/tricky.xml");
+    assert_eq!("<p>Escaped <b>markup</b></p>".to_owned(), channel.description());
+
+    let item = channel.items().unwrap().remove(2);
+    assert_eq!(Some("Caf\u{E9} & Bar\u{A0}\u{2014} CDATA".to_owned()), item.title());
+    assert_eq!(Some("<p>Tom &amp; Jerry</p>".to_owned()), item.description());
+
+    let options = WriterOptionsBuilder::new().cdata(true).finalize();
+    let xml = FeedWriter::with_options(channel.clone(), &options).unwrap().xml();
+    let xml = String::from_utf8(xml).unwrap();
+    assert!(xml.contains("<description><![CDATA[<p>Tom &amp; Jerry</p>]]></description>"));
+    assert_eq!(channel, FeedReader::parse(&xml).unwrap());
+}
+
+
+#[test]
+fn extension_elements_are_read_by_namespace() {
+    let channel = read_channel("rsc/round_trip/extensions.xml");
+    assert_eq!("http://example.com/".to_owned(), channel.link());
+
+    let extensions = channel.extensions();
+    let atom = &extensions["http://www.w3.org/2005/Atom"];
+    assert_eq!(2, atom.len());
+    assert_eq!(Some("hub".to_owned()), atom[1].attribute("rel"));
+
+    let category = &extensions["http://www.itunes.com/dtds/podcast-1.0.dtd"][1];
+    assert_eq!(Some("Podcasting".to_owned()), category.child("category").unwrap().attribute("text"));
+
+    let item = channel.items().unwrap().remove(0);
+    let group = &item.extensions()["http://search.yahoo.com/mrss/"][0];
+    assert_eq!("m:group".to_owned(), group.name());
+    assert_eq!(Some("Fish & Chips".to_owned()), group.child("title").unwrap().text());
+    assert!(item.extensions().contains_key("https://podcastindex.org/namespace/1.0"));
+}
+
+
+#[test]
+fn itunes_extensions_are_typed() {
+    let channel = read_channel("rsc/sample_rss.xml");
+    let itunes = channel.itunes().unwrap();
+    assert_eq!(Some("Jupiter Broadcasting".to_owned()), itunes.author());
+    assert_eq!(Some(false), itunes.explicit());
+    assert!(!itunes.block());
+    assert_eq!(Some("chris@linuxactionshow.com".to_owned()), itunes.owner().unwrap().email());
+    let category = itunes.categories().remove(0);
+    assert_eq!("Technology", category.text());
+    assert_eq!("Tech News", category.subcategories()[0].text());
+
+    let item = channel.items().unwrap().remove(0);
+    let itunes = item.itunes().unwrap();
+    assert_eq!(Some(5372), itunes.duration());
+    assert_eq!(Some("1:29:32".to_owned()), itunes.duration_raw());
+
+    let itunes = ITunesItemExtensionBuilder::new()
+                     .duration(Some(60))
+                     .episode(Some(409))
+                     .finalize();
+    let item = ItemBuilder::new()
+                   .title(Some("Episode".to_owned()))
+                   .itunes(Some(itunes))
+                   .finalize();
+    let channel = ChannelBuilder::new().title("Title").items(Some(vec![item])).finalize();
+    let itunes = round_trip(&channel).items().unwrap()[0].itunes().unwrap();
+    assert_eq!(Some(60), itunes.duration());
+    assert_eq!(Some("00:01:00".to_owned()), itunes.duration_raw());
+    assert_eq!(Some(409), itunes.episode());
+}
+
+
+#[test]
+fn podcast_extensions_are_typed() {
+    let channel = read_channel("rsc/round_trip/podcasting.xml");
+    let podcast = channel.podcast().unwrap();
+    assert_eq!(Some(true), podcast.locked());
+    assert_eq!(Some("jane@example.com".to_owned()), podcast.locked_owner());
+    assert_eq!(Some("Support the show!".to_owned()), podcast.funding()[0].text());
+    assert_eq!("Jane Doe", podcast.persons()[0].name());
+    let recipients = podcast.value().unwrap().recipients();
+    assert_eq!(vec![90, 10], recipients.iter().map(|recipient| recipient.split()).collect::<Vec<_>>());
+    assert!(recipients[1].fee());
+
+    let item = channel.items().unwrap().remove(0);
+    let podcast = item.podcast().unwrap();
+    assert_eq!(2, podcast.transcripts().len());
+    assert_eq!(Some("captions".to_owned()), podcast.transcripts()[0].rel());
+    assert_eq!("application/json+chapters", podcast.chapters().unwrap().chapters_type());
+    let alternate = podcast.alternate_enclosures().remove(0);
+    assert!(alternate.default_rendition());
+    let sources = alternate.sources();
+    assert_eq!(32400000, sources[0].length());
+    assert_eq!("audio/opus", sources[0].enclosure_type());
+    assert_eq!("audio/ogg", sources[1].enclosure_type());
+
+    let item = ItemBuilder::new().title(Some("Episode".to_owned())).podcast(Some(podcast.clone())).finalize();
+    let channel = ChannelBuilder::new().title("Title").items(Some(vec![item])).finalize();
+    assert_eq!(Some(podcast), round_trip(&channel).items().unwrap()[0].podcast());
+}
+
+
+#[test]
+fn media_extensions_are_typed() {
+    let channel = read_channel("rsc/round_trip/media.xml");
+    let media = channel.media().unwrap();
+    assert_eq!(Some(300), media.thumbnails()[0].width());
+    assert_eq!(Some("producer".to_owned()), media.credits()[0].role());
+
+    let items = channel.items().unwrap();
+    let group = items[0].media().unwrap().groups().remove(0);
+    let contents = group.contents();
+    assert_eq!(vec![Some(640), Some(1280)],
+               contents.iter().map(|content| content.width()).collect::<Vec<_>>());
+    assert_eq!(Some("2500".to_owned()), contents[1].bitrate());
+    assert_eq!(Some(185), contents[1].duration());
+    assert!(contents[1].default_rendition());
+    let description = group.description().unwrap();
+    assert_eq!("<b>Bold</b> description", description.text());
+    assert_eq!(Some("html".to_owned()), description.text_type());
+
+    let media = items[1].media().unwrap();
+    assert_eq!(Some("image".to_owned()), media.contents()[0].medium());
+    assert_eq!("John Doe", media.contents()[0].credits()[0].name());
+
+    let item = ItemBuilder::new().title(Some("Item".to_owned())).media(Some(media.clone())).finalize();
+    let channel = ChannelBuilder::new().title("Title").items(Some(vec![item])).finalize();
+    assert_eq!(Some(media), round_trip(&channel).items().unwrap()[0].media());
+}
+
+
+#[test]
+fn dublin_core_and_content_are_read() {
+    let channel = read_channel("rsc/round_trip/wordpress.xml");
+    assert_eq!(vec!["en-US".to_owned()], channel.dublin_core().unwrap().languages());
+
+    let items = channel.items().unwrap();
+    assert_eq!(Some("Jane Doe".to_owned()), items[0].author());
+    assert_eq!(vec!["2016-03-13T20:02:02-07:00".to_owned()], items[0].dublin_core().unwrap().dates());
+    assert!(items[0].pub_date_raw().is_none());
+    assert_eq!("2016-03-13T20:02:02-07:00", items[0].pub_date().unwrap().to_rfc3339());
+    let content = items[0].content().unwrap();
+    assert!(content.starts_with("<p>Welcome to WordPress. This is your <em>first</em> post &amp; more.</p>"));
+
+    assert_eq!("2016-03-14T08:00:00+00:00", items[1].pub_date().unwrap().to_rfc3339());
+    assert!(items[1].pub_date_raw().is_none());
+    assert_eq!(vec!["Linux".to_owned(), "Music".to_owned()], items[1].dublin_core().unwrap().subjects());
+
+    // The Dublin Core elements are written as themselves, not as the fields
+    // falling back to them.
+    let xml = String::from_utf8(FeedWriter::new(channel.clone()).xml()).unwrap();
+    assert!(!xml.contains("<author>"));
+    assert_eq!(1, xml.matches("<pubDate>").count());
+    assert_eq!(2, xml.matches("<dc:creator>").count());
+
+    let options = WriterOptionsBuilder::new().cdata(true).finalize();
+    let xml = String::from_utf8(FeedWriter::with_options(channel.clone(), &options).unwrap().xml()).unwrap();
+    assert!(xml.contains("<content:encoded><![CDATA[<p>Welcome"));
+    assert_eq!(channel, FeedReader::parse(&xml).unwrap());
+
+    let item = ItemBuilder::new()
+                   .title(Some("Item".to_owned()))
+                   .content(Some("<p>Full</p>".to_owned()))
+                   .finalize();
+    let channel = ChannelBuilder::new().title("Title").items(Some(vec![item])).finalize();
+    assert_eq!(Some("<p>Full</p>".to_owned()), round_trip(&channel).items().unwrap()[0].content());
+}
+
+
+#[test]
+fn atom_links_are_typed() {
+    let channel = read_channel("rsc/round_trip/extensions.xml");
+    let self_link = channel.self_link().unwrap();
+    assert_eq!("http://example.com/feed.xml", self_link.href());
+    assert_eq!(Some("application/rss+xml".to_owned()), self_link.link_type());
+    let hubs = channel.hub_links();
+    assert_eq!(1, hubs.len());
+    assert_eq!("http://example.com/hub", hubs[0].href());
+    assert!(channel.next_link().is_none());
+
+    let self_link = LinkBuilder::new()
+                        .href("http://example.com/feed.xml")
+                        .rel(Some("self".to_owned()))
+                        .link_type(Some("application/rss+xml".to_owned()))
+                        .finalize();
+    let next_link = LinkBuilder::new()
+                        .href("http://example.com/feed/page/2.xml")
+                        .rel(Some("next".to_owned()))
+                        .finalize();
+    let channel = ChannelBuilder::new()
+                      .title("Title")
+                      .link("http://example.com/")
+                      .atom_links(vec![self_link, next_link])
+                      .finalize();
+    let xml = String::from_utf8(FeedWriter::new(channel.clone()).xml()).unwrap();
+    assert!(xml.contains("<atom:link href=\"http://example.com/feed.xml\" rel=\"self\" type=\"application/rss+xml\""));
+
+    let channel = round_trip(&channel);
+    assert_eq!("http://example.com/", channel.link());
+    assert_eq!("http://example.com/feed.xml", channel.self_link().unwrap().href());
+    assert_eq!("http://example.com/feed/page/2.xml", channel.next_link().unwrap().href());
+    assert!(channel.hub_links().is_empty());
+}
@@ -0,0 +1,74 @@
+// Copyright (c) 2016 Chris Palmer <pennstate5013@gmail.com>
+// Use of this source code is governed by the LGPLv3 license that can be
+// found in the LICENSE file.
+
+//! The feeds serialized with the `serde` feature must deserialize the same.
+
+#![cfg(feature = "serde")]
+
+extern crate feed;
+extern crate serde_json;
+
+use feed::{Feed, Source};
+use feed::feedio::FeedReader;
+use feed::rss::Channel;
+use std::fs::File;
+use std::io::Read;
+
+// Read the feed at `path` to a string.
+fn read_file(path: &str) -> String {
+    let mut xml = String::new();
+    File::open(path).unwrap().read_to_string(&mut xml).unwrap();
+    xml
+}
+
+
+#[test]
+fn channel_round_trip() {
+    let channel = FeedReader::parse(&read_file("rsc/round_trip/rss_2_0_full.xml")).unwrap();
+    let json = serde_json::to_string(&channel).unwrap();
+    assert!(json.contains("\"pub_date\":\"2016-03-13T20:02:02-07:00\""));
+    assert!(json.contains("\"version\":\"V2_0\""));
+
+    let deserialized: Channel = serde_json::from_str(&json).unwrap();
+    assert_eq!(channel, deserialized);
+}
+
+
+#[test]
+fn feed_round_trip() {
+    let feed = Feed::parse(read_file("rsc/sample_rss.xml").as_bytes()).unwrap();
+    let json = serde_json::to_string(&feed).unwrap();
+    let deserialized: Feed = serde_json::from_str(&json).unwrap();
+    assert_eq!(feed.clone().channel(), deserialized.channel());
+}
+
+
+#[test]
+fn atom_feed_round_trip() {
+    let xml = "<feed xmlns=\"http://www.w3.org/2005/Atom\"><title>Title</title>".to_owned() +
+              "<updated>2003-12-13T18:30:02Z</updated><id>urn:uuid:60a76c80</id></feed>";
+    let feed = Feed::parse(xml.as_bytes()).unwrap();
+    let json = serde_json::to_string(&feed).unwrap();
+    assert!(json.contains("\"updated\":\"2003-12-13T18:30:02+00:00\""));
+
+    let deserialized: Feed = serde_json::from_str(&json).unwrap();
+    match deserialized.source() {
+        Source::Atom(feed) => {
+            assert_eq!("Title".to_owned(), feed.title());
+            assert_eq!("2003-12-13T18:30:02+00:00".to_owned(), feed.updated().unwrap().to_rfc3339());
+        }
+        _ => panic!("expected an atom feed"),
+    }
+}
+
+
+#[test]
+fn invalid_dates() {
+    let item = feed::rss::ItemBuilder::new().title(Some("Title".to_owned())).finalize();
+    let json = serde_json::to_string(&item)
+                   .unwrap()
+                   .replace("\"pub_date\":null", "\"pub_date\":\"yesterday\"");
+    let error = serde_json::from_str::<feed::rss::Item>(&json).err().unwrap();
+    assert!(error.to_string().contains("error converting \"yesterday\" to a date"));
+}